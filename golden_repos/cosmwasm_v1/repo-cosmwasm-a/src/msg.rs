@@ -0,0 +1,37 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub denom: String,
+    pub fee_collector: String,
+    pub staking_contract: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Deposit {},
+    Withdraw { amount: Uint128 },
+    Stake {},
+    Distribute {},
+    UpdateConfig { fee_collector: String },
+}
+
+#[cw_serde]
+pub enum StakingExecuteMsg {
+    Bond {},
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Uint128)]
+    Balance { address: String },
+    #[returns(Vec<(String, Uint128)>)]
+    Balances { start_after: Option<String>, limit: Option<u32> },
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    pub owner: String,
+}
@@ -0,0 +1,15 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Config {
+    pub denom: String,
+    pub fee_collector: Addr,
+    pub staking_contract: Addr,
+}
+
+pub const OWNER: Item<Addr> = Item::new("owner");
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const TOTAL_STAKED: Item<Uint128> = Item::new("total_staked");
+pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
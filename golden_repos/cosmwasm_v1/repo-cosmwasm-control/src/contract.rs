@@ -0,0 +1,188 @@
+use cosmwasm_std::{
+    entry_point, to_json_binary, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order,
+    Reply, Response, StdResult, SubMsg, Uint128, WasmMsg,
+};
+use cw2::{ensure_from_older_version, set_contract_version};
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg, StakingExecuteMsg};
+use crate::state::{Config, BALANCES, CONFIG, OWNER, PENDING_STAKE, TOTAL_STAKED};
+
+const CONTRACT_NAME: &str = "crates.io:stake-vault";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+const STAKE_REPLY_ID: u64 = 1;
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
+#[entry_point]
+pub fn instantiate(deps: DepsMut, _env: Env, info: MessageInfo, msg: InstantiateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    OWNER.save(deps.storage, &info.sender)?;
+    let config = Config {
+        denom: msg.denom,
+        fee_collector: deps.api.addr_validate(&msg.fee_collector)?,
+        staking_contract: deps.api.addr_validate(&msg.staking_contract)?,
+    };
+    CONFIG.save(deps.storage, &config)?;
+    TOTAL_STAKED.save(deps.storage, &Uint128::zero())?;
+    Ok(Response::new().add_attribute("action", "instantiate"))
+}
+
+#[entry_point]
+pub fn execute(deps: DepsMut, env: Env, info: MessageInfo, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Deposit {} => execute_deposit(deps, info),
+        ExecuteMsg::Withdraw { amount } => execute_withdraw(deps, info, amount),
+        ExecuteMsg::Stake {} => execute_stake(deps, info),
+        ExecuteMsg::Distribute { start_after, limit } => execute_distribute(deps, env, info, start_after, limit),
+        ExecuteMsg::UpdateConfig { fee_collector } => execute_update_config(deps, info, fee_collector),
+    }
+}
+
+fn assert_owner(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+    if info.sender != OWNER.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(())
+}
+
+fn paid_amount(config: &Config, info: &MessageInfo) -> Result<Uint128, ContractError> {
+    match info.funds.as_slice() {
+        [coin] if coin.denom == config.denom => Ok(coin.amount),
+        _ => Err(ContractError::InvalidFunds { denom: config.denom.clone() }),
+    }
+}
+
+fn execute_deposit(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let amount = paid_amount(&config, &info)?;
+    BALANCES.update(deps.storage, &info.sender, |balance| -> StdResult<_> {
+        Ok(balance.unwrap_or_default() + amount)
+    })?;
+    Ok(Response::new().add_attribute("action", "deposit").add_attribute("amount", amount))
+}
+
+fn execute_withdraw(deps: DepsMut, info: MessageInfo, amount: Uint128) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let balance = BALANCES.may_load(deps.storage, &info.sender)?.unwrap_or_default();
+    if balance < amount {
+        return Err(ContractError::InsufficientBalance {});
+    }
+    BALANCES.save(deps.storage, &info.sender, &(balance - amount))?;
+    let send = BankMsg::Send { to_address: info.sender.to_string(), amount: vec![Coin::new(amount.u128(), config.denom)] };
+    Ok(Response::new().add_message(send).add_attribute("action", "withdraw"))
+}
+
+fn execute_stake(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let amount = paid_amount(&config, &info)?;
+    PENDING_STAKE.save(deps.storage, &(info.sender.clone(), amount))?;
+    let bond = WasmMsg::Execute {
+        contract_addr: config.staking_contract.to_string(),
+        msg: to_json_binary(&StakingExecuteMsg::Bond {})?,
+        funds: vec![Coin::new(amount.u128(), config.denom)],
+    };
+    Ok(Response::new().add_submessage(SubMsg::reply_on_success(bond, STAKE_REPLY_ID)).add_attribute("action", "stake"))
+}
+
+fn execute_distribute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.as_ref(), &info)?;
+    let config = CONFIG.load(deps.storage)?;
+    let rewards = deps.querier.query_balance(env.contract.address, &config.denom)?.amount;
+    let total = TOTAL_STAKED.load(deps.storage)?;
+    if total.is_zero() {
+        return Ok(Response::new());
+    }
+
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|address| deps.api.addr_validate(&address)).transpose()?;
+    let mut messages = vec![];
+    for entry in BALANCES.range(deps.storage, start.as_ref().map(Bound::exclusive), None, Order::Ascending).take(limit) {
+        let (holder, balance) = entry?;
+        let share = rewards.multiply_ratio(balance, total);
+        if !share.is_zero() {
+            messages.push(BankMsg::Send { to_address: holder.to_string(), amount: vec![Coin::new(share.u128(), &config.denom)] });
+        }
+    }
+    Ok(Response::new().add_messages(messages).add_attribute("action", "distribute"))
+}
+
+fn execute_update_config(deps: DepsMut, info: MessageInfo, fee_collector: String) -> Result<Response, ContractError> {
+    assert_owner(deps.as_ref(), &info)?;
+    let mut config = CONFIG.load(deps.storage)?;
+    config.fee_collector = deps.api.addr_validate(&fee_collector)?;
+    CONFIG.save(deps.storage, &config)?;
+    Ok(Response::new().add_attribute("action", "update_config"))
+}
+
+#[entry_point]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    if msg.id != STAKE_REPLY_ID {
+        return Err(ContractError::UnknownReply { id: msg.id });
+    }
+    let (staker, amount) = PENDING_STAKE.load(deps.storage)?;
+    PENDING_STAKE.remove(deps.storage);
+    TOTAL_STAKED.update(deps.storage, |total| -> StdResult<_> { Ok(total + amount) })?;
+    Ok(Response::new().add_attribute("action", "stake_reply").add_attribute("staker", staker))
+}
+
+#[entry_point]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Balance { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_json_binary(&BALANCES.may_load(deps.storage, &address)?.unwrap_or_default())
+        }
+        QueryMsg::Balances { start_after, limit } => to_json_binary(&query_balances(deps, start_after, limit)?),
+    }
+}
+
+fn query_balances(deps: Deps, start_after: Option<String>, limit: Option<u32>) -> StdResult<Vec<(String, Uint128)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(|address| deps.api.addr_validate(&address)).transpose()?;
+    let start = start.as_ref().map(Bound::exclusive);
+    BALANCES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|entry| entry.map(|(holder, balance)| (holder.to_string(), balance)))
+        .collect()
+}
+
+#[entry_point]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    ensure_from_older_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let owner = deps.api.addr_validate(&msg.owner)?;
+    OWNER.save(deps.storage, &owner)?;
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Addr;
+
+    #[test]
+    fn deposit_credits_sender() {
+        let mut deps = mock_dependencies();
+        let creator = String::from("creator");
+        let msg = InstantiateMsg {
+            denom: "uatom".into(),
+            fee_collector: "collector".into(),
+            staking_contract: "staking".into(),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(&creator, &[]), msg).unwrap();
+        let funds = [Coin::new(100, "uatom")];
+        execute(deps.as_mut(), mock_env(), mock_info("alice", &funds), ExecuteMsg::Deposit {}).unwrap();
+        let alice = Addr::unchecked(String::from("alice"));
+        assert_eq!(BALANCES.load(&deps.storage, &alice).unwrap(), Uint128::new(100));
+        assert_eq!(OWNER.load(&deps.storage).unwrap(), Addr::unchecked(creator));
+    }
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("send exactly one coin of {denom}")]
+    InvalidFunds { denom: String },
+
+    #[error("insufficient balance")]
+    InsufficientBalance {},
+
+    #[error("unknown reply id {id}")]
+    UnknownReply { id: u64 },
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @notice Pays out ETH claims authorized off-chain by a signer key.
+contract SignedClaims {
+    address public owner;
+    address public signer;
+    mapping(address => uint256) public claimed;
+
+    constructor(address signer_) payable {
+        owner = msg.sender;
+        signer = signer_;
+    }
+
+    function setSigner(address signer_) external {
+        require(msg.sender == owner, "not owner");
+        signer = signer_;
+    }
+
+    function claim(address payable account, uint256 amount, uint8 v, bytes32 r, bytes32 s) external {
+        bytes32 digest = keccak256(
+            abi.encodePacked("\x19Ethereum Signed Message:\n32", keccak256(abi.encodePacked(account, amount)))
+        );
+        address recovered = ecrecover(digest, v, r, s);
+        require(recovered == signer, "bad signature");
+        claimed[account] += amount;
+        account.transfer(amount);
+    }
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @notice Runs batched actions from helper modules in the context of the caller's account.
+contract BatchExecutor {
+    address public owner;
+    mapping(address => bool) public trustedModules;
+
+    constructor() {
+        owner = msg.sender;
+    }
+
+    function trustModule(address module) external {
+        require(msg.sender == owner, "not owner");
+        trustedModules[module] = true;
+    }
+
+    function execute(address module, bytes calldata data) external returns (bytes memory) {
+        require(msg.sender == owner, "not owner");
+        require(trustedModules[module], "untrusted module");
+        (bool ok, bytes memory result) = module.delegatecall(data);
+        require(ok, "module call failed");
+        return result;
+    }
+}
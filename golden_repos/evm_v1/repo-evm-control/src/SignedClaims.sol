@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @notice Pays out ETH claims authorized off-chain by a signer key.
+contract SignedClaims {
+    bytes32 private constant CLAIM_TYPEHASH = keccak256("Claim(address account,uint256 amount,uint256 nonce)");
+    uint256 private constant HALF_ORDER = 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF5D576E7357A4501DDFE92F46681B20A0;
+
+    address public owner;
+    address public signer;
+    mapping(address => uint256) public nonces;
+
+    constructor(address signer_) payable {
+        owner = msg.sender;
+        signer = signer_;
+    }
+
+    function setSigner(address signer_) external {
+        require(msg.sender == owner, "not owner");
+        signer = signer_;
+    }
+
+    function claim(address payable account, uint256 amount, uint8 v, bytes32 r, bytes32 s) external {
+        require(uint256(s) <= HALF_ORDER, "malleable signature");
+        bytes32 structHash = keccak256(abi.encode(CLAIM_TYPEHASH, account, amount, nonces[account]));
+        bytes32 digest = keccak256(abi.encodePacked("\x19\x01", _domainSeparator(), structHash));
+        address recovered = ecrecover(digest, v, r, s);
+        require(recovered != address(0) && recovered == signer, "bad signature");
+        nonces[account] += 1;
+        account.transfer(amount);
+    }
+
+    function _domainSeparator() private view returns (bytes32) {
+        return keccak256(abi.encode(keccak256("EIP712Domain(uint256 chainId,address verifyingContract)"), block.chainid, address(this)));
+    }
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @notice Pooled ETH vault with a withdrawal fee collected for the owner.
+contract Vault {
+    address public owner;
+    uint256 public withdrawFeeBps;
+    uint256 public collectedFees;
+    mapping(address => uint256) public balances;
+    bool private locked;
+
+    event Deposited(address indexed account, uint256 amount);
+    event Withdrawn(address indexed account, uint256 amount, uint256 fee);
+
+    modifier onlyOwner() {
+        require(msg.sender == owner, "not owner");
+        _;
+    }
+
+    modifier nonReentrant() {
+        require(!locked, "reentrant call");
+        locked = true;
+        _;
+        locked = false;
+    }
+
+    constructor() {
+        owner = msg.sender;
+    }
+
+    function deposit() external payable {
+        balances[msg.sender] += msg.value;
+        emit Deposited(msg.sender, msg.value);
+    }
+
+    function withdraw(uint256 amount) external nonReentrant {
+        require(balances[msg.sender] >= amount, "insufficient balance");
+        uint256 fee = (amount * withdrawFeeBps) / 10_000;
+        balances[msg.sender] -= amount;
+        collectedFees += fee;
+        (bool ok, ) = msg.sender.call{value: amount - fee}("");
+        require(ok, "transfer failed");
+        emit Withdrawn(msg.sender, amount, fee);
+    }
+
+    function setWithdrawFee(uint256 feeBps) external onlyOwner {
+        require(feeBps <= 1_000, "fee too high");
+        withdrawFeeBps = feeBps;
+    }
+
+    function claimFees(address payable to) external onlyOwner {
+        uint256 amount = collectedFees;
+        collectedFees = 0;
+        (bool ok, ) = to.call{value: amount}("");
+        require(ok, "transfer failed");
+    }
+
+    function emergencyWithdraw(address payable to) external onlyOwner {
+        to.transfer(address(this).balance);
+    }
+}
@@ -0,0 +1,17 @@
+import express from "express";
+import { exec } from "node:child_process";
+
+const app = express();
+
+app.get("/snapshots/:wallet", (req, res) => {
+  const wallet = req.params.wallet;
+  exec("solana account " + wallet + " --output json", (error, stdout) => {
+    if (error) {
+      res.status(500).json({ error: "lookup failed" });
+      return;
+    }
+    res.type("application/json").send(stdout);
+  });
+});
+
+app.listen(Number(process.env.PORT ?? 8080));
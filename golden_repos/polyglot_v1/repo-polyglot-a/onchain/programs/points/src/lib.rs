@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod points {
+    use super::*;
+
+    pub fn transfer_points(ctx: Context<TransferPoints>, amount: u64) -> Result<()> {
+        let from = &mut ctx.accounts.from;
+        from.points = from.points.checked_sub(amount).ok_or(PointsError::InsufficientPoints)?;
+        let to = &mut ctx.accounts.to;
+        to.points = to.points.checked_add(amount).ok_or(PointsError::Overflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub points: u64,
+}
+
+#[derive(Accounts)]
+pub struct TransferPoints<'info> {
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Position>,
+    #[account(mut)]
+    pub to: Account<'info, Position>,
+    pub owner: Signer<'info>,
+}
+
+#[error_code]
+pub enum PointsError {
+    #[msg("Not enough points")]
+    InsufficientPoints,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+}
@@ -0,0 +1,32 @@
+use std::process::Command;
+
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::Router;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Lookup {
+    vault: String,
+}
+
+async fn vault_balance(Query(params): Query<Lookup>) -> Result<String, (StatusCode, String)> {
+    if params.vault.len() > 44 || !params.vault.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err((StatusCode::BAD_REQUEST, "invalid vault address".to_string()));
+    }
+    let output = Command::new("solana")
+        .arg("balance")
+        .arg(&params.vault)
+        .output()
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let app = Router::new().route("/balance", get(vault_balance));
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
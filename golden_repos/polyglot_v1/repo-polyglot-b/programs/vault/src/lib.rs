@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod vault {
+    use super::*;
+
+    pub fn set_withdraw_limit(ctx: Context<SetWithdrawLimit>, limit: u64) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.withdraw_limit = limit;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub withdraw_limit: u64,
+}
+
+#[derive(Accounts)]
+pub struct SetWithdrawLimit<'info> {
+    #[account(mut, has_one = authority)]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: compared against vault.authority by has_one
+    pub authority: UncheckedAccount<'info>,
+}
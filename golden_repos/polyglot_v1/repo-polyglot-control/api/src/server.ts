@@ -0,0 +1,21 @@
+import express from "express";
+import { execFile } from "node:child_process";
+
+const app = express();
+
+app.get("/snapshots/:wallet", (req, res) => {
+  const wallet = req.params.wallet;
+  if (!/^[1-9A-HJ-NP-Za-km-z]{32,44}$/.test(wallet)) {
+    res.status(400).json({ error: "invalid wallet" });
+    return;
+  }
+  execFile("solana", ["account", wallet, "--output", "json"], (error, stdout) => {
+    if (error) {
+      res.status(500).json({ error: "lookup failed" });
+      return;
+    }
+    res.type("application/json").send(stdout);
+  });
+});
+
+app.listen(Number(process.env.PORT ?? 8080));
@@ -0,0 +1,16 @@
+import { AnchorProvider, Program, type Idl } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+const RPC_URL = "https://rpc.helius.xyz";
+const API_KEY = process.env.HELIUS_API_KEY ?? "";
+
+export function rpcEndpoint(): string {
+  return `${RPC_URL}/?api-key=${API_KEY}`;
+}
+
+export async function transferPoints(program: Program<Idl>, provider: AnchorProvider, from: PublicKey, to: PublicKey, amount: number) {
+  return program.methods
+    .transferPoints(amount)
+    .accounts({ from, to, owner: provider.wallet.publicKey })
+    .rpc();
+}
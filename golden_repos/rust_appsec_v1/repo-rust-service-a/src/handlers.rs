@@ -0,0 +1,85 @@
+use std::process::Command;
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use bytes::{Buf, Bytes};
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+
+#[derive(Deserialize)]
+pub struct Search {
+    pub q: String,
+}
+
+#[derive(Deserialize)]
+pub struct Convert {
+    pub input: String,
+    pub format: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct Playlist {
+    pub title: String,
+    pub items: Vec<String>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct User {
+    pub id: i64,
+    pub name: String,
+}
+
+fn internal<E: std::fmt::Display>(err: E) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+pub async fn download(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<Vec<u8>, (StatusCode, String)> {
+    let file = state.media_root.join(&name);
+    tokio::fs::read(file).await.map_err(internal)
+}
+
+pub async fn get_user(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<User>, (StatusCode, String)> {
+    let id: i64 = id.parse().unwrap();
+    let user = sqlx::query_as::<_, User>("SELECT id, name FROM users WHERE id = $1")
+        .bind(id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(internal)?;
+    Ok(Json(user))
+}
+
+pub async fn search(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<Search>,
+) -> Result<Json<Vec<User>>, (StatusCode, String)> {
+    let sql = format!("SELECT id, name FROM users WHERE name LIKE '%{}%'", params.q);
+    let users = sqlx::query_as::<_, User>(&sql)
+        .fetch_all(&state.db)
+        .await
+        .map_err(internal)?;
+    Ok(Json(users))
+}
+
+pub async fn convert(Json(req): Json<Convert>) -> Result<String, (StatusCode, String)> {
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(format!("ffmpeg -i /srv/media/{} -f {} -", req.input, req.format))
+        .output()
+        .map_err(internal)?;
+    Ok(String::from_utf8_lossy(&output.stderr).into_owned())
+}
+
+pub async fn import(body: Bytes) -> Result<Json<Playlist>, (StatusCode, String)> {
+    let playlist: Playlist = bincode::deserialize_from(body.reader()).map_err(internal)?;
+    let title = unsafe { String::from_utf8_unchecked(playlist.title.into_bytes()) };
+    Ok(Json(Playlist { title, items: playlist.items }))
+}
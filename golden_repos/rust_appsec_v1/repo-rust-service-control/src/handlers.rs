@@ -0,0 +1,113 @@
+use std::process::Command;
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+use bincode::Options;
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use crate::AppState;
+
+const MAX_PLAYLIST_BYTES: u64 = 64 * 1024;
+const FORMATS: &[&str] = &["mp3", "ogg", "wav"];
+
+#[derive(Deserialize)]
+pub struct Search {
+    pub q: String,
+}
+
+#[derive(Deserialize)]
+pub struct Convert {
+    pub input: String,
+    pub format: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct Playlist {
+    pub title: String,
+    pub items: Vec<String>,
+}
+
+#[derive(Serialize, sqlx::FromRow)]
+pub struct User {
+    pub id: i64,
+    pub name: String,
+}
+
+fn internal<E: std::fmt::Display>(err: E) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+fn bad_request<E: std::fmt::Display>(err: E) -> (StatusCode, String) {
+    (StatusCode::BAD_REQUEST, err.to_string())
+}
+
+pub async fn download(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<Vec<u8>, (StatusCode, String)> {
+    let root = state.media_root.canonicalize().map_err(internal)?;
+    let file = root.join(&name).canonicalize().map_err(bad_request)?;
+    if !file.starts_with(&root) {
+        return Err((StatusCode::FORBIDDEN, "outside the media root".into()));
+    }
+    tokio::fs::read(file).await.map_err(internal)
+}
+
+pub async fn get_user(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<User>, (StatusCode, String)> {
+    let id: i64 = id.parse().map_err(bad_request)?;
+    let user = sqlx::query_as::<_, User>("SELECT id, name FROM users WHERE id = $1")
+        .bind(id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(internal)?;
+    Ok(Json(user))
+}
+
+pub async fn search(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<Search>,
+) -> Result<Json<Vec<User>>, (StatusCode, String)> {
+    let pattern = format!("%{}%", params.q);
+    let users = sqlx::query_as::<_, User>("SELECT id, name FROM users WHERE name LIKE $1")
+        .bind(pattern)
+        .fetch_all(&state.db)
+        .await
+        .map_err(internal)?;
+    Ok(Json(users))
+}
+
+pub async fn convert(
+    State(state): State<Arc<AppState>>,
+    Json(req): Json<Convert>,
+) -> Result<String, (StatusCode, String)> {
+    if !FORMATS.contains(&req.format.as_str()) {
+        return Err(bad_request("unsupported format"));
+    }
+    let root = state.media_root.canonicalize().map_err(internal)?;
+    let input = root.join(&req.input).canonicalize().map_err(bad_request)?;
+    if !input.starts_with(&root) {
+        return Err((StatusCode::FORBIDDEN, "outside the media root".into()));
+    }
+    let output = Command::new("ffmpeg")
+        .arg("-i")
+        .arg(&input)
+        .args(["-f", req.format.as_str(), "-"])
+        .output()
+        .map_err(internal)?;
+    Ok(String::from_utf8_lossy(&output.stderr).into_owned())
+}
+
+pub async fn import(body: Bytes) -> Result<Json<Playlist>, (StatusCode, String)> {
+    let playlist: Playlist = bincode::options()
+        .with_limit(MAX_PLAYLIST_BYTES)
+        .deserialize(&body)
+        .map_err(bad_request)?;
+    let title = String::from_utf8(playlist.title.into_bytes()).map_err(bad_request)?;
+    Ok(Json(Playlist { title, items: playlist.items }))
+}
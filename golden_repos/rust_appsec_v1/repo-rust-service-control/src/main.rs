@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{get, post};
+use axum::Router;
+use sqlx::PgPool;
+
+mod handlers;
+
+const MAX_BODY_BYTES: usize = 8 * 1024 * 1024;
+
+pub struct AppState {
+    pub db: PgPool,
+    pub media_root: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let db = PgPool::connect(&std::env::var("DATABASE_URL")?).await?;
+    let state = Arc::new(AppState { db, media_root: PathBuf::from("/srv/media") });
+
+    let app = Router::new()
+        .route("/media/:name", get(handlers::download))
+        .route("/users/:id", get(handlers::get_user))
+        .route("/search", get(handlers::search))
+        .route("/convert", post(handlers::convert))
+        .route("/import", post(handlers::import))
+        .layer(DefaultBodyLimit::max(MAX_BODY_BYTES))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
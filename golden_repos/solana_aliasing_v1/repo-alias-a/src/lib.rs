@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod ledger {
+    use super::*;
+
+    pub fn transfer_points(ctx: Context<TransferPoints>, amount: u64) -> Result<()> {
+        let from = &mut ctx.accounts.from;
+        from.points = from.points.checked_sub(amount).ok_or(LedgerError::InsufficientPoints)?;
+        let to = &mut ctx.accounts.to;
+        to.points = to.points.checked_add(amount).ok_or(LedgerError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn rebalance(ctx: Context<Rebalance>, amount: u64) -> Result<()> {
+        let source = &mut ctx.accounts.source;
+        source.reserve = source.reserve.checked_sub(amount).ok_or(LedgerError::InsufficientPoints)?;
+        let dest = &mut ctx.accounts.dest;
+        dest.reserve = dest.reserve.checked_add(amount).ok_or(LedgerError::Overflow)?;
+        let buffer = &mut ctx.accounts.buffer;
+        buffer.rebalances = buffer.rebalances.checked_add(1).ok_or(LedgerError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn flip_points(ctx: Context<FlipPoints>) -> Result<()> {
+        let left_points = ctx.accounts.left.points;
+        ctx.accounts.left.points = ctx.accounts.right.points;
+        ctx.accounts.right.points = left_points;
+        Ok(())
+    }
+
+    pub fn merge_positions(ctx: Context<MergePositions>) -> Result<()> {
+        let self_merge = ctx.accounts.keep.key() == ctx.accounts.retire.key();
+        let moved = ctx.accounts.retire.points;
+        let keep = &mut ctx.accounts.keep;
+        keep.points = keep.points.checked_add(moved).ok_or(LedgerError::Overflow)?;
+        ctx.accounts.retire.points = 0;
+        msg!("merged {} points, self merge: {}", moved, self_merge);
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub points: u64,
+}
+
+#[account]
+pub struct Vault {
+    pub reserve: u64,
+    pub rebalances: u64,
+}
+
+#[derive(Accounts)]
+pub struct TransferPoints<'info> {
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Position>,
+    #[account(mut)]
+    pub to: Account<'info, Position>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Rebalance<'info> {
+    #[account(mut)]
+    pub source: Account<'info, Vault>,
+    #[account(mut, constraint = source.key() != dest.key() @ LedgerError::SameVault)]
+    pub dest: Account<'info, Vault>,
+    #[account(mut)]
+    pub buffer: Account<'info, Vault>,
+    pub operator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FlipPoints<'info> {
+    #[account(mut, has_one = owner)]
+    pub left: Account<'info, Position>,
+    #[account(mut, has_one = owner, constraint = left.points != right.points @ LedgerError::NothingToFlip)]
+    pub right: Account<'info, Position>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MergePositions<'info> {
+    #[account(mut, has_one = owner)]
+    pub keep: Account<'info, Position>,
+    #[account(mut, has_one = owner)]
+    pub retire: Account<'info, Position>,
+    pub owner: Signer<'info>,
+}
+
+#[error_code]
+pub enum LedgerError {
+    #[msg("Not enough points")]
+    InsufficientPoints,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Source and destination vault are the same account")]
+    SameVault,
+    #[msg("Both positions hold the same points")]
+    NothingToFlip,
+}
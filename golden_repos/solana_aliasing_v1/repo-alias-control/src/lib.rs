@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod ledger {
+    use super::*;
+
+    pub fn transfer_points(ctx: Context<TransferPoints>, amount: u64) -> Result<()> {
+        let from = &mut ctx.accounts.from;
+        from.points = from.points.checked_sub(amount).ok_or(LedgerError::InsufficientPoints)?;
+        let to = &mut ctx.accounts.to;
+        to.points = to.points.checked_add(amount).ok_or(LedgerError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn reorder_positions(ctx: Context<ReorderPositions>) -> Result<()> {
+        require_keys_neq!(ctx.accounts.left.key(), ctx.accounts.right.key(), LedgerError::SamePosition);
+        let left_points = ctx.accounts.left.points;
+        ctx.accounts.left.points = ctx.accounts.right.points;
+        ctx.accounts.right.points = left_points;
+        Ok(())
+    }
+
+    pub fn rebalance(ctx: Context<Rebalance>, amount: u64) -> Result<()> {
+        let source = &mut ctx.accounts.source;
+        source.reserve = source.reserve.checked_sub(amount).ok_or(LedgerError::InsufficientPoints)?;
+        let dest = &mut ctx.accounts.dest;
+        dest.reserve = dest.reserve.checked_add(amount).ok_or(LedgerError::Overflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub points: u64,
+}
+
+#[account]
+pub struct Vault {
+    pub reserve: u64,
+}
+
+#[derive(Accounts)]
+pub struct TransferPoints<'info> {
+    #[account(mut, has_one = owner)]
+    pub from: Account<'info, Position>,
+    #[account(mut, constraint = from.key() != to.key() @ LedgerError::SamePosition)]
+    pub to: Account<'info, Position>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReorderPositions<'info> {
+    #[account(mut, has_one = owner)]
+    pub left: Account<'info, Position>,
+    #[account(mut, has_one = owner)]
+    pub right: Account<'info, Position>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Rebalance<'info> {
+    #[account(mut, seeds = [b"vault_hot"], bump)]
+    pub source: Account<'info, Vault>,
+    #[account(mut, seeds = [b"vault_cold"], bump)]
+    pub dest: Account<'info, Vault>,
+    pub operator: Signer<'info>,
+}
+
+#[error_code]
+pub enum LedgerError {
+    #[msg("Not enough points")]
+    InsufficientPoints,
+    #[msg("Arithmetic overflow")]
+    Overflow,
+    #[msg("Both positions are the same account")]
+    SamePosition,
+}
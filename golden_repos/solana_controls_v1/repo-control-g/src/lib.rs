@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod control_g {
+    use super::*;
+
+    pub fn sweep(ctx: Context<Sweep>) -> Result<()> {
+        let target = &ctx.accounts.target_program;
+        require_keys_eq!(target.key(), token::ID);
+        msg!("sweep checked {}", target.key());
+        Ok(())
+    }
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.total_deposits = vault.total_deposits.checked_add(amount).ok_or(VaultError::Overflow)?;
+        msg!("deposit invoke( {}", amount);
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.user_tokens.to_account_info(),
+            to: ctx.accounts.vault_tokens.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
+            amount,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct Sweep<'info> {
+    pub authority: Signer<'info>,
+    /// CHECK: compared against the token program id in the handler
+    pub target_program: UncheckedAccount<'info>,
+    /// CHECK: only logged, never invoked
+    pub token_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, has_one = authority, has_one = vault_tokens)]
+    pub vault: Account<'info, Vault>,
+    pub authority: SystemAccount<'info>,
+    #[account(mut, token::mint = vault.mint, token::authority = user)]
+    pub user_tokens: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = vault.mint)]
+    pub vault_tokens: Account<'info, TokenAccount>,
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[account]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub vault_tokens: Pubkey,
+    pub total_deposits: u64,
+}
+
+#[error_code]
+pub enum VaultError {
+    #[msg("deposit total overflow")]
+    Overflow,
+}
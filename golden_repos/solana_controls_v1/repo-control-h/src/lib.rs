@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+const RESIZE_NOTE: &str = "config never shrinks, so .realloc(len, false) is not needed";
+
+#[program]
+pub mod control_h {
+    use super::*;
+
+    pub fn update_config(ctx: Context<UpdateConfig>, fee_bps: u16) -> Result<()> {
+        require!(fee_bps <= 1_000, ConfigError::FeeTooHigh);
+        let config = &mut ctx.accounts.config;
+        config.fee_bps = fee_bps;
+        msg!("fee updated; clients should not invoke( the old program id");
+        msg!("{}", RESIZE_NOTE);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, has_one = admin)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+}
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[error_code]
+pub enum ConfigError {
+    #[msg("fee above 10%")]
+    FeeTooHigh,
+}
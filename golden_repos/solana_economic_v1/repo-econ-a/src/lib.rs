@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use pyth_sdk_solana::load_price_feed_from_account_info;
+
+declare_id!("11111111111111111111111111111111");
+
+const FEE_BPS: u128 = 30;
+const BPS_DENOMINATOR: u128 = 10_000;
+
+#[program]
+pub mod econ_a {
+    use super::*;
+
+    pub fn swap(ctx: Context<Swap>, amount_in: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let amount_in = amount_in as u128;
+        let fee = amount_in.checked_mul(FEE_BPS).ok_or(EconError::MathOverflow)?.checked_div(BPS_DENOMINATOR).ok_or(EconError::MathOverflow)?;
+        let net_in = amount_in.checked_sub(fee).ok_or(EconError::MathOverflow)?;
+        let reserve_in = pool.reserve_a as u128;
+        let reserve_out = pool.reserve_b as u128;
+        let denominator = reserve_in.checked_add(net_in).ok_or(EconError::MathOverflow)?;
+        require!(denominator > 0, EconError::EmptyPool);
+        let out = reserve_out.checked_mul(net_in).ok_or(EconError::MathOverflow)?.checked_div(denominator).ok_or(EconError::MathOverflow)?;
+        pool.reserve_a = u64::try_from(denominator).map_err(|_| EconError::MathOverflow)?;
+        pool.reserve_b = u64::try_from(reserve_out.checked_sub(out).ok_or(EconError::MathOverflow)?).map_err(|_| EconError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        let vault_balance = ctx.accounts.vault_tokens.amount as u128;
+        let supply = ctx.accounts.share_mint.supply as u128;
+        require!(vault_balance > 0, EconError::EmptyPool);
+        let shares = (amount as u128).checked_mul(supply).ok_or(EconError::MathOverflow)?.checked_div(vault_balance).ok_or(EconError::MathOverflow)?;
+        require!(shares > 0, EconError::ZeroShares);
+        ctx.accounts.vault.pending_shares = u64::try_from(shares).map_err(|_| EconError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn borrow(ctx: Context<Borrow>, amount: u64) -> Result<()> {
+        let feed = load_price_feed_from_account_info(&ctx.accounts.price_feed).map_err(|_| EconError::BadOracle)?;
+        let price = feed.get_price_unchecked();
+        let price_value = u128::try_from(price.price).map_err(|_| EconError::BadOracle)?;
+        let collateral_value = (ctx.accounts.vault.collateral as u128).checked_mul(price_value).ok_or(EconError::MathOverflow)?;
+        require!(collateral_value >= amount as u128, EconError::Undercollateralized);
+        ctx.accounts.vault.debt = ctx.accounts.vault.debt.checked_add(amount).ok_or(EconError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Pool {
+    pub authority: Pubkey,
+    pub reserve_a: u64,
+    pub reserve_b: u64,
+}
+
+#[account]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub oracle: Pubkey,
+    pub collateral: u64,
+    pub debt: u64,
+    pub pending_shares: u64,
+}
+
+#[error_code]
+pub enum EconError {
+    MathOverflow,
+    EmptyPool,
+    ZeroShares,
+    BadOracle,
+    Undercollateralized,
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    #[account(mut, has_one = authority)]
+    pub pool: Account<'info, Pool>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, has_one = authority)]
+    pub vault: Account<'info, Vault>,
+    pub vault_tokens: Account<'info, TokenAccount>,
+    pub share_mint: Account<'info, Mint>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Borrow<'info> {
+    #[account(mut, has_one = authority)]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: pinned to the vault's configured oracle.
+    #[account(address = vault.oracle)]
+    pub price_feed: AccountInfo<'info>,
+    pub authority: Signer<'info>,
+}
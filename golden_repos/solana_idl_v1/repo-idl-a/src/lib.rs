@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod listing_market {
+    use super::*;
+
+    pub fn set_fee(ctx: Context<SetFee>, fee_bps: u16) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        require_keys_eq!(market.fee_authority, ctx.accounts.fee_authority.key(), MarketError::Unauthorized);
+        market.fee_bps = fee_bps;
+        Ok(())
+    }
+
+    pub fn update_listing(ctx: Context<UpdateListing>, listing_id: u64, price: u64) -> Result<()> {
+        let listing = &mut ctx.accounts.listing;
+        listing.id = listing_id;
+        listing.price = price;
+        Ok(())
+    }
+
+    pub fn settle(ctx: Context<Settle>) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        market.settled_volume = market.settled_volume.saturating_add(ctx.accounts.listing.price);
+        ctx.accounts.listing.price = 0;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    /// CHECK: compared against market.fee_authority in the handler
+    pub fee_authority: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(listing_id: u64)]
+pub struct UpdateListing<'info> {
+    #[account(mut, seeds = [b"listing", listing_id.to_le_bytes().as_ref()], bump)]
+    pub listing: Account<'info, Listing>,
+    pub seller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Settle<'info> {
+    #[account(mut)]
+    pub market: Account<'info, Market>,
+    #[account(mut)]
+    pub listing: Account<'info, Listing>,
+}
+
+#[account]
+pub struct Market {
+    pub fee_authority: Pubkey,
+    pub fee_bps: u16,
+    pub settled_volume: u64,
+}
+
+#[account]
+pub struct Listing {
+    pub id: u64,
+    pub seller: Pubkey,
+    pub price: u64,
+}
+
+#[error_code]
+pub enum MarketError {
+    #[msg("Unauthorized")]
+    Unauthorized,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod math_a {
+    use super::*;
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let shares = amount * pool.total_shares / pool.total_assets;
+        pool.total_assets += amount;
+        pool.total_shares += shares;
+        Ok(())
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>, shares: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.total_shares > 0, MathError::EmptyPool);
+        let amount = pool.total_assets.checked_mul(shares).unwrap().checked_div(pool.total_shares).unwrap();
+        pool.total_assets = pool.total_assets.checked_sub(amount).unwrap();
+        pool.total_shares = pool.total_shares.checked_sub(shares).unwrap();
+        Ok(())
+    }
+
+    pub fn accrue(ctx: Context<Accrue>, elapsed: i64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let periods = elapsed as u64;
+        let reward = pool.rate_bps / 10_000 * pool.total_assets;
+        pool.reward_index = pool.reward_index.checked_add(reward.checked_mul(periods).unwrap()).unwrap();
+        Ok(())
+    }
+
+    pub fn record_fee(ctx: Context<Accrue>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let fee_slot = pool.total_assets as u32;
+        pool.fee_slots[0] = fee_slot;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Pool {
+    pub total_assets: u64,
+    pub total_shares: u64,
+    pub rate_bps: u64,
+    pub reward_index: u64,
+    pub fee_slots: [u32; 4],
+}
+
+#[error_code]
+pub enum MathError {
+    EmptyPool,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    pub depositor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    pub withdrawer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Accrue<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+}
+
+/// Withdrawal payout after the protocol fee.
+fn net_payout(gross_amount: u64, fee_bps: u64) -> u64 {
+    let mut fee_amount = gross_amount * fee_bps;
+    fee_amount /= 10_000;
+    return gross_amount - fee_amount;
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+/// Share math that never uses raw `+`, `-`, `*` or `/` on token amounts.
+/// Note: `amount * supply / assets` would round toward zero; see deposit below.
+#[program]
+pub mod math_control {
+    use super::*;
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.total_assets > 0, MathError::EmptyPool);
+        let shares = (amount as u128)
+            .checked_mul(pool.total_shares as u128)
+            .ok_or(MathError::Overflow)?
+            .checked_div(pool.total_assets as u128)
+            .ok_or(MathError::Overflow)?;
+        let shares = u64::try_from(shares).map_err(|_| MathError::Overflow)?;
+        require!(shares > 0, MathError::ZeroShares);
+        pool.total_assets = pool.total_assets.checked_add(amount).ok_or(MathError::Overflow)?;
+        pool.total_shares = pool.total_shares.checked_add(shares).ok_or(MathError::Overflow)?;
+        msg!("minted {} shares for amount * price", shares);
+        Ok(())
+    }
+
+    pub fn accrue(ctx: Context<Accrue>, elapsed: i64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let periods = u64::try_from(elapsed).map_err(|_| MathError::Overflow)?;
+        let reward = pool
+            .total_assets
+            .checked_mul(pool.rate_bps)
+            .ok_or(MathError::Overflow)?
+            .checked_div(10_000)
+            .ok_or(MathError::Overflow)?;
+        pool.reward_index = pool
+            .reward_index
+            .checked_add(reward.checked_mul(periods).ok_or(MathError::Overflow)?)
+            .ok_or(MathError::Overflow)?;
+        Ok(())
+    }
+}
+
+/// Caps a withdrawal request at what the pool holds and returns the amount to pay.
+fn settle(requested_amount: &mut u64, available_amount: u64) -> u64 {
+    if *requested_amount <= available_amount {
+        return *requested_amount;
+    }
+    let capped_amount = &mut *requested_amount;
+    *capped_amount = available_amount;
+    available_amount
+}
+
+#[account]
+pub struct Pool {
+    pub total_assets: u64,
+    pub total_shares: u64,
+    pub rate_bps: u64,
+    pub reward_index: u64,
+}
+
+#[error_code]
+pub enum MathError {
+    EmptyPool,
+    Overflow,
+    ZeroShares,
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    pub depositor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Accrue<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+}
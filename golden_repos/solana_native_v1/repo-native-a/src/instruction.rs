@@ -0,0 +1,9 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub enum VaultInstruction {
+    Deposit { amount: u64 },
+    Withdraw { amount: u64 },
+    Sweep { amount: u64 },
+    CreateRecord { record_bump: u8 },
+}
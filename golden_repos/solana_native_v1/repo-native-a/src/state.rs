@@ -0,0 +1,14 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Vault {
+    pub authority: Pubkey,
+    pub balance: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct UserRecord {
+    pub owner: Pubkey,
+    pub balance: u64,
+}
@@ -0,0 +1,11 @@
+use solana_program::{account_info::AccountInfo, entrypoint, entrypoint::ProgramResult, pubkey::Pubkey};
+
+pub mod instruction;
+pub mod processor;
+pub mod state;
+
+entrypoint!(process_instruction);
+
+pub fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    processor::process(program_id, accounts, instruction_data)
+}
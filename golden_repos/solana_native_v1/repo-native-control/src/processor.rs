@@ -0,0 +1,96 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::instruction::VaultInstruction;
+use crate::state::{AccountType, UserRecord, Vault};
+
+pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    match VaultInstruction::try_from_slice(instruction_data)? {
+        VaultInstruction::Deposit { amount } => process_deposit(program_id, accounts, amount),
+        VaultInstruction::Withdraw { amount } => process_withdraw(program_id, accounts, amount),
+        VaultInstruction::Sweep { amount } => process_sweep(accounts, amount),
+        VaultInstruction::CreateRecord { .. } => process_create_record(program_id, accounts),
+    }
+}
+
+fn process_deposit(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let depositor = next_account_info(account_iter)?;
+    let vault_info = next_account_info(account_iter)?;
+    if !depositor.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if vault_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut vault = Vault::try_from_slice(&vault_info.data.borrow())?;
+    if vault.account_type != AccountType::Vault {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    vault.balance = vault.balance.checked_add(amount).ok_or(ProgramError::ArithmeticOverflow)?;
+    vault.serialize(&mut &mut vault_info.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+fn process_withdraw(program_id: &Pubkey, accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let authority = next_account_info(account_iter)?;
+    let vault_info = next_account_info(account_iter)?;
+    if !authority.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if vault_info.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut vault = Vault::try_from_slice(&vault_info.data.borrow())?;
+    if vault.account_type != AccountType::Vault || vault.authority != *authority.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    vault.balance = vault.balance.checked_sub(amount).ok_or(ProgramError::InsufficientFunds)?;
+    vault.serialize(&mut &mut vault_info.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+fn process_sweep(accounts: &[AccountInfo], amount: u64) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let owner = next_account_info(account_iter)?;
+    let source = next_account_info(account_iter)?;
+    let destination = next_account_info(account_iter)?;
+    let token_program = next_account_info(account_iter)?;
+    if !owner.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if *token_program.key != spl_token::id() {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let ix = spl_token::instruction::transfer(token_program.key, source.key, destination.key, owner.key, &[], amount)?;
+    invoke(&ix, &[source.clone(), destination.clone(), owner.clone(), token_program.clone()])
+}
+
+fn process_create_record(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let user = next_account_info(account_iter)?;
+    let record_info = next_account_info(account_iter)?;
+    if !user.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (expected, _bump) = Pubkey::find_program_address(&[b"record", user.key.as_ref()], program_id);
+    if expected != *record_info.key {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    let mut record = UserRecord::try_from_slice(&record_info.data.borrow())?;
+    record.account_type = AccountType::UserRecord;
+    record.owner = *user.key;
+    record.serialize(&mut &mut record_info.data.borrow_mut()[..])?;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq)]
+pub enum AccountType {
+    Uninitialized,
+    Vault,
+    UserRecord,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct Vault {
+    pub account_type: AccountType,
+    pub authority: Pubkey,
+    pub balance: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct UserRecord {
+    pub account_type: AccountType,
+    pub owner: Pubkey,
+    pub balance: u64,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod owner_anchor {
+    use super::*;
+
+    pub fn set_fee(ctx: Context<SetFee>, fee_bps: u16) -> Result<()> {
+        let config = Config::try_deserialize(&mut &ctx.accounts.config.data.borrow()[..])?;
+        require_keys_eq!(config.admin, ctx.accounts.caller.key(), OwnerError::Unauthorized);
+        ctx.accounts.fee_state.fee_bps = fee_bps;
+        Ok(())
+    }
+
+    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        let stats_info = &ctx.accounts.user_stats;
+        let data = stats_info.try_borrow_data()?;
+        let stats = UserStats::try_from_slice(&data[8..])?;
+        require_keys_eq!(stats.user, ctx.accounts.user.key(), OwnerError::Unauthorized);
+        require!(stats.claimable > 0, OwnerError::NothingToClaim);
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+}
+
+#[account]
+pub struct FeeState {
+    pub fee_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UserStats {
+    pub user: Pubkey,
+    pub claimable: u64,
+}
+
+#[error_code]
+pub enum OwnerError {
+    Unauthorized,
+    NothingToClaim,
+}
+
+#[derive(Accounts)]
+pub struct SetFee<'info> {
+    /// CHECK: deserialized manually in the handler.
+    pub config: AccountInfo<'info>,
+    #[account(mut)]
+    pub fee_state: Account<'info, FeeState>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    /// CHECK: deserialized manually in the handler.
+    pub user_stats: UncheckedAccount<'info>,
+    pub user: Signer<'info>,
+}
@@ -0,0 +1,57 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint,
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_pack::Pack,
+    pubkey::Pubkey,
+};
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub paused: bool,
+}
+
+entrypoint!(process_instruction);
+
+pub fn process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    match instruction_data.first() {
+        Some(0) => process_pause(program_id, accounts),
+        Some(1) => process_redeem(accounts),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn process_pause(_program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let admin = next_account_info(account_iter)?;
+    let config_info = next_account_info(account_iter)?;
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let mut config = Config::try_from_slice(&config_info.data.borrow())?;
+    if config.admin != *admin.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    config.paused = true;
+    config.serialize(&mut &mut config_info.data.borrow_mut()[..])?;
+    Ok(())
+}
+
+fn process_redeem(accounts: &[AccountInfo]) -> ProgramResult {
+    let account_iter = &mut accounts.iter();
+    let user = next_account_info(account_iter)?;
+    let token_info = next_account_info(account_iter)?;
+    if !user.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let token = spl_token::state::Account::unpack(&token_info.data.borrow())?;
+    if token.owner != *user.key || token.amount == 0 {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(())
+}
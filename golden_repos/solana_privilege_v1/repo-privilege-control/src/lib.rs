@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+declare_id!("11111111111111111111111111111111");
+
+pub const PAYOUT_SEED: &[u8] = b"payout_authority";
+pub const REWARD_SEED: &[u8] = b"reward_authority";
+
+#[program]
+pub mod treasury {
+    use super::*;
+
+    pub fn record_visit(ctx: Context<RecordVisit>) -> Result<()> {
+        require!(ctx.accounts.config.open, TreasuryError::Closed);
+        let visitor = &mut ctx.accounts.visitor;
+        visitor.visits = visitor.visits.checked_add(1).ok_or(TreasuryError::Overflow)?;
+        Ok(())
+    }
+
+    pub fn pay_out(ctx: Context<PayOut>, amount: u64) -> Result<()> {
+        let bump = ctx.bumps.treasury_authority;
+        let seeds: &[&[u8]] = &[PAYOUT_SEED, &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.treasury_vault.to_account_info(),
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: ctx.accounts.treasury_authority.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+
+    pub fn mint_rewards(ctx: Context<MintRewards>, amount: u64) -> Result<()> {
+        let bump = ctx.bumps.reward_authority;
+        let seeds: &[&[u8]] = &[REWARD_SEED, &[bump]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.reward_mint.to_account_info(),
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: ctx.accounts.reward_authority.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub mint: Pubkey,
+    pub reward_mint: Pubkey,
+    pub auditor: Pubkey,
+    pub open: bool,
+}
+
+#[account]
+pub struct Visitor {
+    pub visits: u64,
+}
+
+#[derive(Accounts)]
+pub struct RecordVisit<'info> {
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [b"visitor", user.key().as_ref()], bump)]
+    pub visitor: Account<'info, Visitor>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PayOut<'info> {
+    #[account(has_one = admin)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    #[account(address = config.auditor)]
+    pub auditor: Signer<'info>,
+    /// CHECK: PDA that only owns the treasury vault
+    #[account(seeds = [PAYOUT_SEED], bump)]
+    pub treasury_authority: UncheckedAccount<'info>,
+    #[account(mut, token::mint = mint, token::authority = treasury_authority)]
+    pub treasury_vault: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub recipient_token: Account<'info, TokenAccount>,
+    #[account(address = config.mint)]
+    pub mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct MintRewards<'info> {
+    #[account(has_one = admin)]
+    pub config: Account<'info, Config>,
+    pub admin: Signer<'info>,
+    /// CHECK: PDA that only holds the reward mint authority
+    #[account(seeds = [REWARD_SEED], bump)]
+    pub reward_authority: UncheckedAccount<'info>,
+    #[account(mut, address = config.reward_mint)]
+    pub reward_mint: Account<'info, Mint>,
+    #[account(mut, token::mint = reward_mint)]
+    pub recipient_token: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[error_code]
+pub enum TreasuryError {
+    #[msg("Treasury is closed")]
+    Closed,
+    #[msg("Visit counter overflow")]
+    Overflow,
+}
@@ -0,0 +1,200 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+declare_id!("11111111111111111111111111111111");
+
+pub const NOTE_SEED: &[u8] = b"note";
+
+#[program]
+pub mod profiles {
+    use super::*;
+
+    pub fn create_profile(ctx: Context<CreateProfile>, display_name: String) -> Result<()> {
+        let profile = &mut ctx.accounts.profile;
+        profile.owner = ctx.accounts.owner.key();
+        profile.display_name = display_name;
+        Ok(())
+    }
+
+    pub fn rename_profile(ctx: Context<RenameProfile>, display_name: String) -> Result<()> {
+        ctx.accounts.profile.display_name = display_name;
+        Ok(())
+    }
+
+    pub fn configure_mint(ctx: Context<ConfigureMint>, fee_bps: u16) -> Result<()> {
+        let config = &mut ctx.accounts.mint_config;
+        config.mint = ctx.accounts.mint.key();
+        config.fee_bps = fee_bps;
+        Ok(())
+    }
+
+    pub fn post_note(ctx: Context<PostNote>, topic: String, title: String, body: String) -> Result<()> {
+        let note = &mut ctx.accounts.note;
+        note.author = ctx.accounts.author.key();
+        note.topic = topic;
+        note.title = title;
+        note.body = body;
+        Ok(())
+    }
+
+    pub fn open_escrow(ctx: Context<OpenEscrow>, escrow_id: u64, amount: u64) -> Result<()> {
+        let escrow = &mut ctx.accounts.escrow;
+        escrow.maker = ctx.accounts.maker.key();
+        escrow.escrow_id = escrow_id;
+        escrow.amount = amount;
+        Ok(())
+    }
+
+    pub fn make_offer(ctx: Context<MakeOffer>, price: u64) -> Result<()> {
+        let offer = &mut ctx.accounts.offer;
+        offer.maker = ctx.accounts.maker.key();
+        offer.price = price;
+        Ok(())
+    }
+
+    pub fn record_receipt(ctx: Context<RecordReceipt>, reference: Vec<u8>, amount: u64) -> Result<()> {
+        require!(reference.len() <= 32, ProfileError::ReferenceTooLong);
+        let (expected, _) = receipt_address(ctx.program_id, &reference);
+        require_keys_eq!(expected, ctx.accounts.receipt.key(), ProfileError::InvalidReceipt);
+        let mut data = ctx.accounts.receipt.try_borrow_mut_data()?;
+        data[..8].copy_from_slice(&amount.to_le_bytes());
+        Ok(())
+    }
+}
+
+/// Address of the receipt recorded for an off-chain payment reference.
+pub fn receipt_address(program_id: &Pubkey, reference: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"user", reference], program_id)
+}
+
+#[account]
+pub struct UserProfile {
+    pub owner: Pubkey,
+    pub display_name: String,
+}
+
+#[account]
+pub struct MintConfig {
+    pub mint: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[account]
+pub struct Note {
+    pub author: Pubkey,
+    pub topic: String,
+    pub title: String,
+    pub body: String,
+}
+
+#[account]
+pub struct Escrow {
+    pub maker: Pubkey,
+    pub escrow_id: u64,
+    pub amount: u64,
+}
+
+#[account]
+pub struct Offer {
+    pub maker: Pubkey,
+    pub price: u64,
+}
+
+#[error_code]
+pub enum ProfileError {
+    ReferenceTooLong,
+    InvalidReceipt,
+}
+
+#[derive(Accounts)]
+pub struct CreateProfile<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + 32 + 4 + 64,
+        seeds = [b"user", owner.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, UserProfile>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RenameProfile<'info> {
+    #[account(mut, seeds = [b"user", owner.key().as_ref()], bump, has_one = owner)]
+    pub profile: Account<'info, UserProfile>,
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureMint<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + 32 + 2,
+        seeds = [b"user", mint.key().as_ref()],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    pub mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(topic: String, title: String)]
+pub struct PostNote<'info> {
+    #[account(
+        init,
+        payer = author,
+        space = 8 + 32 + 4 * 3 + 256,
+        seeds = [NOTE_SEED, topic.as_bytes(), title.as_bytes()],
+        bump
+    )]
+    pub note: Account<'info, Note>,
+    #[account(mut)]
+    pub author: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(escrow_id: u64)]
+pub struct OpenEscrow<'info> {
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + 32 + 8 + 8,
+        seeds = [b"escrow", maker.key().as_ref(), &escrow_id.to_le_bytes()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MakeOffer<'info> {
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + 32 + 8,
+        seeds = [b"escrow_offer", maker.key().as_ref()],
+        bump
+    )]
+    pub offer: Account<'info, Offer>,
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordReceipt<'info> {
+    /// CHECK: address verified against receipt_address in the handler.
+    #[account(mut)]
+    pub receipt: UncheckedAccount<'info>,
+    pub payer: Signer<'info>,
+}
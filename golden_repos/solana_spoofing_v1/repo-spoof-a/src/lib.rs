@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+declare_id!("11111111111111111111111111111111");
+
+pub const FEE_LAMPORTS: u64 = 5_000;
+
+#[program]
+pub mod aggregator {
+    use super::*;
+
+    pub fn settle_routes(ctx: Context<SettleRoutes>) -> Result<()> {
+        let mut total_liquidity: u64 = 0;
+        for pool_info in ctx.remaining_accounts.iter() {
+            let pool = Pool::try_deserialize(&mut &pool_info.data.borrow()[..])?;
+            total_liquidity = total_liquidity.checked_add(pool.liquidity).ok_or(AggregatorError::Overflow)?;
+        }
+        ctx.accounts.state.total_liquidity = total_liquidity;
+        Ok(())
+    }
+
+    pub fn collect_fee(ctx: Context<CollectFee>) -> Result<()> {
+        let fee_receiver = ctx.remaining_accounts.get(0).ok_or(AggregatorError::MissingFeeReceiver)?;
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: fee_receiver.clone(),
+                },
+            ),
+            FEE_LAMPORTS,
+        )?;
+        Ok(())
+    }
+
+    pub fn flash_borrow(ctx: Context<FlashBorrow>, amount: u64) -> Result<()> {
+        let data = ctx.accounts.instructions.try_borrow_data()?;
+        let count = u16::from_le_bytes([data[0], data[1]]);
+        require!(count >= 2, AggregatorError::MissingRepay);
+        ctx.accounts.state.flash_outstanding = amount;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Pool {
+    pub liquidity: u64,
+}
+
+#[account]
+pub struct RouterState {
+    pub total_liquidity: u64,
+    pub flash_outstanding: u64,
+    pub treasury: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct SettleRoutes<'info> {
+    #[account(mut)]
+    pub state: Account<'info, RouterState>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CollectFee<'info> {
+    pub state: Account<'info, RouterState>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FlashBorrow<'info> {
+    #[account(mut)]
+    pub state: Account<'info, RouterState>,
+    /// CHECK: instructions sysvar, inspected for the matching repay instruction.
+    pub instructions: AccountInfo<'info>,
+    pub user: Signer<'info>,
+}
+
+#[error_code]
+pub enum AggregatorError {
+    #[msg("Liquidity total overflowed")]
+    Overflow,
+    #[msg("Fee receiver account is missing")]
+    MissingFeeReceiver,
+    #[msg("Transaction does not repay the flash loan")]
+    MissingRepay,
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod state_a {
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>, fee_bps: u16) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.initializer.key();
+        config.fee_bps = fee_bps;
+        Ok(())
+    }
+
+    pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.owner = ctx.accounts.owner.key();
+        position.deposited = 0;
+        Ok(())
+    }
+
+    pub fn close_position(ctx: Context<ClosePosition>) -> Result<()> {
+        let position = ctx.accounts.position.to_account_info();
+        let destination = ctx.accounts.owner.to_account_info();
+        **destination.lamports.borrow_mut() += position.lamports();
+        **position.lamports.borrow_mut() = 0;
+        Ok(())
+    }
+
+    pub fn grow_notes(ctx: Context<GrowNotes>, len: u32) -> Result<()> {
+        ctx.accounts.notes.len = len;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[account]
+pub struct Position {
+    pub owner: Pubkey,
+    pub deposited: u64,
+}
+
+#[account]
+pub struct Notes {
+    pub len: u32,
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+    pub initializer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(init_if_needed, payer = owner, space = 8 + 40, seeds = [b"position", owner.key().as_ref()], bump)]
+    pub position: Account<'info, Position>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut, has_one = owner)]
+    pub position: Account<'info, Position>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(len: u32)]
+pub struct GrowNotes<'info> {
+    #[account(mut, realloc = 8 + 4 + len as usize, realloc::payer = payer, realloc::zero = false)]
+    pub notes: Account<'info, Notes>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod state_control {
+    use super::*;
+
+    pub fn initialize(ctx: Context<Initialize>, fee_bps: u16) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.admin = ctx.accounts.admin.key();
+        config.fee_bps = fee_bps;
+        Ok(())
+    }
+
+    pub fn open_position(ctx: Context<OpenPosition>) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        require!(!position.initialized, StateError::AlreadyInitialized);
+        position.initialized = true;
+        position.owner = ctx.accounts.owner.key();
+        Ok(())
+    }
+
+    /// Never do `**position.lamports.borrow_mut() = 0;` by hand: use `close = owner`.
+    pub fn close_position(_ctx: Context<ClosePosition>) -> Result<()> {
+        Ok(())
+    }
+
+    pub fn grow_notes(ctx: Context<GrowNotes>, len: u32) -> Result<()> {
+        ctx.accounts.notes.len = len;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Config {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+}
+
+#[account]
+pub struct Position {
+    pub initialized: bool,
+    pub owner: Pubkey,
+}
+
+#[account]
+pub struct Notes {
+    pub len: u32,
+}
+
+#[error_code]
+pub enum StateError {
+    AlreadyInitialized,
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(init, payer = admin, space = 8 + 34)]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenPosition<'info> {
+    #[account(init_if_needed, payer = owner, space = 8 + 41, seeds = [b"position", owner.key().as_ref()], bump)]
+    pub position: Account<'info, Position>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePosition<'info> {
+    #[account(mut, has_one = owner, close = owner)]
+    pub position: Account<'info, Position>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(len: u32)]
+pub struct GrowNotes<'info> {
+    #[account(mut, realloc = 8 + 4 + len as usize, realloc::payer = payer, realloc::zero = true)]
+    pub notes: Account<'info, Notes>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
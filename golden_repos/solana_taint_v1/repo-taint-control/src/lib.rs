@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_instruction;
+
+declare_id!("11111111111111111111111111111111");
+
+pub const ROUTER_PROGRAM_ID: Pubkey = pubkey!("Router1111111111111111111111111111111111111");
+
+#[program]
+pub mod relay {
+    use super::*;
+
+    pub fn derive_profile(ctx: Context<DeriveProfile>, seed: u64) -> Result<()> {
+        let profile_seed = seed.to_le_bytes();
+        let (expected, _bump) = Pubkey::find_program_address(&[b"profile", &profile_seed], ctx.program_id);
+        require_keys_eq!(expected, ctx.accounts.profile.key(), RelayError::WrongProfile);
+        Ok(())
+    }
+
+    pub fn forward(ctx: Context<Forward>, callee: Pubkey, payload: Vec<u8>) -> Result<()> {
+        require_keys_eq!(callee, ROUTER_PROGRAM_ID, RelayError::UnknownProgram);
+        let ix = Instruction {
+            program_id: callee,
+            accounts: vec![AccountMeta::new(ctx.accounts.vault.key(), false)],
+            data: payload,
+        };
+        invoke(&ix, &[ctx.accounts.vault.to_account_info()])?;
+        Ok(())
+    }
+
+    pub fn sweep(ctx: Context<Sweep>, lamports: u64) -> Result<()> {
+        let bump = ctx.accounts.config.authority_bump;
+        let signer_seeds: &[&[u8]] = &[b"authority", &[bump]];
+        invoke_signed(
+            &system_instruction::transfer(&ctx.accounts.pool_authority.key(), &ctx.accounts.recipient.key(), lamports),
+            &[
+                ctx.accounts.pool_authority.to_account_info(),
+                ctx.accounts.recipient.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+        Ok(())
+    }
+
+    pub fn route(ctx: Context<Route>, payload: Vec<u8>) -> Result<()> {
+        require_keys_eq!(ctx.accounts.registry.router, ROUTER_PROGRAM_ID, RelayError::UnknownProgram);
+        let ix = Instruction::new_with_bytes(ROUTER_PROGRAM_ID, &payload, vec![]);
+        invoke(&ix, &[])?;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Profile {
+    pub visits: u64,
+}
+
+#[account]
+pub struct Vault {
+    pub balance: u64,
+}
+
+#[account]
+pub struct Config {
+    pub authority_bump: u8,
+}
+
+#[account]
+pub struct Registry {
+    pub router: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct DeriveProfile<'info> {
+    pub profile: Account<'info, Profile>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Forward<'info> {
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+    pub user: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Sweep<'info> {
+    pub config: Account<'info, Config>,
+    /// CHECK: PDA that holds swept lamports; signs through invoke_signed.
+    #[account(mut, seeds = [b"authority"], bump = config.authority_bump)]
+    pub pool_authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+    pub user: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Route<'info> {
+    pub registry: Account<'info, Registry>,
+    pub user: Signer<'info>,
+}
+
+#[error_code]
+pub enum RelayError {
+    #[msg("Profile does not match the derived address")]
+    WrongProfile,
+    #[msg("CPI target is not the router program")]
+    UnknownProgram,
+}
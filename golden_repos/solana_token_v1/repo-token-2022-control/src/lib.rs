@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_2022::spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod hook_pool {
+    use super::*;
+
+    pub fn create_pool(ctx: Context<CreatePool>) -> Result<()> {
+        reject_unsafe_extensions(&ctx.accounts.mint)?;
+        let pool = &mut ctx.accounts.pool;
+        pool.mint = ctx.accounts.mint.key();
+        pool.pool_bump = ctx.bumps.pool;
+        Ok(())
+    }
+
+    pub fn payout(ctx: Context<Payout>, amount: u64) -> Result<()> {
+        let mint_key = ctx.accounts.mint.key();
+        let seeds: &[&[u8]] = &[b"pool", mint_key.as_ref(), &[ctx.accounts.pool.pool_bump]];
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.pool_vault.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: ctx.accounts.winner_ata.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                &[seeds],
+            ),
+            amount,
+            ctx.accounts.mint.decimals,
+        )?;
+        Ok(())
+    }
+}
+
+fn reject_unsafe_extensions(mint: &InterfaceAccount<Mint>) -> Result<()> {
+    let info = mint.to_account_info();
+    let data = info.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    for extension in state.get_extension_types()? {
+        require!(
+            !matches!(extension, ExtensionType::PermanentDelegate | ExtensionType::TransferHook),
+            PoolError::UnsupportedMint
+        );
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreatePool<'info> {
+    #[account(init, payer = payer, space = 8 + Pool::INIT_SPACE, seeds = [b"pool", mint.key().as_ref()], bump)]
+    pub pool: Account<'info, Pool>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = pool,
+        token::token_program = token_program,
+        seeds = [b"pool_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Payout<'info> {
+    #[account(seeds = [b"pool", mint.key().as_ref()], bump = pool.pool_bump)]
+    pub pool: Account<'info, Pool>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(mut, seeds = [b"pool_vault", mint.key().as_ref()], bump)]
+    pub pool_vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = winner,
+        associated_token::token_program = token_program
+    )]
+    pub winner_ata: InterfaceAccount<'info, TokenAccount>,
+    pub winner: SystemAccount<'info>,
+    pub operator: Signer<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Pool {
+    pub mint: Pubkey,
+    pub pool_bump: u8,
+}
+
+#[error_code]
+pub enum PoolError {
+    #[msg("Mints with a permanent delegate or transfer hook are not supported")]
+    UnsupportedMint,
+}
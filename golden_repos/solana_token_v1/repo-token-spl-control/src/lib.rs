@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod token_vault {
+    use super::*;
+
+    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token.to_account_info(),
+                    to: ctx.accounts.vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        ctx.accounts.pool.total_deposits = ctx.accounts.pool.total_deposits.saturating_add(amount);
+        Ok(())
+    }
+
+    pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+        let depositor_key = ctx.accounts.depositor.key();
+        let bump = ctx.bumps.vault_authority;
+        let seeds: &[&[u8]] = &[b"vault", depositor_key.as_ref(), &[bump]];
+        let signer = &[seeds];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.recipient_token.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+        Ok(())
+    }
+
+    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
+        let expected = get_associated_token_address(&ctx.accounts.user.key(), &ctx.accounts.reward_mint.key());
+        require_keys_eq!(ctx.accounts.user_reward.key(), expected, VaultError::WrongRewardAccount);
+        ctx.accounts.pool.claimed = true;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(mut, has_one = vault)]
+    pub pool: Account<'info, Pool>,
+    #[account(mut, token::authority = user)]
+    pub user_token: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub vault: Account<'info, TokenAccount>,
+    pub user: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    pub depositor: Signer<'info>,
+    /// CHECK: PDA signer for the depositor's vault
+    #[account(seeds = [b"vault", depositor.key().as_ref()], bump)]
+    pub vault_authority: UncheckedAccount<'info>,
+    #[account(mut, token::mint = mint, token::authority = vault_authority)]
+    pub vault: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = mint)]
+    pub recipient_token: Account<'info, TokenAccount>,
+    #[account(address = VAULT_MINT)]
+    pub mint: Account<'info, Mint>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReward<'info> {
+    #[account(mut)]
+    pub pool: Account<'info, Pool>,
+    pub reward_mint: Account<'info, Mint>,
+    pub user_reward: Account<'info, TokenAccount>,
+    pub user: Signer<'info>,
+}
+
+pub const VAULT_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
+
+#[account]
+pub struct Pool {
+    pub vault: Pubkey,
+    pub total_deposits: u64,
+    pub claimed: bool,
+}
+
+#[error_code]
+pub enum VaultError {
+    #[msg("Reward account is not the user's associated token account")]
+    WrongRewardAccount,
+}
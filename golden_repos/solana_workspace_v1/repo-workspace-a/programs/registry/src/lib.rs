@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+declare_id!("11111111111111111111111111111111");
+
+pub const ORACLE_PROGRAM_ID: Pubkey = pubkey!("11111111111111111111111111111111");
+
+#[program]
+pub mod registry {
+    use super::*;
+
+    pub fn sync_price(ctx: Context<SyncPrice>) -> Result<()> {
+        let data = ctx.accounts.price_feed.try_borrow_data()?;
+        let feed = FeedSnapshot::try_from_slice(&data)?;
+        ctx.accounts.entry.price = feed.price;
+        ctx.accounts.entry.updated_at = feed.publish_time;
+        Ok(())
+    }
+}
+
+#[account]
+pub struct Entry {
+    pub curator: Pubkey,
+    pub price: u64,
+    pub updated_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FeedSnapshot {
+    pub price: u64,
+    pub publish_time: i64,
+}
+
+#[derive(Accounts)]
+pub struct SyncPrice<'info> {
+    #[account(mut, has_one = curator)]
+    pub entry: Account<'info, Entry>,
+    /// CHECK: owner pinned to the oracle program; layout decoded in the handler.
+    #[account(owner = ORACLE_PROGRAM_ID)]
+    pub price_feed: UncheckedAccount<'info>,
+    pub curator: Signer<'info>,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct Sweep<'info> {
+    #[account(has_one = operator)]
+    pub vault: Account<'info, Vault>,
+    #[account(mut, token::authority = operator)]
+    pub vault_tokens: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = vault_tokens.mint)]
+    pub payout_tokens: Account<'info, TokenAccount>,
+    pub operator: Signer<'info>,
+    /// CHECK: program invoked for the sweep transfer.
+    pub token_program: UncheckedAccount<'info>,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Transfer};
+
+pub mod instructions;
+pub mod state;
+
+use instructions::*;
+
+declare_id!("11111111111111111111111111111111");
+
+#[program]
+pub mod vault {
+    use super::*;
+
+    pub fn sync_price(ctx: Context<SyncPrice>) -> Result<()> {
+        instructions::sync_price::handler(ctx)
+    }
+
+    pub fn release(ctx: Context<Release>) -> Result<()> {
+        instructions::release::handler(ctx)
+    }
+
+    pub fn sweep(ctx: Context<Sweep>, amount: u64) -> Result<()> {
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.vault_tokens.to_account_info(),
+            to: ctx.accounts.payout_tokens.to_account_info(),
+            authority: ctx.accounts.operator.to_account_info(),
+        };
+        token::transfer(CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts), amount)
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct Vault {
+    pub operator: Pubkey,
+    pub last_price: u64,
+    pub owed_lamports: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PriceFeed {
+    pub price: u64,
+    pub publish_time: i64,
+}
+
+pub const MAX_PRICE_AGE: i64 = 60;
+
+/// Canonical address and bump of the PDA that signs lamport releases for `vault`.
+pub fn vault_signer(program_id: &Pubkey, vault: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vault_signer", vault.as_ref()], program_id)
+}
+
+#[error_code]
+pub enum VaultError {
+    StalePrice,
+    InvalidSigner,
+}
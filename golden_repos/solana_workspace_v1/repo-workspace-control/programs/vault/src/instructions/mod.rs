@@ -0,0 +1,7 @@
+pub mod release;
+pub mod sweep;
+pub mod sync_price;
+
+pub use release::*;
+pub use sweep::*;
+pub use sync_price::*;
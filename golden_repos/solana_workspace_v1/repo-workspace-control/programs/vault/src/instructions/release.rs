@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{program::invoke_signed, system_instruction};
+
+use crate::state::{vault_signer, Vault, VaultError};
+
+#[derive(Accounts)]
+pub struct Release<'info> {
+    #[account(mut, has_one = operator)]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub vault_signer: SystemAccount<'info>,
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+    pub operator: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<Release>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let (signer_key, signer_bump) = vault_signer(ctx.program_id, &vault_key);
+    require_keys_eq!(signer_key, ctx.accounts.vault_signer.key(), VaultError::InvalidSigner);
+
+    let amount = ctx.accounts.vault.owed_lamports;
+    ctx.accounts.vault.owed_lamports = 0;
+    invoke_signed(
+        &system_instruction::transfer(&signer_key, &ctx.accounts.recipient.key(), amount),
+        &[
+            ctx.accounts.vault_signer.to_account_info(),
+            ctx.accounts.recipient.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[&[b"vault_signer", vault_key.as_ref(), &[signer_bump]]],
+    )?;
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct Sweep<'info> {
+    #[account(has_one = operator)]
+    pub vault: Account<'info, Vault>,
+    #[account(mut, token::authority = operator)]
+    pub vault_tokens: Account<'info, TokenAccount>,
+    #[account(mut, token::mint = vault_tokens.mint)]
+    pub payout_tokens: Account<'info, TokenAccount>,
+    pub operator: Signer<'info>,
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{PriceFeed, Vault, VaultError, MAX_PRICE_AGE, ORACLE_PROGRAM_ID};
+
+#[derive(Accounts)]
+pub struct SyncPrice<'info> {
+    #[account(mut, has_one = operator)]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: owner pinned to the oracle program; layout decoded in the handler.
+    #[account(owner = ORACLE_PROGRAM_ID)]
+    pub price_feed: UncheckedAccount<'info>,
+    pub operator: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<SyncPrice>) -> Result<()> {
+    let data = ctx.accounts.price_feed.try_borrow_data()?;
+    let feed = PriceFeed::try_from_slice(&data)?;
+    let now = Clock::get()?.unix_timestamp;
+    require!(now - feed.publish_time <= MAX_PRICE_AGE, VaultError::StalePrice);
+    ctx.accounts.vault.last_price = feed.price;
+    Ok(())
+}
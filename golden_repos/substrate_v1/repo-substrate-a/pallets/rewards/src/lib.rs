@@ -0,0 +1,86 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_std::vec::Vec;
+
+    pub type Balance = u128;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    #[pallet::storage]
+    pub type RewardRate<T> = StorageValue<_, Balance, ValueQuery>;
+
+    #[pallet::storage]
+    pub type Points<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    #[pallet::storage]
+    pub type Claimable<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        RewardRateSet { rate: Balance },
+        PointsTransferred { from: T::AccountId, to: T::AccountId, amount: Balance },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        InsufficientPoints,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let rate = RewardRate::<T>::get();
+            for (who, points) in Points::<T>::iter() {
+                Claimable::<T>::mutate(&who, |claimable| *claimable = claimable.saturating_add(points.saturating_mul(rate)));
+            }
+            Weight::from_parts(10_000, 0)
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        pub fn set_reward_rate(origin: OriginFor<T>, rate: Balance) -> DispatchResult {
+            ensure_signed(origin)?;
+            RewardRate::<T>::put(rate);
+            Self::deposit_event(Event::RewardRateSet { rate });
+            Ok(())
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        pub fn award(origin: OriginFor<T>, recipients: Vec<T::AccountId>, points: Balance) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            for who in recipients.iter() {
+                Points::<T>::mutate(who, |current| *current = current.saturating_add(points));
+            }
+            Ok(())
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        pub fn transfer_points(origin: OriginFor<T>, to: T::AccountId, amount: Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let balance = Points::<T>::get(&who);
+            Points::<T>::insert(&who, balance - amount);
+            Points::<T>::mutate(&to, |points| *points = points.saturating_add(amount));
+            Self::deposit_event(Event::PointsTransferred { from: who, to, amount });
+            Ok(())
+        }
+    }
+}
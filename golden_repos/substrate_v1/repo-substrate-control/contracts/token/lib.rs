@@ -0,0 +1,116 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+mod token {
+    use ink::storage::Mapping;
+
+    #[ink(storage)]
+    pub struct Token {
+        owner: AccountId,
+        total_supply: Balance,
+        balances: Mapping<AccountId, Balance>,
+        allowances: Mapping<(AccountId, AccountId), Balance>,
+        fee_bps: u16,
+    }
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum Error {
+        InsufficientBalance,
+        InsufficientAllowance,
+        NotOwner,
+        Overflow,
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    impl Token {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let caller = Self::env().caller();
+            let mut balances = Mapping::default();
+            balances.insert(caller, &total_supply);
+            Self { owner: caller, total_supply, balances, allowances: Mapping::default(), fee_bps: 0 }
+        }
+
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            self.balances.get(owner).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn fee_bps(&self) -> u16 {
+            self.fee_bps
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance) -> Result<()> {
+            let from = self.env().caller();
+            self.transfer_from_to(&from, &to, value)
+        }
+
+        #[ink(message)]
+        pub fn approve(&mut self, spender: AccountId, value: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            self.allowances.insert((owner, spender), &value);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: Balance) -> Result<()> {
+            let caller = self.env().caller();
+            let allowance = self.allowances.get((from, caller)).unwrap_or_default();
+            if allowance < value {
+                return Err(Error::InsufficientAllowance);
+            }
+            self.transfer_from_to(&from, &to, value)?;
+            self.allowances.insert((from, caller), &(allowance - value));
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn mint(&mut self, value: Balance) -> Result<()> {
+            self.ensure_owner()?;
+            let owner = self.owner;
+            let balance = self.balance_of(owner);
+            self.total_supply = self.total_supply.checked_add(value).ok_or(Error::Overflow)?;
+            self.balances.insert(owner, &balance.checked_add(value).ok_or(Error::Overflow)?);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_fee(&mut self, fee_bps: u16) -> Result<()> {
+            self.ensure_owner()?;
+            self.fee_bps = fee_bps;
+            Ok(())
+        }
+
+        fn ensure_owner(&self) -> Result<()> {
+            if self.env().caller() != self.owner {
+                return Err(Error::NotOwner);
+            }
+            Ok(())
+        }
+
+        fn transfer_from_to(&mut self, from: &AccountId, to: &AccountId, value: Balance) -> Result<()> {
+            let from_balance = self.balance_of(*from);
+            if from_balance < value {
+                return Err(Error::InsufficientBalance);
+            }
+            let to_balance = self.balance_of(*to);
+            self.balances.insert(from, &(from_balance - value));
+            self.balances.insert(to, &to_balance.checked_add(value).ok_or(Error::Overflow)?);
+            self.env().emit_event(Transfer { from: Some(*from), to: Some(*to), value });
+            Ok(())
+        }
+    }
+}
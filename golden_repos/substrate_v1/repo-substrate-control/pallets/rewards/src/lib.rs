@@ -0,0 +1,111 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+    use sp_std::vec::Vec;
+
+    pub type Balance = u128;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        #[pallet::constant]
+        type MaxAccrualsPerBlock: Get<u32>;
+        #[pallet::constant]
+        type MaxRecipients: Get<u32>;
+    }
+
+    #[pallet::storage]
+    pub type RewardRate<T> = StorageValue<_, Balance, ValueQuery>;
+
+    #[pallet::storage]
+    pub type Points<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    /// Last account accrued by `on_initialize`; the next block resumes after it.
+    #[pallet::storage]
+    pub type AccrualCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+    #[pallet::storage]
+    pub type Claimable<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Balance, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        RewardRateSet { rate: Balance },
+        PointsTransferred { from: T::AccountId, to: T::AccountId, amount: Balance },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        InsufficientPoints,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let rate = RewardRate::<T>::get();
+            let limit = T::MaxAccrualsPerBlock::get();
+            let mut iter = match AccrualCursor::<T>::get() {
+                Some(cursor) => Points::<T>::iter_from(cursor),
+                None => Points::<T>::iter(),
+            };
+            let mut processed = 0u32;
+            for (who, points) in iter.by_ref().take(limit as usize) {
+                Claimable::<T>::mutate(&who, |claimable| *claimable = claimable.saturating_add(points.saturating_mul(rate)));
+                processed += 1;
+            }
+            if processed < limit {
+                AccrualCursor::<T>::kill();
+            } else {
+                AccrualCursor::<T>::put(iter.last_raw_key().to_vec());
+            }
+            Weight::from_parts(10_000, 0).saturating_mul(processed.into())
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        #[pallet::call_index(0)]
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        pub fn set_reward_rate(origin: OriginFor<T>, rate: Balance) -> DispatchResult {
+            ensure_root(origin)?;
+            RewardRate::<T>::put(rate);
+            Self::deposit_event(Event::RewardRateSet { rate });
+            Ok(())
+        }
+
+        #[pallet::call_index(1)]
+        #[pallet::weight(Weight::from_parts(10_000, 0).saturating_mul(recipients.len() as u64))]
+        pub fn award(
+            origin: OriginFor<T>,
+            recipients: BoundedVec<T::AccountId, T::MaxRecipients>,
+            points: Balance,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            for who in recipients.iter() {
+                Points::<T>::mutate(who, |current| *current = current.saturating_add(points));
+            }
+            Ok(())
+        }
+
+        #[pallet::call_index(2)]
+        #[pallet::weight(Weight::from_parts(10_000, 0))]
+        pub fn transfer_points(origin: OriginFor<T>, to: T::AccountId, amount: Balance) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let balance = Points::<T>::get(&who);
+            ensure!(balance >= amount, Error::<T>::InsufficientPoints);
+            Points::<T>::insert(&who, balance - amount);
+            Points::<T>::mutate(&to, |points| *points = points.saturating_add(amount));
+            Self::deposit_event(Event::PointsTransferred { from: who, to, amount });
+            Ok(())
+        }
+    }
+}
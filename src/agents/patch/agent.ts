@@ -5,6 +5,7 @@ import { routeModelWithFallbacks } from "../../llm/router";
 import { renderPrompt } from "../../llm/prompts";
 import { computeTokenBudget, truncateToTokenBudget } from "../../llm/token-budget";
 import { parseJsonResponse } from "../../llm/parser";
+import { getVulnClass } from "../../vulns/registry";
 
 async function readSource(filePath: string): Promise<string> {
   try {
@@ -56,6 +57,7 @@ export async function runPatchAgent(result: AdversarialResult): Promise<PatchPro
 
   const truncated = truncateToTokenBudget(sourceCode, budget.maxInputTokens - 1500);
   const rootCause = buildRootCause(result);
+  const vulnClass = getVulnClass(result.finding.vuln_class);
 
   const rendered = renderPrompt("patch", {
     vuln_class: result.finding.vuln_class,
@@ -64,6 +66,8 @@ export async function runPatchAgent(result: AdversarialResult): Promise<PatchPro
     line: String(result.finding.line),
     title: result.finding.title,
     root_cause: rootCause,
+    remediation: vulnClass?.remediation ?? "none recorded for this class",
+    example_fix: vulnClass?.exampleFix ?? "(none)",
     code: truncated.text
   });
 
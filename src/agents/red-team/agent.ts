@@ -5,6 +5,7 @@ import { routeModelWithFallbacks } from "../../llm/router";
 import { renderPrompt } from "../../llm/prompts";
 import { computeTokenBudget, truncateToTokenBudget } from "../../llm/token-budget";
 import { parseJsonResponse } from "../../llm/parser";
+import { exploitHarnessFor, stageExploit } from "../../sandbox/harness";
 import { createSandbox, isSandboxAvailable, isSandboxImageBuilt } from "../../sandbox/runner";
 
 interface RedTeamContext {
@@ -22,7 +23,7 @@ async function readSourceContext(finding: Finding): Promise<string> {
   }
 }
 
-async function tryExploitInSandbox(exploitCode: string): Promise<{
+async function tryExploitInSandbox(finding: Finding, sourceCode: string, exploitCode: string): Promise<{
   executed: boolean;
   exitCode?: number;
   stdout?: string;
@@ -32,15 +33,16 @@ async function tryExploitInSandbox(exploitCode: string): Promise<{
     return { executed: false };
   }
 
-  const imageReady = await isSandboxImageBuilt("generic");
+  const harness = exploitHarnessFor(finding.file);
+  const imageReady = await isSandboxImageBuilt(harness.profile);
   if (!imageReady) {
     return { executed: false };
   }
 
-  const session = await createSandbox("generic", { timeoutMs: 30_000 });
+  const session = await createSandbox(harness.profile, { timeoutMs: harness.timeoutMs + 5_000 });
   try {
-    await session.writeFile("/workspace/exploit.ts", exploitCode);
-    const result = await session.exec(["bun", "run", "/workspace/exploit.ts"], 25_000);
+    await stageExploit(session, harness, exploitCode, sourceCode);
+    const result = await session.exec(harness.command, harness.timeoutMs);
     return {
       executed: true,
       exitCode: result.exit_code,
@@ -67,7 +69,8 @@ export async function runRedTeamAgent(context: RedTeamContext): Promise<RedTeamA
     title: context.finding.title,
     description: context.finding.description,
     code: truncated.text,
-    program_id: context.programId ?? "unknown"
+    program_id: context.programId ?? "unknown",
+    exploit_format: exploitHarnessFor(context.finding.file).format
   });
 
   const response = await client.createMessage({
@@ -106,7 +109,7 @@ export async function runRedTeamAgent(context: RedTeamContext): Promise<RedTeamA
 
   if (exploitable && exploitCode) {
     try {
-      const sandbox = await tryExploitInSandbox(exploitCode);
+      const sandbox = await tryExploitInSandbox(context.finding, sourceCode, exploitCode);
       sandboxExecuted = sandbox.executed;
       sandboxExitCode = sandbox.exitCode;
       sandboxStdout = sandbox.stdout;
@@ -5,6 +5,7 @@ import { routeModelWithFallbacks } from "../../llm/router";
 import { renderPrompt } from "../../llm/prompts";
 import { computeTokenBudget, truncateToTokenBudget } from "../../llm/token-budget";
 import { parseJsonResponse } from "../../llm/parser";
+import { exploitHarnessFor, stageExploit } from "../../sandbox/harness";
 import { createSandbox, isSandboxAvailable, isSandboxImageBuilt } from "../../sandbox/runner";
 
 async function readSource(filePath: string): Promise<string> {
@@ -16,6 +17,7 @@ async function readSource(filePath: string): Promise<string> {
 }
 
 async function retestExploitInSandbox(
+  file: string,
   patchedCode: string,
   exploitCode: string | undefined
 ): Promise<boolean | undefined> {
@@ -24,14 +26,14 @@ async function retestExploitInSandbox(
   const dockerReady = await isSandboxAvailable();
   if (!dockerReady) return undefined;
 
-  const imageReady = await isSandboxImageBuilt("generic");
+  const harness = exploitHarnessFor(file);
+  const imageReady = await isSandboxImageBuilt(harness.profile);
   if (!imageReady) return undefined;
 
-  const session = await createSandbox("generic", { timeoutMs: 30_000 });
+  const session = await createSandbox(harness.profile, { timeoutMs: harness.timeoutMs + 5_000 });
   try {
-    await session.writeFile("/workspace/patched.rs", patchedCode);
-    await session.writeFile("/workspace/exploit.ts", exploitCode);
-    const result = await session.exec(["bun", "run", "/workspace/exploit.ts"], 25_000);
+    await stageExploit(session, harness, exploitCode, patchedCode);
+    const result = await session.exec(harness.command, harness.timeoutMs);
     // If exploit now fails (non-zero exit), the patch works
     return result.exit_code !== 0;
   } catch {
@@ -165,6 +167,7 @@ export async function runReviewAgent(
         });
       } else {
         exploitRetestPassed = await retestExploitInSandbox(
+          patch.file,
           patchedSource,
           adversarial.red_team.exploit_code
         );
@@ -0,0 +1,242 @@
+import { promises as fs } from "node:fs";
+import type { Finding } from "../../types";
+import {
+  contextAccountsStruct,
+  extractProgramInstructions,
+  hasConstraint,
+  instructionAt,
+  instructionsUsing,
+  isUncheckedField,
+  type AnchorAccountsStruct,
+  type AnchorInstruction
+} from "../../analysis/anchor-model";
+import {
+  checksKey,
+  checksOwner,
+  extractNativeProcessors,
+  isNativeProgramSource,
+  nativeAccountNames,
+  type NativeProcessor
+} from "../../analysis/native-model";
+import { extractFunctions, type RustFunction } from "../../analysis/rust-items";
+import { escapeRegExp } from "../../analysis/rust-source";
+import { crateOf, modulePathOf, sameCrate, visibleFiles, type RustCrateGraph } from "../../analysis/rust-crates";
+import type { TaintOptions } from "../../analysis/taint";
+import { findingId } from "./base";
+
+/** A native processor viewed as an instruction: no Accounts struct, accounts come from the `&[AccountInfo]` slice. */
+function nativeInstruction(processor: NativeProcessor): AnchorInstruction {
+  return {
+    programModule: processor.programModule,
+    name: processor.name,
+    file: processor.file,
+    line: processor.line,
+    endLine: processor.endLine,
+    contextParam: processor.accountsParam,
+    args: [],
+    fn: processor.fn
+  };
+}
+
+/**
+ * Follow a `#[program]` entry that forwards its context, e.g. `instructions::deposit::handler(ctx, amount)`,
+ * to the crate function taking the same `Context<T>`. The call path picks between same-named handlers.
+ */
+function resolveDelegate(
+  instruction: AnchorInstruction,
+  graph: RustCrateGraph,
+  functionsByFile: Map<string, RustFunction[]>
+): { file: string; fn: RustFunction } | undefined {
+  const call = new RegExp(`\\b((?:\\w+\\s*::\\s*)*)(\\w+)\\s*\\(\\s*${escapeRegExp(instruction.contextParam)}\\s*[,)]`);
+  const match = instruction.fn.body.match(call);
+  if (!match) return undefined;
+  const callPath = match[1].split("::").map((segment) => segment.trim()).filter(Boolean);
+
+  const candidates: { file: string; fn: RustFunction }[] = [];
+  for (const [file, functions] of functionsByFile) {
+    if (!sameCrate(graph, file, instruction.file)) continue;
+    for (const fn of functions) {
+      if (fn.name !== match[2] || (file === instruction.file && fn.line === instruction.line)) continue;
+      const context = fn.params[0];
+      if (!context || contextAccountsStruct(context.type) !== instruction.accountsStruct) continue;
+      candidates.push({ file, fn });
+    }
+  }
+
+  const suffix = callPath.filter((segment) => !["crate", "self", "super"].includes(segment)).join("::");
+  const visible = visibleFiles(graph, instruction.file);
+  return (
+    candidates.find((candidate) => suffix && modulePathOf(graph, candidate.file)?.endsWith(`::${suffix}`)) ??
+    candidates.find((candidate) => visible.includes(candidate.file)) ??
+    candidates[0]
+  );
+}
+
+/**
+ * Collect instruction handlers across all files, since handlers and Accounts structs often live apart.
+ * Anchor `#[program]` handlers come first; native programs contribute their account-reading processors.
+ * Entries that only forward `ctx` are replaced by the handler they forward to.
+ */
+export async function collectProgramInstructions(
+  files: string[],
+  graph: RustCrateGraph
+): Promise<AnchorInstruction[]> {
+  const instructions: AnchorInstruction[] = [];
+  for (const file of files) {
+    const content = graph.sources.get(file) ?? (await fs.readFile(file, "utf8"));
+    if (content.includes("#[program]")) {
+      instructions.push(...extractProgramInstructions(content, file));
+    } else if (isNativeProgramSource(content)) {
+      instructions.push(...extractNativeProcessors(content, file).map(nativeInstruction));
+    }
+  }
+
+  const functionsByFile = new Map<string, RustFunction[]>();
+  for (const file of files) {
+    const code = graph.sources.get(file) ?? "";
+    if (/\bContext\s*</.test(code)) functionsByFile.set(file, extractFunctions(code));
+  }
+
+  return instructions.map((instruction) => {
+    const crateName = crateOf(graph, instruction.file)?.name;
+    const delegate = instruction.accountsStruct ? resolveDelegate(instruction, graph, functionsByFile) : undefined;
+    if (!delegate) return { ...instruction, crateName };
+    const [context, ...args] = delegate.fn.params;
+    return {
+      ...instruction,
+      crateName,
+      file: delegate.file,
+      line: delegate.fn.line,
+      endLine: delegate.fn.endLine,
+      contextParam: context.name,
+      args,
+      fn: delegate.fn,
+      entry: { file: instruction.file, line: instruction.line, endLine: instruction.endLine }
+    };
+  });
+}
+
+/**
+ * The Accounts struct an instruction's `Context<T>` names. Workspaces can hold several programs with
+ * a struct of the same name, so prefer one in scope of the handler, then one in the same crate.
+ */
+export function accountsStructFor(
+  instruction: AnchorInstruction,
+  structs: AnchorAccountsStruct[],
+  graph: RustCrateGraph
+): AnchorAccountsStruct | undefined {
+  const named = structs.filter((candidate) => candidate.name === instruction.accountsStruct);
+  if (named.length <= 1) return named[0];
+  const visible = visibleFiles(graph, instruction.file);
+  return (
+    named.find((candidate) => visible.includes(candidate.file)) ??
+    named.find((candidate) => sameCrate(graph, candidate.file, instruction.file)) ??
+    named[0]
+  );
+}
+
+/** Instructions whose `Context<T>` names `struct`, leaving out same-named structs of other crates. */
+export function instructionsUsingStruct(
+  instructions: AnchorInstruction[],
+  struct: AnchorAccountsStruct,
+  graph: RustCrateGraph
+): AnchorInstruction[] {
+  return instructionsUsing(instructions, struct.name).filter((instruction) =>
+    sameCrate(graph, instruction.file, struct.file)
+  );
+}
+
+/**
+ * Taint sources of a handler: every parameter besides the context (or native account slice and
+ * program id), and the raw accounts whose owner neither a constraint nor the body checks.
+ */
+export function taintOptionsFor(instruction: AnchorInstruction, struct: AnchorAccountsStruct | undefined): TaintOptions {
+  const body = instruction.fn.body;
+  const args = instruction.fn.params.filter(
+    (param) => param.name !== instruction.contextParam && !/^&\s*(?:'\w+\s+)?Pubkey$/.test(param.type)
+  );
+  if (!instruction.accountsStruct) {
+    const accounts = nativeAccountNames(body, instruction.contextParam);
+    return { args, uncheckedAccounts: accounts.filter((name) => !checksOwner(body, name) && !checksKey(body, name)) };
+  }
+
+  const unchecked = (struct?.fields ?? []).filter(
+    (field) =>
+      isUncheckedField(field) &&
+      !["owner", "address", "seeds"].some((key) => hasConstraint(field, key)) &&
+      !checksOwner(body, `${instruction.contextParam}.accounts.${field.name}`)
+  );
+  return { args, uncheckedAccounts: unchecked.map((field) => field.name), contextParam: instruction.contextParam };
+}
+
+function withInstruction(finding: Finding, instruction: AnchorInstruction): Finding {
+  return {
+    ...finding,
+    id: findingId(finding.scanner_id, finding.vuln_class, finding.file, finding.line, instruction.name),
+    program_module: instruction.programModule,
+    instruction: instruction.name,
+    ...(instruction.crateName ? { crate: instruction.crateName } : {})
+  };
+}
+
+/** Record the crate of each finding's file, for findings that are not scoped to an instruction. */
+export function attributeToCrates(findings: Finding[], graph: RustCrateGraph): Finding[] {
+  return findings.map((finding) => {
+    if (finding.crate) return finding;
+    const crate = crateOf(graph, finding.file);
+    return crate ? { ...finding, crate: crate.name } : finding;
+  });
+}
+
+/**
+ * Attribute findings to the instructions that expose them.
+ *
+ * A finding inside a handler body (or the `#[program]` entry forwarding to it) belongs to that handler.
+ * A finding inside an Accounts struct is reported once per instruction whose `Context<T>` uses the struct.
+ */
+export function scopeFindingsToInstructions(
+  findings: Finding[],
+  structs: AnchorAccountsStruct[],
+  instructions: AnchorInstruction[],
+  graph: RustCrateGraph
+): Finding[] {
+  const scoped: Finding[] = [];
+
+  for (const finding of findings) {
+    if (finding.instruction) {
+      scoped.push(...attributeToCrates([finding], graph));
+      continue;
+    }
+
+    const handler =
+      instructionAt(
+        instructions.filter((instruction) => instruction.file === finding.file),
+        finding.line
+      ) ??
+      instructions.find(
+        (instruction) =>
+          instruction.entry?.file === finding.file &&
+          finding.line >= instruction.entry.line &&
+          finding.line <= instruction.entry.endLine
+      );
+    if (handler) {
+      scoped.push(withInstruction(finding, handler));
+      continue;
+    }
+
+    const struct = structs.find(
+      (candidate) =>
+        candidate.file === finding.file && finding.line >= candidate.line && finding.line <= candidate.endLine
+    );
+    const users = struct ? instructionsUsingStruct(instructions, struct, graph) : [];
+    if (users.length === 0) {
+      scoped.push(...attributeToCrates([finding], graph));
+      continue;
+    }
+    for (const instruction of users) {
+      scoped.push(withInstruction(finding, instruction));
+    }
+  }
+
+  return scoped;
+}
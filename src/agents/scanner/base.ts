@@ -1,7 +1,9 @@
 import { createHash } from "node:crypto";
 import { promises as fs } from "node:fs";
 import path from "node:path";
-import type { Finding, Severity, VulnClass } from "../../types";
+import type { Finding, FindingLocation, Severity, VulnClass } from "../../types";
+import { lexRust, maskRust, type RustRegion } from "../../analysis/rust-lexer";
+import { vulnClassInfo } from "../../vulns/registry";
 
 export interface Scanner {
   id: string;
@@ -38,10 +40,27 @@ export function findLineContaining(content: string, needle: string): number {
   return idx >= 0 ? idx + 1 : 1;
 }
 
+/** Source line `line` of `content`, whitespace-collapsed and capped for use as finding evidence. */
+export function evidenceLine(content: string, line: number): string {
+  const text = (content.split(/\r?\n/)[line - 1] ?? "").replace(/\s+/g, " ").trim();
+  return text.length > 120 ? `${text.slice(0, 117)}...` : text;
+}
+
 export function marker(classTag: string): string {
   return `HYDRA_VULN:${classTag}`;
 }
 
+export function findingId(
+  scannerId: string,
+  vulnClass: VulnClass,
+  file: string,
+  line: number,
+  instruction?: string
+): string {
+  const idSeed = `${scannerId}|${vulnClass}|${file}|${line}${instruction ? `|${instruction}` : ""}`;
+  return createHash("sha256").update(idSeed).digest("hex").slice(0, 16);
+}
+
 export function makeFinding(input: {
   scannerId: string;
   vulnClass: VulnClass;
@@ -52,12 +71,14 @@ export function makeFinding(input: {
   title: string;
   description: string;
   evidence: string;
+  crateName?: string;
+  programModule?: string;
+  instruction?: string;
+  accountField?: string;
+  trace?: FindingLocation[];
 }): Finding {
-  const idSeed = `${input.scannerId}|${input.vulnClass}|${input.file}|${input.line}`;
-  const id = createHash("sha256").update(idSeed).digest("hex").slice(0, 16);
-
-  return {
-    id,
+  const finding: Finding = {
+    id: findingId(input.scannerId, input.vulnClass, input.file, input.line, input.instruction),
     scanner_id: input.scannerId,
     vuln_class: input.vulnClass,
     severity: input.severity,
@@ -68,8 +89,54 @@ export function makeFinding(input: {
     description: input.description,
     evidence: input.evidence
   };
+
+  if (input.crateName) finding.crate = input.crateName;
+  if (input.programModule) finding.program_module = input.programModule;
+  if (input.instruction) finding.instruction = input.instruction;
+  if (input.accountField) finding.account_field = input.accountField;
+  if (input.trace && input.trace.length > 0) finding.trace = input.trace;
+  return finding;
+}
+
+/**
+ * Findings for `HYDRA_VULN:<class>` markers in `content` (golden repos / eval compatibility), titled,
+ * described and rated from the vulnerability-class registry.
+ */
+export function markerFindings(
+  scannerId: string,
+  file: string,
+  content: string,
+  classes: readonly VulnClass[],
+  confidence: number
+): Finding[] {
+  const findings: Finding[] = [];
+  for (const vulnClass of classes) {
+    const token = marker(vulnClass);
+    if (!content.includes(token)) continue;
+
+    const info = vulnClassInfo(vulnClass);
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass,
+        severity: info.defaultSeverity,
+        confidence,
+        file,
+        line: findLineContaining(content, token),
+        title: info.title,
+        description: info.description,
+        evidence: `Found marker ${token}`
+      })
+    );
+  }
+  return findings;
 }
 
+/** Which lexical regions of a Rust file a rule pattern is matched against. */
+export type PatternScope = "code" | "attribute" | "string";
+
+const CODE_REGIONS: readonly PatternScope[] = ["code"];
+
 export interface PatternRule {
   vulnClass: VulnClass;
   severity: Severity;
@@ -80,23 +147,48 @@ export interface PatternRule {
   pattern: RegExp;
   /** If any of these appear within contextLines of the match, suppress the finding */
   mitigations?: RegExp[];
+  /** Rust regions mitigations may match in (default: code only). Ignored for other languages. */
+  mitigationScope?: PatternScope[];
   /** Lines before/after to check for mitigations (default: 5) */
   contextLines?: number;
+  /** Rust regions the pattern may match in (default: code only). Ignored for other languages. */
+  scope?: PatternScope[];
+  /** Also accept mitigations from the modules the file imports, passed to scanFileWithPatterns as `related` */
+  crossFileMitigations?: boolean;
 }
 
 export function scanFileWithPatterns(
   scannerId: string,
   filePath: string,
   content: string,
-  rules: PatternRule[]
+  rules: PatternRule[],
+  related?: string
 ): Finding[] {
   const lines = content.split(/\r?\n/);
   const findings: Finding[] = [];
   const seen = new Set<string>();
 
+  // For Rust, match against masked views so comments never trigger a rule, and only real code (or
+  // the regions a rule names) can mitigate one.
+  const lexed = filePath.endsWith(".rs") ? lexRust(content) : undefined;
+  const views = new Map<string, string[]>();
+  const viewLines = (scope: readonly RustRegion[]): string[] => {
+    if (!lexed) return lines;
+    const key = [...scope].sort().join(",");
+    let view = views.get(key);
+    if (!view) {
+      view = maskRust(lexed, scope).split(/\r?\n/);
+      views.set(key, view);
+    }
+    return view;
+  };
+
   for (const rule of rules) {
+    const ruleLines = viewLines(rule.scope ?? CODE_REGIONS);
+    const mitigationScope = rule.mitigationScope ?? CODE_REGIONS;
+    const imported = rule.crossFileMitigations && related && lexed ? maskRust(related, mitigationScope) : related;
     for (let i = 0; i < lines.length; i++) {
-      if (!rule.pattern.test(lines[i])) continue;
+      if (!rule.pattern.test(ruleLines[i])) continue;
 
       // Deduplicate same vuln class at same line
       const dedup = `${rule.vulnClass}:${i}`;
@@ -107,9 +199,10 @@ export function scanFileWithPatterns(
         const window = rule.contextLines ?? 5;
         const start = Math.max(0, i - window);
         const end = Math.min(lines.length, i + window + 1);
-        const context = lines.slice(start, end).join("\n");
+        const context = viewLines(mitigationScope).slice(start, end).join("\n");
 
         if (rule.mitigations.some((m) => m.test(context))) continue;
+        if (rule.crossFileMitigations && imported && rule.mitigations.some((m) => m.test(imported))) continue;
       }
 
       seen.add(dedup);
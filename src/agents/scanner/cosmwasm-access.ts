@@ -0,0 +1,175 @@
+import type { Finding, FindingLocation } from "../../types";
+import {
+  calledFunctions,
+  checksSender,
+  infoParam,
+  routeCode,
+  storageWrites,
+  type CosmwasmContract,
+  type ExecuteRoute
+} from "../../analysis/cosmwasm-model";
+import { bodyLine } from "../../analysis/rust-items";
+import { matchingBracket, splitTopLevel } from "../../analysis/rust-source";
+import { derivedBindings, mentionsBinding } from "../../analysis/rust-web";
+import { attributeToCrates } from "./anchor-scope";
+import { makeFinding, type Scanner } from "./base";
+import { evidenceAt, loadCosmwasmContract, routeLine } from "./cosmwasm-scope";
+
+/** Messages only an authority should be able to make the contract send. */
+const PRIVILEGED_MESSAGE = /\bBankMsg\s*::\s*(?:Send|Burn)\b|\bWasmMsg\s*::\s*(?:Migrate|UpdateAdmin|ClearAdmin)\b/g;
+/** A migration that compares the stored contract name or version before touching state. */
+const VERSION_CHECK =
+  /\b(?:get_contract_version|ensure_from_older_version|assert_contract_version)\s*\(|\bCONTRACT_NAME\b[^;]*[!=]=|[!=]=[^;]*\bCONTRACT_NAME\b/;
+const ADMIN_STORE = /owner|admin|gov|operator/i;
+
+/** Item writes and privileged messages a route performs, directly or one call deep. */
+function privilegedActions(contract: CosmwasmContract, route: ExecuteRoute): FindingLocation[] {
+  const code = routeCode(route);
+  const { file } = route.target;
+  const actions: FindingLocation[] = [];
+
+  for (const write of storageWrites(code)) {
+    if (!contract.items.has(write.store)) continue;
+    actions.push({ file, line: routeLine(contract, route, write.offset), label: `writes \`${write.store}\` (${write.operation})` });
+  }
+  for (const message of code.matchAll(PRIVILEGED_MESSAGE)) {
+    actions.push({ file, line: routeLine(contract, route, message.index ?? 0), label: `sends \`${message[0].replace(/\s+/g, "")}\`` });
+  }
+  for (const helper of calledFunctions(code, contract.functions, route.target.fn)) {
+    const write = storageWrites(helper.fn.body).find((candidate) => contract.items.has(candidate.store));
+    if (!write) continue;
+    const call = code.search(new RegExp(`\\b${helper.fn.name}\\s*\\(`));
+    actions.push(
+      { file, line: routeLine(contract, route, Math.max(0, call)), label: `calls \`${helper.fn.name}\`` },
+      {
+        file: helper.file,
+        line: bodyLine(contract.contents.get(helper.file) ?? "", helper.fn, write.offset),
+        label: `\`${helper.fn.name}\` writes \`${write.store}\``
+      }
+    );
+  }
+  return actions.sort((a, b) => (a.file === b.file ? a.line - b.line : 0));
+}
+
+function scanSenderChecks(scannerId: string, contract: CosmwasmContract): Finding[] {
+  const findings: Finding[] = [];
+  for (const route of contract.routes) {
+    if (route.guarded) continue;
+    const code = routeCode(route);
+    const info = infoParam(route.target.fn);
+    if (checksSender(code, info, contract.functions)) continue;
+    const actions = privilegedActions(contract, route);
+    if (actions.length === 0) continue;
+
+    const [first] = actions;
+    const handler = route.target.fn.name === "execute" ? "its `execute` arm" : `\`${route.target.fn.name}\``;
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "missing_sender_auth",
+        severity: "HIGH",
+        confidence: info && !info.startsWith("_") ? 64 : 68,
+        file: first.file,
+        line: first.line,
+        title: "Execute handler changes contract state without checking the sender",
+        description:
+          `\`ExecuteMsg::${route.variant}\` (handled by ${handler}) ${actions.map((action) => action.label).filter((label) => !label.startsWith("calls")).join(", ")} ` +
+          `but never compares \`info.sender\` with a stored owner or admin${info ? "" : " (it is not even passed `MessageInfo`)"}. ` +
+          "Any address can send this message; load the authority from storage and reject other senders before changing state.",
+        evidence: evidenceAt(contract, first.file, first.line),
+        programModule: "execute",
+        instruction: route.variant,
+        trace: [
+          { file: route.file, line: route.line, label: `\`ExecuteMsg::${route.variant}\` dispatched without a sender check` },
+          ...actions
+        ]
+      })
+    );
+  }
+  return findings;
+}
+
+function scanUncheckedAddresses(scannerId: string, contract: CosmwasmContract): Finding[] {
+  const findings: Finding[] = [];
+  for (const { file, fn } of contract.functions) {
+    const content = contract.contents.get(file) ?? "";
+    for (const call of fn.body.matchAll(/\bAddr\s*::\s*unchecked\s*\(/g)) {
+      const open = (call.index ?? 0) + call[0].length - 1;
+      const close = matchingBracket(fn.body, open);
+      const value = (splitTopLevel(fn.body.slice(open + 1, close < 0 ? fn.body.length : close))[0] ?? "").replace(/\s+/g, " ").trim();
+      // Literal and constant addresses are fixed at build time.
+      if (!value || /^"[^"]*"$/.test(value) || /^[A-Z][A-Z0-9_]*$/.test(value)) continue;
+      const line = bodyLine(content, fn, call.index ?? 0);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unchecked_addr",
+          severity: "MEDIUM",
+          confidence: 60,
+          file,
+          line,
+          title: "Address taken with Addr::unchecked",
+          description:
+            `\`${fn.name}\` turns \`${value}\` into an \`Addr\` without \`deps.api.addr_validate\`. Malformed addresses are ` +
+            "stored as-is, so funds sent to them are lost, and a differently-cased copy of a valid address becomes a second " +
+            "identity that slips past allowlists and map keys. Validate every address that arrives in a message.",
+          evidence: evidenceAt(contract, file, line)
+        })
+      );
+    }
+  }
+  return findings;
+}
+
+function scanMigrations(scannerId: string, contract: CosmwasmContract): Finding[] {
+  const findings: Finding[] = [];
+  for (const entry of contract.entryPoints.filter((candidate) => candidate.name === "migrate")) {
+    const body = entry.fn.body;
+    if (VERSION_CHECK.test(body) || /\bassert_(?:admin|owner)\s*\(/.test(body)) continue;
+    const msg = entry.fn.params.find((param) => /\bMigrateMsg\b/.test(param.type))?.name;
+    if (!msg || msg.startsWith("_")) continue;
+    const tainted = derivedBindings(body, [msg]);
+    const content = contract.contents.get(entry.file) ?? "";
+
+    for (const write of storageWrites(body)) {
+      const close = matchingBracket(body, body.indexOf("(", write.offset));
+      if (!mentionsBinding(body.slice(write.offset, close < 0 ? body.length : close), tainted)) continue;
+      const line = bodyLine(content, entry.fn, write.offset);
+      const authority = ADMIN_STORE.test(write.store);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unprotected_migration",
+          severity: authority ? "HIGH" : "MEDIUM",
+          confidence: authority ? 62 : 56,
+          file: entry.file,
+          line,
+          title: "Migration rewrites state without an admin or version check",
+          description:
+            `\`migrate\` writes \`${write.store}\` from \`MigrateMsg\` without checking the stored contract name and version ` +
+            "(`cw2::get_contract_version` / `ensure_from_older_version`) or the current admin. The chain lets the code admin " +
+            "run any migration, so a migration from an unrelated contract or an older release silently resets " +
+            `${authority ? "who controls the contract" : "its configuration"}; verify the contract being migrated before accepting new values.`,
+          evidence: evidenceAt(contract, entry.file, line),
+          programModule: "migrate",
+          instruction: "migrate"
+        })
+      );
+      break;
+    }
+  }
+  return findings;
+}
+
+export const cosmwasmAccessScanner: Scanner = {
+  id: "scanner.cosmwasm.access",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const contract = await loadCosmwasmContract(rootPath);
+    const findings = [
+      ...scanSenderChecks(this.id, contract),
+      ...scanUncheckedAddresses(this.id, contract),
+      ...scanMigrations(this.id, contract)
+    ];
+    return attributeToCrates(findings, contract.graph);
+  }
+};
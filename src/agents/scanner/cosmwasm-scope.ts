@@ -0,0 +1,24 @@
+import { promises as fs } from "node:fs";
+import { buildCosmwasmContract, type CosmwasmContract, type ExecuteRoute } from "../../analysis/cosmwasm-model";
+import { loadCrateGraph } from "../../analysis/rust-crates";
+import { bodyLine } from "../../analysis/rust-items";
+import { evidenceLine, listFilesRecursive } from "./base";
+
+/** Model of every CosmWasm contract under `rootPath`, shared by the CosmWasm scanners and the threat model. */
+export async function loadCosmwasmContract(rootPath: string): Promise<CosmwasmContract> {
+  const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+  const graph = await loadCrateGraph(rootPath, files);
+  const contents = new Map<string, string>();
+  for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+  return buildCosmwasmContract(graph, contents);
+}
+
+/** Line of an offset within a route's code. */
+export function routeLine(contract: CosmwasmContract, route: ExecuteRoute, offset: number): number {
+  return bodyLine(contract.contents.get(route.target.file) ?? "", route.target.fn, route.start + offset);
+}
+
+/** Evidence line of a contract file. */
+export function evidenceAt(contract: CosmwasmContract, file: string, line: number): string {
+  return evidenceLine(contract.contents.get(file) ?? "", line);
+}
@@ -0,0 +1,72 @@
+import type { Finding } from "../../types";
+import type { CosmwasmContract } from "../../analysis/cosmwasm-model";
+import { bodyLine } from "../../analysis/rust-items";
+import { matchingBracket } from "../../analysis/rust-source";
+import { attributeToCrates } from "./anchor-scope";
+import { makeFinding, type Scanner } from "./base";
+import { evidenceAt, loadCosmwasmContract } from "./cosmwasm-scope";
+
+/** `cw-storage-plus` iteration: `BALANCES.range(deps.storage, None, None, Order::Ascending)`. */
+const ITERATION = /\.\s*(range|range_raw|keys|keys_raw|prefix_range)\s*\(/g;
+
+/** End of the expression holding `offset`: the next `;` or block brace outside brackets (`for .. in x.range(..) {`). */
+function statementEnd(body: string, offset: number): number {
+  let index = offset;
+  while (index < body.length) {
+    const char = body[index];
+    if (char === ";" || char === "{" || char === "}") return index;
+    if (char === "(" || char === "[") {
+      const close = matchingBracket(body, index);
+      if (close < 0) return body.length;
+      index = close + 1;
+      continue;
+    }
+    index += 1;
+  }
+  return body.length;
+}
+
+function scanIterations(scannerId: string, contract: CosmwasmContract): Finding[] {
+  const findings: Finding[] = [];
+  for (const { file, fn } of contract.functions) {
+    const mutating = fn.params.some((param) => /\bDepsMut\b/.test(param.type));
+    const content = contract.contents.get(file) ?? "";
+    for (const call of fn.body.matchAll(ITERATION)) {
+      const open = (call.index ?? 0) + call[0].length - 1;
+      const close = matchingBracket(fn.body, open);
+      if (close < 0 || !/\bOrder\s*::/.test(fn.body.slice(open, close))) continue;
+      // A `.take(n)` on the iterator bounds the work done per call.
+      if (/\.\s*take\s*\(/.test(fn.body.slice(close, statementEnd(fn.body, close)))) continue;
+
+      const line = bodyLine(content, fn, call.index ?? 0);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unbounded_iteration",
+          severity: mutating ? "HIGH" : "MEDIUM",
+          confidence: mutating ? 60 : 56,
+          file,
+          line,
+          title: mutating ? "Unbounded storage iteration in a state-changing handler" : "Unbounded storage iteration in a query",
+          description:
+            `\`${fn.name}\` walks \`.${call[1]}(..)\` over a storage map without a \`.take(limit)\`. Anyone who can add entries ` +
+            "grows the map until the loop exceeds the gas limit" +
+            (mutating
+              ? ", after which this handler always fails and whatever it guards (withdrawals, distributions) is stuck for good."
+              : " or the node's query gas cap, breaking the query for every client.") +
+            " Paginate with a `start_after` bound and a capped limit.",
+          evidence: evidenceAt(contract, file, line)
+        })
+      );
+    }
+  }
+  return findings;
+}
+
+export const cosmwasmStorageScanner: Scanner = {
+  id: "scanner.cosmwasm.storage",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const contract = await loadCosmwasmContract(rootPath);
+    return attributeToCrates(scanIterations(this.id, contract), contract.graph);
+  }
+};
@@ -0,0 +1,76 @@
+import type { Finding } from "../../types";
+import { calledFunctions, routeCode, storageWrites, type ContractFunction, type CosmwasmContract } from "../../analysis/cosmwasm-model";
+import { bodyLine } from "../../analysis/rust-items";
+import { attributeToCrates } from "./anchor-scope";
+import { makeFinding, type Scanner } from "./base";
+import { evidenceAt, loadCosmwasmContract, routeLine } from "./cosmwasm-scope";
+
+/** Submessages whose result comes back through `reply`. */
+const REPLY_SUBMSG = /\bSubMsg\s*::\s*reply_(?:on_success|always|on_error)\b|\bReplyOn\s*::\s*(?:Success|Always|Error)\b/;
+
+/** First storage write in `entry` or a crate function it calls. */
+function firstWrite(contract: CosmwasmContract, entry: ContractFunction): { file: string; line: number; store: string } | undefined {
+  for (const candidate of [entry, ...calledFunctions(entry.fn.body, contract.functions, entry.fn)]) {
+    const [write] = storageWrites(candidate.fn.body);
+    if (write) {
+      const line = bodyLine(contract.contents.get(candidate.file) ?? "", candidate.fn, write.offset);
+      return { file: candidate.file, line, store: write.store };
+    }
+  }
+  return undefined;
+}
+
+/**
+ * A route that dispatches a reply-bearing submessage before recording anything leaves the
+ * callee free to re-enter the contract while the state still describes the old world; the
+ * bookkeeping only happens later in `reply`.
+ */
+function scanReplyOrdering(scannerId: string, contract: CosmwasmContract): Finding[] {
+  const reply = contract.entryPoints.find((entry) => entry.name === "reply");
+  const replyWrite = reply ? firstWrite(contract, reply) : undefined;
+  if (!reply || !replyWrite) return [];
+
+  const findings: Finding[] = [];
+  for (const route of contract.routes) {
+    const code = routeCode(route);
+    const submsg = REPLY_SUBMSG.exec(code);
+    if (!submsg) continue;
+    const helpers = calledFunctions(code, contract.functions, route.target.fn);
+    if (storageWrites(code).length > 0 || helpers.some((helper) => storageWrites(helper.fn.body).length > 0)) continue;
+
+    const line = routeLine(contract, route, submsg.index);
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "submsg_reentrancy",
+        severity: "HIGH",
+        confidence: 62,
+        file: route.target.file,
+        line,
+        title: "Submessage dispatched before state is recorded; bookkeeping deferred to reply",
+        description:
+          `\`ExecuteMsg::${route.variant}\` sends a reply-bearing submessage without saving any state, and \`reply\` writes ` +
+          `\`${replyWrite.store}\` once the callee returns. The callee runs first and can call back into the contract ` +
+          "(or the same message can be sent again in the transaction) while balances and in-flight markers still hold " +
+          "their old values. Record the pending operation before dispatching and reconcile it in `reply`.",
+        evidence: evidenceAt(contract, route.target.file, line),
+        programModule: "execute",
+        instruction: route.variant,
+        trace: [
+          { file: route.file, line: route.line, label: `\`ExecuteMsg::${route.variant}\`` },
+          { file: route.target.file, line, label: "submessage with reply dispatched, no state written" },
+          { file: replyWrite.file, line: replyWrite.line, label: `\`reply\` writes \`${replyWrite.store}\`` }
+        ]
+      })
+    );
+  }
+  return findings;
+}
+
+export const cosmwasmSubmsgScanner: Scanner = {
+  id: "scanner.cosmwasm.submsg",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const contract = await loadCosmwasmContract(rootPath);
+    return attributeToCrates(scanReplyOrdering(this.id, contract), contract.graph);
+  }
+};
@@ -1,6 +1,7 @@
 import { promises as fs } from "node:fs";
 import type { Finding, Severity, VulnClass } from "../../types";
-import { listFilesRecursive, makeFinding } from "./base";
+import { stripComments } from "../../analysis/rust-source";
+import { listFilesRecursive, makeFinding, type Scanner } from "./base";
 
 interface DeterministicSignalRule {
   id: string;
@@ -54,9 +55,10 @@ export async function runDeterministicSignalAdapters(rootPath: string): Promise<
   for (const file of files) {
     const content = await fs.readFile(file, "utf8");
     const lines = content.split(/\r?\n/);
+    const codeLines = stripComments(content).split(/\r?\n/);
 
     for (const rule of rules) {
-      const index = lines.findIndex((line) => rule.regex.test(line));
+      const index = codeLines.findIndex((line) => rule.regex.test(line));
       if (index < 0) {
         continue;
       }
@@ -79,3 +81,8 @@ export async function runDeterministicSignalAdapters(rootPath: string): Promise<
 
   return findings;
 }
+
+export const deterministicSignalScanner: Scanner = {
+  id: "signal.deterministic.adapters",
+  scan: runDeterministicSignalAdapters
+};
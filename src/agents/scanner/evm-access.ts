@@ -0,0 +1,117 @@
+import type { Finding, FindingLocation } from "../../types";
+import {
+  checksCaller,
+  functionLine,
+  isExternallyCallable,
+  mutableStateVariables,
+  mutatesState,
+  stateWrites,
+  statementStart,
+  type SolidityProject
+} from "../../analysis/solidity-model";
+import { evidenceLine, makeFinding, type Scanner } from "./base";
+import { contractFunctions, loadSolidityProject } from "./evm-scope";
+
+/** State that configures the contract or names who may administer it. */
+const PRIVILEGED_STATE =
+  /owner|admin|governance|governor|guardian|operator|minter|oracle|treasury|paused|implementation|signer|authority|keeper|whitelist|blacklist|allowlist|manager|controller|fee(?:bps|rate|recipient|receiver|to|percent)?$/i;
+
+/** Calls that destroy, upgrade, mint or hand out roles. */
+const PRIVILEGED_CALL =
+  /\b(?:selfdestruct|suicide|_upgradeTo\w*|upgradeTo\w*|_setImplementation|_transferOwnership|_grantRole|_setupRole|_mint)\s*\(/g;
+
+/**
+ * Public or external functions that rewrite configuration or ownership, or destroy, upgrade
+ * or mint, with no access modifier and no `msg.sender` check anywhere on their path.
+ */
+function scanAccessModifiers(scannerId: string, project: SolidityProject): Finding[] {
+  const findings: Finding[] = [];
+  for (const { source, contract, fn } of contractFunctions(project)) {
+    if (!isExternallyCallable(fn) || !mutatesState(fn) || checksCaller(fn, contract, project)) continue;
+
+    // Writes keyed by the caller (`approvals[msg.sender]`) only touch the caller's own entry.
+    const writes = stateWrites(fn.body, mutableStateVariables(contract, project)).filter(
+      (write) => PRIVILEGED_STATE.test(write.variable) && !/msg\.sender|_msgSender/.test(write.target)
+    );
+    const calls = [...fn.body.matchAll(PRIVILEGED_CALL)].filter(
+      (match) => !/^\s*msg\.sender\b/.test(fn.body.slice((match.index ?? 0) + match[0].length))
+    );
+    const actions: FindingLocation[] = [
+      ...writes.map((write) => ({ offset: write.offset, label: `writes \`${write.target}\`` })),
+      ...calls.map((match) => ({ offset: match.index ?? 0, label: `calls \`${match[0].replace(/\s*\($/, "")}\`` }))
+    ]
+      .sort((a, b) => a.offset - b.offset)
+      .map((action) => ({ file: source.file, line: functionLine(source, fn, action.offset), label: action.label }));
+    if (actions.length === 0) continue;
+
+    const [first] = actions;
+    const destructive = calls.length > 0 || writes.some((write) => /owner|admin|implementation/i.test(write.variable));
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "missing_access_modifier",
+        severity: "HIGH",
+        confidence: destructive ? 66 : 62,
+        file: source.file,
+        line: first.line,
+        title: "Privileged function has no access control",
+        description:
+          `\`${contract.name}.${fn.name}\` is \`${fn.visibility}\` and ${actions.map((action) => action.label).join(", ")}, ` +
+          `but has no access modifier${fn.modifiers.length > 0 ? ` (only \`${fn.modifiers.join("`, `")}\`)` : ""} and never checks ` +
+          "`msg.sender`. Any account can call it. Add `onlyOwner`/`onlyRole(..)` or an explicit check against the stored admin.",
+        evidence: evidenceLine(source.content, first.line),
+        programModule: contract.name,
+        instruction: fn.name,
+        trace: [{ file: source.file, line: fn.line, label: `\`${fn.name}\` (${fn.visibility}, no access check)` }, ...actions]
+      })
+    );
+  }
+  return findings;
+}
+
+/**
+ * `tx.origin` is the account that signed the transaction, not the immediate caller: a contract
+ * the owner is tricked into calling passes any `tx.origin == owner` check. Comparing it with
+ * `msg.sender` (an "only EOAs" check) is not authorization and is left alone.
+ */
+function scanTxOrigin(scannerId: string, project: SolidityProject): Finding[] {
+  const findings: Finding[] = [];
+  for (const { source, contract, fn } of contractFunctions(project, true)) {
+    for (const match of fn.body.matchAll(/\btx\s*\.\s*origin\b/g)) {
+      const offset = match.index ?? 0;
+      const end = fn.body.slice(offset).search(/[;{]/);
+      const statement = fn.body.slice(statementStart(fn.body, offset), end < 0 ? fn.body.length : offset + end);
+      if (/tx\s*\.\s*origin\s*[!=]=\s*msg\.sender|msg\.sender\s*[!=]=\s*tx\s*\.\s*origin/.test(statement)) continue;
+      if (!/[!=]=|\[\s*tx\s*\.\s*origin\s*\]/.test(statement) || !/\b(?:require|assert|if)\s*\(/.test(statement)) continue;
+
+      const line = functionLine(source, fn, offset);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "tx_origin_auth",
+          severity: "HIGH",
+          confidence: 66,
+          file: source.file,
+          line,
+          title: "Authorization based on tx.origin",
+          description:
+            `\`${contract.name}.${fn.name}\` authorizes the caller with \`tx.origin\`. Any contract the authorized account ` +
+            "interacts with can call in on its behalf and pass the check (phishing-style relay). Use `msg.sender`.",
+          evidence: evidenceLine(source.content, line),
+          programModule: contract.name,
+          instruction: fn.name
+        })
+      );
+      break;
+    }
+  }
+  return findings;
+}
+
+export const evmAccessScanner: Scanner = {
+  id: "scanner.evm.access",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const project = await loadSolidityProject(rootPath);
+    return [...scanAccessModifiers(this.id, project), ...scanTxOrigin(this.id, project)];
+  }
+};
@@ -0,0 +1,161 @@
+import type { Finding } from "../../types";
+import {
+  callResultChecked,
+  checksCaller,
+  contractTypedVariables,
+  externalCalls,
+  functionLine,
+  hasReentrancyGuard,
+  isExternallyCallable,
+  mutableStateVariables,
+  mutatesState,
+  receiverRoot,
+  stateWrites,
+  type SolidityProject
+} from "../../analysis/solidity-model";
+import { evidenceLine, makeFinding, type Scanner } from "./base";
+import { contractFunctions, loadSolidityProject } from "./evm-scope";
+
+/**
+ * An external call that hands control to another contract before the function updates its own
+ * state lets the callee re-enter while balances still hold their old values: the classic
+ * drain of a `withdraw` that pays first and debits after.
+ */
+function scanReentrancy(scannerId: string, project: SolidityProject): Finding[] {
+  const findings: Finding[] = [];
+  for (const { source, contract, fn } of contractFunctions(project)) {
+    if (!isExternallyCallable(fn) || !mutatesState(fn) || hasReentrancyGuard(fn)) continue;
+    const variables = mutableStateVariables(contract, project);
+    const calls = externalCalls(fn.body, contractTypedVariables(contract, project)).filter(
+      (call) => call.kind === "call" || call.kind === "interface"
+    );
+    for (const call of calls) {
+      const writes = stateWrites(fn.body, variables).filter((write) => write.offset >= call.end);
+      if (writes.length === 0) continue;
+
+      const line = functionLine(source, fn, call.offset);
+      const lowLevel = call.kind === "call";
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "reentrancy",
+          severity: lowLevel ? "HIGH" : "MEDIUM",
+          confidence: lowLevel ? (call.value ? 66 : 60) : 56,
+          file: source.file,
+          line,
+          title: lowLevel ? "External call before state update (reentrancy)" : "Interface call before state update",
+          description:
+            `\`${contract.name}.${fn.name}\` calls \`${call.receiver}\` ${call.value ? "and forwards ether " : ""}before writing ` +
+            `${[...new Set(writes.map((write) => `\`${write.target}\``))].join(", ")}. The callee runs arbitrary code and can call back ` +
+            `into \`${fn.name}\` (or another function reading the same state) before the update lands. Apply checks-effects-interactions ` +
+            "by writing state before the call, or add a `nonReentrant` guard.",
+          evidence: evidenceLine(source.content, line),
+          programModule: contract.name,
+          instruction: fn.name,
+          trace: [
+            { file: source.file, line: fn.line, label: `\`${fn.name}\` (${fn.visibility}, no reentrancy guard)` },
+            { file: source.file, line, label: `external call to \`${call.receiver}\`` },
+            ...writes.map((write) => ({ file: source.file, line: functionLine(source, fn, write.offset), label: `writes \`${write.target}\`` }))
+          ]
+        })
+      );
+      break;
+    }
+  }
+  return findings;
+}
+
+/** Low-level calls and `send` report failure through a `bool`; nothing reverts if it is ignored. */
+function scanUncheckedCalls(scannerId: string, project: SolidityProject): Finding[] {
+  const findings: Finding[] = [];
+  for (const { source, contract, fn } of contractFunctions(project, true)) {
+    for (const call of externalCalls(fn.body)) {
+      if (call.kind === "transfer" || call.kind === "interface" || callResultChecked(fn.body, call)) continue;
+      const line = functionLine(source, fn, call.offset);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unchecked_call_return",
+          severity: call.value ? "HIGH" : "MEDIUM",
+          confidence: call.value ? 62 : 58,
+          file: source.file,
+          line,
+          title: `Return value of low-level \`${call.kind}\` is not checked`,
+          description:
+            `\`${contract.name}.${fn.name}\` ignores the success flag of \`${call.receiver}.${call.kind}(..)\`. A failed ` +
+            `${call.value ? "payment" : "call"} does not revert, so execution continues as if it succeeded` +
+            `${call.value ? " and the ether stays in the contract while the books say it left" : ""}. ` +
+            "Check the returned `bool` with `require`, or use OpenZeppelin's `Address.sendValue`/`functionCall`.",
+          evidence: evidenceLine(source.content, line),
+          programModule: contract.name,
+          instruction: fn.name
+        })
+      );
+    }
+  }
+  return findings;
+}
+
+/**
+ * `delegatecall` runs the target's code against this contract's storage and balance. A target
+ * the caller chooses, directly or through an unguarded setter, hands over the whole contract.
+ */
+function scanDelegatecalls(scannerId: string, project: SolidityProject): Finding[] {
+  const findings: Finding[] = [];
+  for (const { source, contract, fn } of contractFunctions(project, true)) {
+    for (const call of externalCalls(fn.body).filter((candidate) => candidate.kind === "delegatecall")) {
+      const root = receiverRoot(call.receiver);
+      if (!root) continue;
+      const line = functionLine(source, fn, call.offset);
+      const param = fn.params.some((candidate) => candidate.name === root);
+      const variable = mutableStateVariables(contract, project).find((candidate) => candidate.name === root);
+
+      let reason: string | undefined;
+      let setter: { file: string; line: number; label: string } | undefined;
+      if (param && isExternallyCallable(fn) && !checksCaller(fn, contract, project)) {
+        reason = `takes the target \`${root}\` straight from its arguments and anyone may call it`;
+      } else if (variable) {
+        const writer = contractFunctions(project)
+          .filter((candidate) => candidate.contract === contract && isExternallyCallable(candidate.fn))
+          .find(
+            (candidate) =>
+              stateWrites(candidate.fn.body, [variable]).length > 0 && !checksCaller(candidate.fn, contract, project)
+          );
+        if (writer) {
+          reason = `reads the target from \`${root}\`, which \`${writer.fn.name}\` lets any caller overwrite`;
+          setter = { file: writer.source.file, line: writer.fn.line, label: `\`${writer.fn.name}\` sets \`${root}\` without an access check` };
+        }
+      }
+      if (!reason) continue;
+
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "arbitrary_delegatecall",
+          severity: "HIGH",
+          confidence: param ? 68 : 62,
+          file: source.file,
+          line,
+          title: "delegatecall to a caller-controlled address",
+          description:
+            `\`${contract.name}.${fn.name}\` ${reason}. The delegated code runs with this contract's storage, balance and ` +
+            "`msg.sender`, so an attacker-deployed target can overwrite the owner or implementation slot or `selfdestruct` the " +
+            "contract. Delegate only to a fixed or allow-listed implementation and restrict who can change it.",
+          evidence: evidenceLine(source.content, line),
+          programModule: contract.name,
+          instruction: fn.name,
+          trace: setter ? [setter, { file: source.file, line, label: `\`delegatecall\` to \`${root}\`` }] : undefined
+        })
+      );
+    }
+  }
+  return findings;
+}
+
+export const evmCallsScanner: Scanner = {
+  id: "scanner.evm.calls",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const project = await loadSolidityProject(rootPath);
+    return [...scanReentrancy(this.id, project), ...scanUncheckedCalls(this.id, project), ...scanDelegatecalls(this.id, project)];
+  }
+};
@@ -0,0 +1,40 @@
+import { promises as fs } from "node:fs";
+import path from "node:path";
+import {
+  buildSolidityProject,
+  isSolidityFile,
+  isSolidityTestFile,
+  type SolidityContract,
+  type SolidityFunction,
+  type SolidityProject,
+  type SoliditySource
+} from "../../analysis/solidity-model";
+import { listFilesRecursive } from "./base";
+
+/** Solidity sources under `rootPath`, minus tests, scripts, mocks and vendored libraries; shared by the EVM scanners and the threat model. */
+export async function loadSolidityProject(rootPath: string): Promise<SolidityProject> {
+  const files = await listFilesRecursive(
+    rootPath,
+    (filePath) => isSolidityFile(filePath) && !isSolidityTestFile(path.relative(rootPath, filePath))
+  );
+  const contents = new Map<string, string>();
+  for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+  return buildSolidityProject(contents);
+}
+
+export interface ContractFunctionRef {
+  source: SoliditySource;
+  contract: SolidityContract;
+  fn: SolidityFunction;
+}
+
+/** Functions (and, with `withModifiers`, modifiers) of every deployable contract; interfaces and libraries hold no state to protect. */
+export function contractFunctions(project: SolidityProject, withModifiers = false): ContractFunctionRef[] {
+  return project.sources.flatMap((source) =>
+    source.contracts
+      .filter((contract) => contract.kind === "contract" || contract.kind === "abstract")
+      .flatMap((contract) =>
+        [...contract.functions, ...(withModifiers ? contract.modifiers : [])].map((fn) => ({ source, contract, fn }))
+      )
+  );
+}
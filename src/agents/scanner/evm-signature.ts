@@ -0,0 +1,73 @@
+import type { Finding } from "../../types";
+import { functionLine, statementStart, type SolidityProject } from "../../analysis/solidity-model";
+import { escapeRegExp, matchingBracket, splitTopLevel } from "../../analysis/rust-source";
+import { evidenceLine, makeFinding, type Scanner } from "./base";
+import { contractFunctions, loadSolidityProject } from "./evm-scope";
+
+/** Digest ingredients that pin a signature to one use, one chain and one verifying contract. */
+const REPLAY_BINDING = /\bnonces?\b|\bnonces?\s*\[|\bblock\s*\.\s*chainid\b|\bDOMAIN_SEPARATOR\b|_domainSeparator\w*|_hashTypedData\w*|\baddress\s*\(\s*this\s*\)|\bdeadline\b|\bused\w*\s*\[/i;
+
+/** The EIP-2 upper bound on `s`, or an explicit comparison of `s` against it. */
+const MALLEABILITY_CHECK = /0x7[fF]{7}|\bs\s*(?:<=?|>)|\buint256\s*\(\s*s\s*\)\s*(?:<=?|>)/;
+
+const ZERO_ADDRESS = String.raw`address\s*\(\s*0\s*\)`;
+
+/**
+ * Raw `ecrecover` returns `address(0)` for malformed signatures, accepts both `s` values of a
+ * signature, and recovers the same signer for the same digest forever. Callers must reject the
+ * zero address and bind the digest to a nonce, the chain and the contract.
+ */
+function scanEcrecover(scannerId: string, project: SolidityProject): Finding[] {
+  const findings: Finding[] = [];
+  for (const { source, contract, fn } of contractFunctions(project)) {
+    for (const match of fn.body.matchAll(/\becrecover\s*\(/g)) {
+      const offset = match.index ?? 0;
+      const open = offset + match[0].length - 1;
+      const close = matchingBracket(fn.body, open);
+      const [digest] = splitTopLevel(close < 0 ? "" : fn.body.slice(open + 1, close));
+      const prefix = fn.body.slice(statementStart(fn.body, offset), offset);
+      const binding = prefix.match(/(\w+)\s*=\s*$/)?.[1];
+
+      const zeroCheck = binding
+        ? new RegExp(`\\b${escapeRegExp(binding)}\\s*[!=]=\\s*${ZERO_ADDRESS}|${ZERO_ADDRESS}\\s*[!=]=\\s*${escapeRegExp(binding)}\\b`).test(fn.body)
+        : new RegExp(ZERO_ADDRESS).test(fn.body.slice(statementStart(fn.body, offset), fn.body.indexOf(";", offset)));
+      // A digest passed in by the caller may be bound elsewhere; only judge digests built here.
+      const digestIsParam = fn.params.some((param) => param.name === digest?.trim());
+      const replayable = !digestIsParam && !REPLAY_BINDING.test(fn.body);
+      const malleable = !MALLEABILITY_CHECK.test(fn.body);
+      if (zeroCheck && !replayable) continue;
+
+      const problems = [
+        ...(zeroCheck ? [] : ["the recovered address is never compared with `address(0)`, so an invalid signature matches an unset signer"]),
+        ...(replayable ? ["the signed digest has no nonce, chain id or contract address, so a signature can be replayed"] : []),
+        ...(malleable ? ["`s` is not restricted to the lower half-order, so each signature has a second valid form"] : [])
+      ];
+      const line = functionLine(source, fn, offset);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unsafe_ecrecover",
+          severity: "HIGH",
+          confidence: zeroCheck ? 58 : 62,
+          file: source.file,
+          line,
+          title: "Unsafe signature verification with raw ecrecover",
+          description:
+            `\`${contract.name}.${fn.name}\` verifies a signature with \`ecrecover\`, but ${problems.join("; ")}. ` +
+            "Use OpenZeppelin's `ECDSA.recover` over an EIP-712 digest that includes a per-signer nonce, and mark the nonce used.",
+          evidence: evidenceLine(source.content, line),
+          programModule: contract.name,
+          instruction: fn.name
+        })
+      );
+    }
+  }
+  return findings;
+}
+
+export const evmSignatureScanner: Scanner = {
+  id: "scanner.evm.signature",
+  async scan(rootPath: string): Promise<Finding[]> {
+    return scanEcrecover(this.id, await loadSolidityProject(rootPath));
+  }
+};
@@ -42,6 +42,8 @@ const patternRules: PatternRule[] = [
       "Credential-like value appears hardcoded in source. Move secrets to a secure secret manager or environment variables.",
     pattern:
       /\b(api[_-]?key|secret|token|password|passwd|private[_-]?key)\b.{0,40}[:=]\s*["'][A-Za-z0-9+/_=-]{12,}["']/i,
+    // In Rust the credential is a string literal, so strings must stay visible.
+    scope: ["code", "string"],
     mitigations: [/\b(process\.env|System\.getenv|os\.environ|getenv\(|ENV\[)\b/i]
   },
   {
@@ -67,6 +69,8 @@ const patternRules: PatternRule[] = [
       "SQL statement appears built via string interpolation/concatenation. Use parameterized queries or prepared statements.",
     pattern:
       /\b(SELECT|INSERT|UPDATE|DELETE)\b.{0,120}(\+|\$\{|format\(|f["']).{0,120}\b(WHERE|VALUES|SET)\b/i,
+    // The SQL keywords sit inside string literals.
+    scope: ["code", "string"],
     mitigations: [
       /\b(prepare|prepared|parameterized|bindParam|bindValue)\b/i,
       /\b(query|execute)\s*\([^,]+,\s*[\[\(]/i
@@ -0,0 +1,55 @@
+import type { Finding } from "../../types";
+import { bodyLine } from "../../analysis/rust-items";
+import { inkStorageWrites, readsCaller, type InkContract, type SubstrateProject } from "../../analysis/substrate-model";
+import { attributeToCrates } from "./anchor-scope";
+import { evidenceLine, makeFinding, type Scanner } from "./base";
+import { loadSubstrateProject, scanBalanceArithmetic } from "./substrate-scope";
+
+function scanCallerChecks(scannerId: string, contract: InkContract, content: string): Finding[] {
+  const findings: Finding[] = [];
+  for (const message of contract.messages) {
+    if (!message.mutates) continue;
+    const writes = inkStorageWrites(message.fn.body, contract.storage);
+    if (writes.length === 0 || readsCaller(message.fn.body, contract.functions)) continue;
+
+    const lines = writes.map((write) => bodyLine(content, message.fn, write.offset));
+    const fields = [...new Set(writes.map((write) => write.store))];
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "missing_caller_check",
+        severity: "HIGH",
+        confidence: 64,
+        file: contract.file,
+        line: lines[0],
+        title: "ink! message mutates storage without checking the caller",
+        description:
+          `\`${message.fn.name}\` is an \`#[ink(message)]\` taking \`&mut self\` that writes ${fields.map((field) => `\`self.${field}\``).join(", ")} ` +
+          "but never reads `self.env().caller()`, directly or through a helper. Any account can call it; compare the " +
+          "caller with the stored owner, or key the write by the caller, before changing state.",
+        evidence: evidenceLine(content, lines[0]),
+        instruction: message.fn.name,
+        trace: [
+          { file: contract.file, line: message.fn.line, label: `\`#[ink(message)] ${message.fn.name}\` (no caller check)` },
+          ...writes.map((write, index) => ({ file: contract.file, line: lines[index], label: `writes \`self.${write.store}\`` }))
+        ]
+      })
+    );
+  }
+  return findings;
+}
+
+function scanInkContracts(scannerId: string, project: SubstrateProject): Finding[] {
+  return project.contracts.flatMap((contract) => {
+    const content = project.contents.get(contract.file) ?? "";
+    return [...scanCallerChecks(scannerId, contract, content), ...scanBalanceArithmetic(scannerId, contract.file, content, contract.functions)];
+  });
+}
+
+export const inkContractScanner: Scanner = {
+  id: "scanner.ink.contract",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const project = await loadSubstrateProject(rootPath);
+    return attributeToCrates(scanInkContracts(this.id, project), project.graph);
+  }
+};
@@ -1,23 +1,44 @@
 import { promises as fs } from "node:fs";
+import path from "node:path";
 import type { Finding } from "../../types";
+import { crateOf, loadCrateGraph, visibleFiles, type RustCrateGraph } from "../../analysis/rust-crates";
 import { listFilesRecursive } from "./base";
 import { LlmClient, type LlmClientOptions } from "../../llm/client";
 import { routeModelWithFallbacks } from "../../llm/router";
 import { renderPrompt } from "../../llm/prompts";
 import { computeTokenBudget, truncateToTokenBudget, estimateTokens } from "../../llm/token-budget";
 import { parseFindingsResponse } from "../../llm/parser";
+import { loadConfiguredProfiles } from "../../profiles/registry";
+import type { ProfileFileSelection } from "../../profiles/types";
 
 export interface LlmScannerOptions {
   vulnFocus: string;
   scannerId: string;
+  /** The files of the profile the scanner belongs to */
+  files: ProfileFileSelection;
+  /** Narrows the files further, such as to those no other profile in a polyglot repo reads */
+  include?: (filePath: string) => boolean;
   clientOptions?: LlmClientOptions;
 }
 
 const MAX_FILE_SIZE_BYTES = 256_000;
-const GENERIC_EXTENSIONS = [".js", ".jsx", ".ts", ".tsx", ".mjs", ".cjs", ".py", ".go", ".java", ".rb", ".php", ".cs"];
 
-function isSolanaScanner(scannerId: string): boolean {
-  return scannerId.includes(".solana.");
+/**
+ * The modules a Rust file imports (Accounts structs, state, helpers), so handlers split across
+ * files are judged with the definitions they rely on. Empty when nothing fits the budget.
+ */
+function relatedContext(graph: RustCrateGraph, filePath: string, maxTokens: number): string {
+  const related = visibleFiles(graph, filePath).slice(1);
+  if (related.length === 0 || maxTokens < 200) return "";
+  const sections = related.map((file) => `// ${file}\n${(graph.sources.get(file) ?? "").trim()}`).join("\n\n");
+  const truncated = truncateToTokenBudget(sections, maxTokens - 100);
+  return [
+    "",
+    "Modules this file imports (context only; report findings in the file above):",
+    "```rust",
+    truncated.text,
+    "```"
+  ].join("\n");
 }
 
 export async function runLlmScanner(
@@ -31,12 +52,15 @@ export async function runLlmScanner(
     fallbackModels: route.fallbacks
   });
 
-  const files = await listFilesRecursive(rootPath, (f) => {
-    if (isSolanaScanner(options.scannerId)) {
-      return f.endsWith(".rs");
-    }
-    return GENERIC_EXTENSIONS.some((ext) => f.endsWith(ext));
-  });
+  const { extensions, exclude, rustCrates } = options.files;
+  const files = await listFilesRecursive(
+    rootPath,
+    (f) =>
+      extensions.some((ext) => f.endsWith(ext)) &&
+      !exclude?.(path.relative(rootPath, f)) &&
+      (options.include?.(f) ?? true)
+  );
+  const graph = rustCrates ? await loadCrateGraph(rootPath, files) : undefined;
   const findings: Finding[] = [];
 
   for (const filePath of files) {
@@ -50,7 +74,10 @@ export async function runLlmScanner(
     const rendered = renderPrompt("scanner", {
       vuln_focus: options.vulnFocus,
       file_path: filePath,
-      code: truncated.text
+      code: truncated.text,
+      related_context: graph
+        ? relatedContext(graph, filePath, budget.maxInputTokens - 500 - truncated.finalTokens)
+        : ""
     });
 
     const response = await client.createMessage({
@@ -63,10 +90,12 @@ export async function runLlmScanner(
 
     const parsed = parseFindingsResponse(response.content, options.scannerId);
 
+    const crate = graph ? crateOf(graph, filePath)?.name : undefined;
     for (const finding of parsed.findings) {
       findings.push({
         ...finding,
-        file: filePath
+        file: filePath,
+        ...(crate ? { crate } : {})
       });
     }
   }
@@ -74,51 +103,22 @@ export async function runLlmScanner(
   return findings;
 }
 
-export const SOLANA_LLM_SCANNER_CONFIGS = [
-  {
-    vulnFocus: "missing signer check, missing has_one constraint, and account type confusion",
-    scannerId: "llm.scanner.solana.account-validation"
-  },
-  {
-    vulnFocus: "arbitrary CPI, CPI signer seed bypass, and CPI reentrancy",
-    scannerId: "llm.scanner.solana.cpi"
-  },
-  {
-    vulnFocus: "non-canonical bump, seed collision, and attacker-controlled seed",
-    scannerId: "llm.scanner.solana.pda"
-  }
-] as const;
-
-export const GENERIC_LLM_SCANNER_CONFIGS = [
-  {
-    vulnFocus: "hardcoded secrets and credential leakage",
-    scannerId: "llm.scanner.generic.secrets"
-  },
-  {
-    vulnFocus: "command injection and unsafe shell/process execution",
-    scannerId: "llm.scanner.generic.command-injection"
-  },
-  {
-    vulnFocus: "sql injection, xss sinks, and insecure deserialization",
-    scannerId: "llm.scanner.generic.injection-and-deserialization"
-  }
-] as const;
-
-export const LLM_SCANNER_CONFIGS = [...GENERIC_LLM_SCANNER_CONFIGS, ...SOLANA_LLM_SCANNER_CONFIGS] as const;
-
 export async function runAllLlmScanners(
   rootPath: string,
   clientOptions?: LlmClientOptions
 ): Promise<Finding[]> {
   const allFindings: Finding[] = [];
 
-  for (const config of LLM_SCANNER_CONFIGS) {
-    const findings = await runLlmScanner(rootPath, {
-      vulnFocus: config.vulnFocus,
-      scannerId: config.scannerId,
-      clientOptions
-    });
-    allFindings.push(...findings);
+  for (const profile of await loadConfiguredProfiles()) {
+    for (const config of profile.llmScanners) {
+      const findings = await runLlmScanner(rootPath, {
+        vulnFocus: config.vulnFocus,
+        scannerId: config.scannerId,
+        files: profile.files,
+        clientOptions
+      });
+      allFindings.push(...findings);
+    }
   }
 
   return allFindings;
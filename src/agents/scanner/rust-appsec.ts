@@ -0,0 +1,471 @@
+import { promises as fs } from "node:fs";
+import type { Finding, FindingLocation } from "../../types";
+import { loadCrateGraph } from "../../analysis/rust-crates";
+import { bodyLine, extractFunctions, type RustFunction } from "../../analysis/rust-items";
+import { maskRust, NON_COMMENT_REGIONS } from "../../analysis/rust-lexer";
+import { lineAt, lineStarts, matchingBracket, splitTopLevel } from "../../analysis/rust-source";
+import {
+  derivedBindings,
+  extractRequestHandlers,
+  mentionsBinding,
+  routedFunctions,
+  type RequestHandler
+} from "../../analysis/rust-web";
+import { attributeToCrates } from "./anchor-scope";
+import { listFilesRecursive, makeFinding, type Scanner } from "./base";
+
+const SHELL_PROGRAM = /^"(?:(?:\/usr)?\/bin\/)?(?:sh|bash|zsh|dash)"$|^"(?:cmd|powershell|pwsh)(?:\.exe)?"$/i;
+const SHELL_SCRIPT_FLAG = /^"(?:-c|\/c|-Command)"$/i;
+const COMMAND_NEW = /\b(?:std\s*::\s*process\s*::\s*|tokio\s*::\s*process\s*::\s*)?Command\s*::\s*new\s*\(/g;
+const COMMAND_ARG = /\.\s*(arg|args)\s*\(/g;
+/** sqlx, diesel, rusqlite, postgres and tokio-postgres calls whose first argument is the SQL text. */
+const SQL_CALL =
+  /\b(?:sqlx\s*::\s*)?(?:query|query_as|query_scalar|query_with|query_as_with|raw_sql|sql_query)\s*(?:::\s*<[^()]*?>\s*)?\(|\.\s*(?:execute|query|query_one|query_opt|query_row|query_map|prepare|prepare_cached|execute_batch|batch_execute|simple_query)\s*\(/g;
+/** File-system calls whose first argument names the file. */
+const FS_SINK =
+  /\b(?:fs\s*::\s*(?:read|read_to_string|read_dir|write|remove_file|remove_dir_all|create_dir_all|copy|rename|metadata)|File\s*::\s*(?:open|create)|NamedFile\s*::\s*open(?:_async)?|ServeFile\s*::\s*new)\s*\(/g;
+const PATH_EXTEND = /(\w+)?(?:\s*\.\s*\w+(?:\s*\(\s*\))?)*\s*\.\s*(join|push)\s*\(/g;
+const PATH_BUFFER = /\blet\s+mut\s+(\w+)\s*(?::\s*PathBuf\s*)?=\s*[^;]*(?:PathBuf|to_path_buf|\.join\s*\()/g;
+/** A prefix check on the resolved path, a `..` rejection or a file-name-only reduction. */
+const TRAVERSAL_GUARD =
+  /\bcanonicalize\s*\([\s\S]*\bstarts_with\s*\(|\.\s*contains\s*\(\s*"\.\."|Component\s*::\s*(?:ParentDir|Normal|RootDir)|\bsanitize(?:_filename|_path)?\b|\.\s*file_name\s*\(\s*\)|\b(?:safe_join|secure_join|is_safe_path|normalize_path)\b/;
+/**
+ * Extracted values that cannot carry a traversal: `Path<u64>`, `Query<(i32, bool)>`, `Json<Uuid>`, and
+ * Rocket's `PathBuf` segments guard, which already rejects `..` and absolute segments.
+ */
+const SAFE_PATH_INPUT =
+  /^(?:[\w:]*::)?\w+\s*<\s*\(?\s*(?:(?:[ui](?:8|16|32|64|128|size)|f32|f64|bool|(?:uuid\s*::\s*)?Uuid)\s*,?\s*)+\)?\s*>$|^PathBuf$/;
+const PANIC_SITE =
+  /(?<!\b(?:lock|read|write)\s*\(\s*\)\s*)\.\s*(?:unwrap|expect)\s*\(|\b(?:panic|unreachable|todo|unimplemented|assert|assert_eq|assert_ne)!\s*\(/g;
+const PANIC_GUARD = /\bCatchPanicLayer\b|\bcatch_unwind\s*\(/;
+const SAFETY_COMMENT = /\bSAFETY\s*:|#\s*Safety\b/i;
+
+function flat(text: string): string {
+  const compact = text.replace(/\s+/g, " ").trim();
+  return compact.length > 120 ? `${compact.slice(0, 117)}...` : compact;
+}
+
+function callArgs(body: string, openParen: number): string[] {
+  const close = matchingBracket(body, openParen);
+  return splitTopLevel(body.slice(openParen + 1, close < 0 ? body.length : close));
+}
+
+/** Locals bound to `format!(...)`, with the body offset of the binding. */
+function formatBindings(body: string): Map<string, number> {
+  const bound = new Map<string, number>();
+  for (const match of body.matchAll(/\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=;]+)?=\s*format!\s*\(/g)) {
+    bound.set(match[1], match.index ?? 0);
+  }
+  return bound;
+}
+
+/** The `format!` binding an argument refers to (`&sql`, `sql.as_str()`, `cmd.clone()`), or `inline` for `format!(...)` itself. */
+function formatted(arg: string | undefined, bindings: Map<string, number>): string | undefined {
+  if (!arg) return undefined;
+  if (/\bformat!\s*\(/.test(arg)) return "inline";
+  const name = arg.match(/^&?\s*\*?\s*(\w+)(?:\s*\.\s*(?:as_str|as_ref|clone|to_string|to_owned)\s*\(\s*\))*$/)?.[1];
+  return name && bindings.has(name) ? name : undefined;
+}
+
+/** `args(["-c", x])`, `args(&[..])` and `args(vec![..])` as their elements; `arg(x)` as one. */
+function commandArgs(body: string, from: number): { value: string; offset: number }[] {
+  const args: { value: string; offset: number }[] = [];
+  for (const match of body.slice(from).matchAll(COMMAND_ARG)) {
+    const offset = from + (match.index ?? 0);
+    const [first] = callArgs(body, offset + match[0].length - 1);
+    if (!first) continue;
+    if (match[1] === "arg") {
+      args.push({ value: first, offset });
+      continue;
+    }
+    const list = first.match(/^&?\s*(?:vec!\s*)?\[([\s\S]*)\]$/);
+    for (const value of list ? splitTopLevel(list[1]) : [first]) args.push({ value, offset });
+  }
+  return args;
+}
+
+interface Site {
+  fn: RustFunction;
+  handler?: RequestHandler;
+}
+
+function scanCommands(scannerId: string, file: string, content: string, { fn, handler }: Site): Finding[] {
+  const findings: Finding[] = [];
+  const body = fn.body;
+  const bindings = formatBindings(body);
+  const tainted = handler ? derivedBindings(body, handler.inputs.map((input) => input.name)) : new Set<string>();
+
+  for (const call of body.matchAll(COMMAND_NEW)) {
+    const offset = call.index ?? 0;
+    const program = callArgs(body, offset + call[0].length - 1)[0]?.trim();
+    if (!program) continue;
+    const line = bodyLine(content, fn, offset);
+    const args = commandArgs(body, offset);
+    const builtFrom = (value: string): FindingLocation[] => {
+      const binding = formatted(value, bindings);
+      return binding && binding !== "inline"
+        ? [{ file, line: bodyLine(content, fn, bindings.get(binding)!), label: `\`${binding}\` built with format!` }]
+        : [];
+    };
+
+    if (SHELL_PROGRAM.test(program)) {
+      const flag = args.findIndex((arg) => SHELL_SCRIPT_FLAG.test(arg.value));
+      const script = flag >= 0 ? args[flag + 1] : undefined;
+      if (!script || /^"(?:[^"\\]|\\.)*"$/.test(script.value)) continue;
+      const scriptLine = bodyLine(content, fn, script.offset);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "rust_command_injection",
+          severity: "CRITICAL",
+          confidence: mentionsBinding(script.value, tainted) || formatted(script.value, bindings) ? 72 : 62,
+          file,
+          line: scriptLine,
+          title: "Shell command line built from dynamic input",
+          description:
+            `\`${fn.name}\` runs ${program} with \`${flat(script.value)}\` as its script. The shell re-parses that string, so any ` +
+            "`;`, `|`, `$(...)` or quote in interpolated values runs as an extra command. Invoke the program directly with " +
+            "`Command::new(program).arg(value)` and let the OS pass each argument verbatim.",
+          evidence: flat(body.slice(script.offset, script.offset + 120).split(";")[0]),
+          trace: [
+            ...builtFrom(script.value),
+            { file, line, label: `\`Command::new(${program})\`` },
+            { file, line: scriptLine, label: "dynamic script passed after the `-c` flag" }
+          ]
+        })
+      );
+      continue;
+    }
+
+    if (formatted(program, bindings) || mentionsBinding(program, tainted)) {
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "rust_command_injection",
+          severity: "HIGH",
+          confidence: 68,
+          file,
+          line,
+          title: "Executable path built from dynamic input",
+          description:
+            `\`${fn.name}\` chooses the program to run from \`${flat(program)}\`. Whoever controls the interpolated value picks ` +
+            "which binary executes; map the request onto a fixed allowlist of executables instead.",
+          evidence: `Command::new(${flat(program)})`,
+          trace: [...builtFrom(program), { file, line, label: "program spawned" }]
+        })
+      );
+      continue;
+    }
+
+    const arg = args.find((candidate) => formatted(candidate.value, bindings));
+    if (!arg) continue;
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "rust_command_injection",
+        severity: "MEDIUM",
+        confidence: 60,
+        file,
+        line: bodyLine(content, fn, arg.offset),
+        title: "Command argument built with format!",
+        description:
+          `\`${fn.name}\` passes \`${flat(arg.value)}\` to ${program}. No shell is involved, but an interpolated value that starts ` +
+          "with `-` or embeds `=` can turn into an option of the spawned program (argument injection). Pass untrusted values " +
+          "as separate arguments after `--`, and validate them against the values the program expects.",
+        evidence: flat(arg.value),
+        trace: [...builtFrom(arg.value), { file, line, label: `\`Command::new(${program})\`` }]
+      })
+    );
+  }
+  return findings;
+}
+
+function scanQueries(scannerId: string, file: string, content: string, { fn, handler }: Site): Finding[] {
+  const findings: Finding[] = [];
+  const body = fn.body;
+  const bindings = formatBindings(body);
+
+  for (const call of body.matchAll(SQL_CALL)) {
+    const offset = call.index ?? 0;
+    const [sql] = callArgs(body, offset + call[0].length - 1);
+    const binding = formatted(sql?.trim(), bindings);
+    if (!binding) continue;
+    const line = bodyLine(content, fn, offset);
+    const trace: FindingLocation[] =
+      binding === "inline" ? [] : [{ file, line: bodyLine(content, fn, bindings.get(binding)!), label: `\`${binding}\` built with format!` }];
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "rust_sql_injection",
+        severity: "HIGH",
+        confidence: handler ? 72 : 70,
+        file,
+        line,
+        title: "SQL statement built with format!",
+        description:
+          `\`${fn.name}\` sends \`${flat(sql)}\` as SQL text. Values interpolated with \`format!\` become part of the statement, ` +
+          "so a quote in them rewrites the query. Keep the SQL constant and pass values with `.bind(...)` (or use the " +
+          "compile-time checked `sqlx::query!` macros); identifiers that must vary belong in an allowlist.",
+        evidence: flat(body.slice(offset, offset + 160).split(";")[0]),
+        trace: [...trace, { file, line, label: "executed as SQL" }]
+      })
+    );
+  }
+  return findings;
+}
+
+function scanPathTraversal(scannerId: string, file: string, content: string, handler: RequestHandler): Finding[] {
+  const { fn } = handler;
+  const body = fn.body;
+  const inputs = handler.inputs.filter((input) => !SAFE_PATH_INPUT.test(input.type));
+  if (inputs.length === 0 || TRAVERSAL_GUARD.test(body)) return [];
+  const tainted = derivedBindings(body, inputs.map((input) => input.name));
+  const buffers = new Set([...body.matchAll(PATH_BUFFER)].map((match) => match[1]));
+
+  const sites: { offset: number; label: string }[] = [];
+  for (const call of body.matchAll(PATH_EXTEND)) {
+    const [first] = callArgs(body, (call.index ?? 0) + call[0].length - 1);
+    if (!first || !mentionsBinding(first, tainted)) continue;
+    if (call[2] === "push" && !(call[1] && buffers.has(call[1]))) continue;
+    sites.push({ offset: (call.index ?? 0) + call[0].lastIndexOf(call[2]), label: `request value appended to a path with \`.${call[2]}(${flat(first)})\`` });
+  }
+  for (const call of body.matchAll(FS_SINK)) {
+    const [first] = callArgs(body, (call.index ?? 0) + call[0].length - 1);
+    if (!first || !mentionsBinding(first, tainted)) continue;
+    sites.push({ offset: call.index ?? 0, label: `\`${flat(call[0].slice(0, -1))}\` opens the request-derived path` });
+  }
+  if (sites.length === 0) return [];
+  sites.sort((a, b) => a.offset - b.offset);
+
+  const input = inputs.find((candidate) => mentionsBinding(body, [candidate.name])) ?? inputs[0];
+  const line = bodyLine(content, fn, sites[0].offset);
+  return [
+    makeFinding({
+      scannerId,
+      vulnClass: "path_traversal",
+      severity: "HIGH",
+      confidence: 66,
+      file,
+      line,
+      title: "File path built from request input",
+      description:
+        `Handler \`${fn.name}\` joins \`${input.name}: ${input.type}\` onto a file-system path without rejecting \`..\` ` +
+        "components or checking that the canonicalized result stays under the intended directory. A request such as " +
+        "`../../etc/passwd` (or an absolute path, which replaces the base in `Path::join`) reads or writes outside it.",
+      evidence: flat(content.split(/\r?\n/)[line - 1] ?? ""),
+      trace: [
+        { file, line: fn.line, label: `request input \`${input.name}: ${input.type}\`` },
+        ...sites.map((site) => ({ file, line: bodyLine(content, fn, site.offset), label: site.label }))
+      ]
+    })
+  ];
+}
+
+function panicSites(fn: RustFunction): number[] {
+  return [...fn.body.matchAll(PANIC_SITE)].map((match) => match.index ?? 0);
+}
+
+function scanPanics(scannerId: string, file: string, content: string, handler: RequestHandler, helpers: RustFunction[]): Finding[] {
+  const { fn } = handler;
+  const lines = content.split(/\r?\n/);
+  const source = (line: number): string => flat(lines[line - 1] ?? "");
+  const trace: FindingLocation[] = panicSites(fn).map((offset) => {
+    const line = bodyLine(content, fn, offset);
+    return { file, line, label: source(line) };
+  });
+  // One level into local helpers: `load(&id)` or `self.load(&id)` where `load` unwraps.
+  for (const helper of helpers) {
+    if (helper === fn) continue;
+    const call = fn.body.match(new RegExp(`(?<![\\w:])(?:self\\s*\\.\\s*|Self\\s*::\\s*)?${helper.name}\\s*\\(`));
+    const [inner] = panicSites(helper);
+    if (!call || inner === undefined) continue;
+    const innerLine = bodyLine(content, helper, inner);
+    trace.push(
+      { file, line: bodyLine(content, fn, call.index ?? 0), label: `calls \`${helper.name}\`` },
+      { file, line: innerLine, label: `\`${helper.name}\` panics: ${source(innerLine)}` }
+    );
+  }
+  if (trace.length === 0) return [];
+  // Each helper contributes its call site and its first panic to the trace.
+  const sites = trace.filter((step) => !step.label.startsWith("calls ")).length;
+
+  return [
+    makeFinding({
+      scannerId,
+      vulnClass: "handler_panic",
+      severity: "MEDIUM",
+      confidence: 56,
+      file,
+      line: trace[0].line,
+      title: "Request handler can panic on client input",
+      description:
+        `Handler \`${fn.name}\` reaches ${sites === 1 ? "a panic" : `${sites} panic sites`} (\`unwrap\`, \`expect\`, ` +
+        "`panic!`, failed assertions). A request that trips one aborts the connection, poisons any mutex held at the time and, " +
+        'with `panic = "abort"`, takes the whole service down. Return an error response instead, or install a ' +
+        "`CatchPanicLayer`.",
+      evidence: source(trace[0].line),
+      trace
+    })
+  ];
+}
+
+function scanDeserialization(scannerId: string, file: string, content: string): Finding[] {
+  const code = maskRust(content, NON_COMMENT_REGIONS);
+  const starts = lineStarts(code);
+  const findings: Finding[] = [];
+  const report = (offset: number, severity: "HIGH" | "MEDIUM", confidence: number, title: string, description: string): void => {
+    const line = lineAt(starts, offset);
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "unbounded_deserialization",
+        severity,
+        confidence,
+        file,
+        line,
+        title,
+        description,
+        evidence: flat(content.split(/\r?\n/)[line - 1] ?? "")
+      })
+    );
+  };
+
+  for (const call of code.matchAll(/\bbincode\s*::\s*(deserialize_from|deserialize)\s*\(/g)) {
+    const [source] = callArgs(code, (call.index ?? 0) + call[0].length - 1);
+    if (/\btake\s*\(/.test(source ?? "")) continue;
+    const stream = call[1] === "deserialize_from";
+    report(
+      call.index ?? 0,
+      stream ? "HIGH" : "MEDIUM",
+      stream ? 64 : 55,
+      "bincode decoding without a size limit",
+      `\`bincode::${call[1]}\` uses no byte limit, so length prefixes in the input decide how much memory is allocated. ` +
+        "A few crafted bytes can claim gigabytes. Decode through `bincode::options().with_limit(n)` sized to the largest " +
+        "legitimate message."
+    );
+  }
+
+  for (const call of code.matchAll(
+    /\b(serde_json|serde_cbor|ciborium(?:\s*::\s*de)?|serde_yaml|rmp_serde|ron\s*::\s*de|serde_pickle)\s*::\s*(from_reader|from_read)\s*\(/g
+  )) {
+    const [source] = callArgs(code, (call.index ?? 0) + call[0].length - 1);
+    if (/\btake\s*\(/.test(source ?? "")) continue;
+    report(
+      call.index ?? 0,
+      "MEDIUM",
+      58,
+      "Deserializing an unbounded reader",
+      `\`${call[1].replace(/\s+/g, "")}::${call[2]}\` consumes \`${flat(source ?? "")}\` until the document ends, with no cap on its ` +
+        "size or nesting. Wrap the reader in `.take(limit)` (or read a bounded buffer first) before decoding untrusted input."
+    );
+  }
+
+  for (const call of code.matchAll(/\bhyper\s*::\s*body\s*::\s*to_bytes\s*\(|\bto_bytes\s*\(\s*[^,()]+,\s*usize\s*::\s*MAX\s*\)/g)) {
+    report(
+      call.index ?? 0,
+      "MEDIUM",
+      58,
+      "Request body read without a size limit",
+      "The whole request body is buffered in memory with no upper bound, so one large or slow upload exhausts memory before any " +
+        "deserializer runs. Pass a real limit (`axum::body::to_bytes(body, LIMIT)`, `http_body_util::Limited`)."
+    );
+  }
+
+  for (const call of code.matchAll(/\bDefaultBodyLimit\s*::\s*disable\s*\(\s*\)|\.\s*limit\s*\(\s*usize\s*::\s*MAX\s*\)|\bPayloadConfig\s*::\s*new\s*\(\s*usize\s*::\s*MAX\s*\)/g)) {
+    report(
+      call.index ?? 0,
+      "MEDIUM",
+      58,
+      "Request body limit disabled",
+      `\`${flat(call[0])}\` removes the framework's default cap on request bodies, so \`Json\`, \`Form\` and \`Bytes\` extractors ` +
+        "buffer whatever the client sends. Set an explicit limit sized to the largest legitimate request instead."
+    );
+  }
+
+  return findings;
+}
+
+function scanUnsafe(scannerId: string, file: string, content: string, handlers: RequestHandler[]): Finding[] {
+  const code = maskRust(content, ["code"]);
+  const compiled = maskRust(content, NON_COMMENT_REGIONS);
+  const starts = lineStarts(code);
+  const lines = content.split(/\r?\n/);
+  const findings: Finding[] = [];
+  const seen = new Set<number>();
+
+  const sites: { offset: number; kind: string; confidence: number }[] = [
+    ...[...code.matchAll(/\bunsafe\s*\{/g)].map((match) => ({ offset: match.index ?? 0, kind: "unsafe block", confidence: 55 })),
+    ...[...code.matchAll(/\bunsafe\s+impl\b/g)].map((match) => ({ offset: match.index ?? 0, kind: "unsafe impl", confidence: 55 })),
+    ...[...code.matchAll(/\bunsafe\s+fn\s+\w+[^;{]*\{/g)].map((match) => ({ offset: match.index ?? 0, kind: "unsafe fn", confidence: 52 })),
+    ...[...compiled.matchAll(/\bextern\s+"[^"]*"\s*\{/g)].map((match) => ({ offset: match.index ?? 0, kind: "foreign function import", confidence: 52 })),
+    ...[...compiled.matchAll(/#\[\s*(?:unsafe\s*\(\s*)?no_mangle\b|\bextern\s+"[^"]*"\s+fn\b/g)].map((match) => ({
+      offset: match.index ?? 0,
+      kind: "exported foreign function",
+      confidence: 52
+    }))
+  ];
+
+  for (const site of sites.sort((a, b) => a.offset - b.offset)) {
+    const line = lineAt(starts, site.offset);
+    // `#[no_mangle]` and the `extern "C" fn` under it are one export.
+    if (seen.has(line) || (site.kind === "exported foreign function" && seen.has(line - 1))) continue;
+    seen.add(line);
+    // A `// SAFETY:` comment (or `# Safety` doc section) just above the site documents the invariant it relies on.
+    if (lines.slice(Math.max(0, line - 4), line).some((text) => SAFETY_COMMENT.test(text))) continue;
+
+    const handler = handlers.find(({ fn }) => fn.line <= line && line <= fn.endLine);
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "unsafe_code",
+        severity: "MEDIUM",
+        confidence: handler ? site.confidence + 5 : site.confidence,
+        file,
+        line,
+        title: `Undocumented ${site.kind}`,
+        description:
+          `${site.kind[0].toUpperCase()}${site.kind.slice(1)} without a \`// SAFETY:\` justification` +
+          (handler ? `, reachable from request handler \`${handler.fn.name}\`` : "") +
+          ". The compiler no longer checks memory safety here: aliasing, lifetimes, bounds and the foreign side's contract " +
+          "must hold by hand. State the invariant the code relies on, and keep untrusted lengths and pointers out of it.",
+        evidence: flat(lines[line - 1] ?? "")
+      })
+    );
+  }
+  return findings;
+}
+
+export const rustAppSecScanner: Scanner = {
+  id: "scanner.rust.appsec",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+    const graph = await loadCrateGraph(rootPath, files);
+    const contents = new Map<string, string>();
+    for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+
+    // Routers often live in main.rs while the handlers they name live in their own modules.
+    const routed = new Set([...contents.values()].flatMap((content) => [...routedFunctions(content)]));
+    const catchesPanics = [...contents.values()].some((content) => PANIC_GUARD.test(content));
+    const findings: Finding[] = [];
+
+    for (const [file, content] of contents) {
+      const functions = extractFunctions(content);
+      const handlers = extractRequestHandlers(content, routed);
+      const handlerOf = (fn: RustFunction): RequestHandler | undefined => handlers.find((handler) => handler.fn.line === fn.line);
+      const helpers = functions.filter((fn) => !handlerOf(fn));
+
+      for (const fn of functions) {
+        const handler = handlerOf(fn);
+        findings.push(...scanCommands(this.id, file, content, { fn, handler }));
+        findings.push(...scanQueries(this.id, file, content, { fn, handler }));
+        if (!handler) continue;
+        findings.push(...scanPathTraversal(this.id, file, content, handler));
+        if (!catchesPanics) findings.push(...scanPanics(this.id, file, content, handler, helpers));
+      }
+      findings.push(...scanDeserialization(this.id, file, content));
+      findings.push(...scanUnsafe(this.id, file, content, handlers));
+    }
+
+    return attributeToCrates(findings, graph);
+  }
+};
@@ -0,0 +1,338 @@
+import { promises as fs } from "node:fs";
+import type { Finding, FindingLocation, VulnClass } from "../../types";
+import {
+  extractAnchorAccounts,
+  hasConstraint,
+  isUncheckedField,
+  type AnchorAccountsStruct,
+  type AnchorInstruction
+} from "../../analysis/anchor-model";
+import { checksKey, checksOwner, nativeAccountNames } from "../../analysis/native-model";
+import { bodyLine, extractFunctions, type RustFunction } from "../../analysis/rust-items";
+import { escapeRegExp, matchingBracket, splitTopLevel } from "../../analysis/rust-source";
+import { loadCrateGraph, type RustCrateGraph } from "../../analysis/rust-crates";
+import {
+  accountsStructFor,
+  attributeToCrates,
+  collectProgramInstructions,
+  scopeFindingsToInstructions
+} from "./anchor-scope";
+import { listFilesRecursive, makeFinding, markerFindings, type Scanner } from "./base";
+
+const MARKER_CLASSES: VulnClass[] = ["unchecked_remaining_accounts", "sysvar_spoofing"];
+
+/** Typed loads that verify owner (and discriminator) of a raw account: `Account::<Pool>::try_from(acc)`. */
+const TYPED_LOAD = "\\b(?:Account|AccountLoader|InterfaceAccount|Program|Interface|Signer|SystemAccount|Sysvar)\\s*(?:::\\s*<[^>]*>)?\\s*::\\s*try_from(?:_unchecked)?\\s*\\(\\s*&?\\s*";
+/** Helpers that vet an account passed to them, e.g. `validate_pool(&acc)?`. */
+const VALIDATOR = "\\b(?:validate|verify|check|assert)\\w*\\s*\\(\\s*&?\\s*";
+/** Field and binding names that conventionally hold a sysvar account. */
+const SYSVAR_NAME =
+  /^(?:sysvar_)?(?:instructions?|ixs?|clock|rent|slot_hashes|recent_blockhashes|stake_history|epoch_schedule|epoch_rewards)(?:_(?:sysvar|account|info))?$|^\w+_sysvar$/;
+/** Sysvar readers that compare the account key against the sysvar id themselves. */
+const CHECKED_READER =
+  /\b(?:load_instruction_at_checked|load_current_index_checked|get_instruction_relative|\w+\s*::\s*from_account_info)\s*\(/g;
+/** Readers that trust whatever account or bytes they are given. */
+const UNCHECKED_READER = /\b(?:load_instruction_at|load_current_index|deserialize_instruction)\s*\(/g;
+
+interface RemainingEntry {
+  /** Binding of one entry, or the indexing expression itself when it is used in place */
+  name: string;
+  offset: number;
+  text: string;
+}
+
+function statementText(body: string, offset: number): string {
+  const end = body.indexOf(";", offset);
+  return body.slice(offset, end < 0 ? body.length : end).replace(/\s+/g, " ").trim();
+}
+
+/** Collections and iterators that stand for `ctx.remaining_accounts`, including `let` aliases. */
+function remainingSources(body: string): { collections: string[]; iterators: string[] } {
+  const collections = [...new Set([...body.matchAll(/\b(\w+)\s*\.\s*remaining_accounts\b/g)].map((match) => `${match[1]}.remaining_accounts`))];
+  const iterators: string[] = [];
+  for (let changed = true; changed; ) {
+    changed = false;
+    const source = collections.map(escapeRegExp).join("|");
+    const alias = new RegExp(`\\blet\\s+(?:mut\\s+)?(\\w+)\\s*(?::[^=]+)?=\\s*&?\\s*(?:mut\\s+)?(?:${source})\\s*(\\.\\s*iter\\s*\\(\\s*\\))?\\s*;`, "g");
+    for (const match of body.matchAll(alias)) {
+      const target = match[2] ? iterators : collections;
+      if (collections.includes(match[1]) || iterators.includes(match[1])) continue;
+      target.push(match[1]);
+      changed = true;
+    }
+  }
+  return { collections, iterators };
+}
+
+/** Every place a handler takes a single account out of `remaining_accounts`. */
+function remainingEntries(body: string): RemainingEntry[] {
+  const { collections, iterators } = remainingSources(body);
+  if (collections.length === 0) return [];
+  const source = `(?:${collections.map(escapeRegExp).join("|")})`;
+  const iterator = iterators.length > 0 ? `(?:${iterators.map(escapeRegExp).join("|")})` : undefined;
+  const entries: RemainingEntry[] = [];
+  const push = (name: string, offset: number, text = statementText(body, offset)): void => {
+    if (!entries.some((entry) => entry.name === name)) entries.push({ name, offset, text });
+  };
+
+  const loops = new RegExp(`\\bfor\\s+(\\(?[\\w\\s,&]+?\\)?)\\s+in\\s+&?\\s*${source}\\s*(?:\\.\\s*iter\\s*\\(\\s*\\)\\s*)?(\\.\\s*enumerate\\s*\\(\\s*\\))?`, "g");
+  for (const match of body.matchAll(loops)) {
+    const names = match[1].replace(/[()&]/g, "").split(",").map((name) => name.trim()).filter(Boolean);
+    push(names[match[2] ? 1 : 0] ?? names[0], match.index ?? 0, match[0].replace(/\s+/g, " ").trim());
+  }
+  const picks = [
+    new RegExp(`\\blet\\s+(\\w+)\\s*(?::[^=]+)?=\\s*&?\\s*${source}\\s*(?:\\[|\\.\\s*(?:get|first|last)\\s*\\()`, "g"),
+    new RegExp(`${source}\\s*\\.\\s*iter\\s*\\(\\s*\\)\\s*\\.\\s*(?:map|for_each|try_for_each|filter|filter_map|find|any|all)\\s*\\(\\s*\\|\\s*&?\\s*(\\w+)`, "g")
+  ];
+  if (iterator) {
+    picks.push(
+      new RegExp(`\\blet\\s+(\\w+)\\s*(?::[^=]+)?=\\s*(?:next_account_info\\s*\\(\\s*(?:&\\s*mut\\s+)?${iterator}\\s*\\)|${iterator}\\s*\\.\\s*next\\s*\\()`, "g")
+    );
+  }
+  for (const pattern of picks) {
+    for (const match of body.matchAll(pattern)) push(match[1], match.index ?? 0);
+  }
+  const destructured = new RegExp(`\\blet\\s+\\[([^\\]]*)\\]\\s*=\\s*&?\\s*${source}\\b`, "g");
+  for (const match of body.matchAll(destructured)) {
+    for (const name of match[1].split(",").map((part) => part.replace(/\bref\b|&|\.\./g, "").trim())) {
+      if (/^[a-z_]\w*$/.test(name) && name !== "_") push(name, match.index ?? 0);
+    }
+  }
+  // Entries used in place, e.g. `ctx.remaining_accounts[0].try_borrow_data()`.
+  for (const match of body.matchAll(new RegExp(`${source}\\s*\\[[^\\]]+\\]`, "g"))) {
+    const after = body.slice((match.index ?? 0) + match[0].length).trimStart();
+    if (/^[.,)]/.test(after)) push(match[0].replace(/\s+/g, ""), match.index ?? 0);
+  }
+  return entries.sort((a, b) => a.offset - b.offset);
+}
+
+/** First use of an entry as an account after it is taken: a member access, or passing it on. */
+function firstUse(body: string, entry: RemainingEntry): { offset: number; label: string } | undefined {
+  const name = escapeRegExp(entry.name);
+  const use = new RegExp(`(?<![\\w.])${name}\\s*\\.\\s*(\\w+)|[(,]\\s*&?\\s*${name}\\s*[,)]`, "g");
+  use.lastIndex = entry.offset + (/^\w+$/.test(entry.name) ? entry.text.indexOf(entry.name) + entry.name.length : 0);
+  const match = use.exec(body);
+  if (!match) return undefined;
+  return { offset: match.index, label: match[1] ? `\`${entry.name}.${match[1]}\` used` : `\`${entry.name}\` passed on` };
+}
+
+function entryValidated(body: string, entry: RemainingEntry): boolean {
+  const name = escapeRegExp(entry.name);
+  return (
+    checksOwner(body, entry.name) ||
+    checksKey(body, entry.name) ||
+    new RegExp(`${TYPED_LOAD}${name}(?!\\w)`).test(body) ||
+    new RegExp(`${VALIDATOR}${name}(?!\\w)`).test(body)
+  );
+}
+
+function scanRemainingAccounts(scannerId: string, file: string, content: string, functions: RustFunction[]): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const fn of functions) {
+    if (!/\bremaining_accounts\b/.test(fn.body)) continue;
+    for (const entry of remainingEntries(fn.body)) {
+      const use = firstUse(fn.body, entry);
+      if (!use || entryValidated(fn.body, entry)) continue;
+
+      const line = bodyLine(content, fn, entry.offset);
+      const trace: FindingLocation[] = [
+        { file, line, label: `\`${entry.name}\` taken from remaining_accounts` },
+        { file, line: bodyLine(content, fn, use.offset), label: use.label }
+      ];
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unchecked_remaining_accounts",
+          severity: "HIGH",
+          confidence: 66,
+          file,
+          line,
+          title: "remaining_accounts entry used without owner, key or type check",
+          description:
+            `\`${fn.name}\` uses \`${entry.name}\` from remaining_accounts without checking its owner or address and ` +
+            "without loading it through a typed `Account::try_from`. Anchor validates none of these accounts, so the " +
+            "caller can pass any account with forged data in its place.",
+          evidence: entry.text,
+          trace
+        })
+      );
+    }
+  }
+
+  return findings;
+}
+
+/** References to one account in a handler body: the `ctx.accounts` path (or native binding) and its `let` aliases. */
+function accountRefs(body: string, path: string): string[] {
+  const alias = new RegExp(
+    `\\blet\\s+(?:mut\\s+)?(\\w+)\\s*(?::[^=]+)?=\\s*&?\\s*${escapeRegExp(path)}(?:\\s*\\.\\s*to_account_info\\s*\\(\\s*\\))?\\s*;`,
+    "g"
+  );
+  return [path, ...[...body.matchAll(alias)].map((match) => match[1])];
+}
+
+/** Calls of `reader` with one of `refs` among their arguments. */
+function readerCalls(body: string, reader: RegExp, refs: string[]): number[] {
+  const offsets: number[] = [];
+  for (const match of body.matchAll(reader)) {
+    const open = (match.index ?? 0) + match[0].length - 1;
+    const close = matchingBracket(body, open);
+    const args = splitTopLevel(body.slice(open + 1, close < 0 ? body.length : close));
+    if (args.some((arg) => refs.some((ref) => new RegExp(`(?<![\\w.])${escapeRegExp(ref)}\\b`).test(arg)))) {
+      offsets.push(match.index ?? 0);
+    }
+  }
+  return offsets;
+}
+
+interface SysvarUse {
+  /** The account is pinned to the sysvar id, or only read through helpers that check it */
+  checked: boolean;
+  /** First raw read of its data, or call of a reader that trusts the account */
+  raw?: number;
+}
+
+function sysvarUse(body: string, path: string): SysvarUse {
+  const refs = accountRefs(body, path);
+  const keyed = refs.some((ref) => checksKey(body, ref));
+  const checkedReads = readerCalls(body, CHECKED_READER, refs);
+  const rawReads = [
+    ...readerCalls(body, UNCHECKED_READER, refs),
+    ...refs.flatMap((ref) =>
+      [...body.matchAll(new RegExp(`(?<![\\w.])${escapeRegExp(ref)}\\s*(?:\\.\\s*to_account_info\\s*\\(\\s*\\))?\\s*\\.\\s*(?:data\\b|try_borrow_data\\s*\\()`, "g"))].map(
+        (match) => match.index ?? 0
+      )
+    )
+  ].sort((a, b) => a - b);
+  return { checked: keyed || (checkedReads.length > 0 && rawReads.length === 0), raw: rawReads[0] };
+}
+
+/** Whether an account is used as a sysvar: by name, or because a sysvar reader is given it. */
+function looksLikeSysvar(body: string, name: string, path: string): boolean {
+  if (SYSVAR_NAME.test(name)) return true;
+  const refs = accountRefs(body, path);
+  return readerCalls(body, CHECKED_READER, refs).length > 0 || readerCalls(body, UNCHECKED_READER, refs).length > 0;
+}
+
+function sysvarFinding(
+  scannerId: string,
+  instruction: AnchorInstruction,
+  account: string,
+  declared: { file: string; line: number; text: string },
+  raw: number | undefined,
+  content: string
+): Finding {
+  const trace: FindingLocation[] = [{ file: declared.file, line: declared.line, label: `\`${account}\` taken as a raw account` }];
+  if (raw !== undefined) {
+    trace.push({ file: instruction.file, line: bodyLine(content, instruction.fn, raw), label: `\`${account}\` read as a sysvar` });
+  }
+  return makeFinding({
+    scannerId,
+    vulnClass: "sysvar_spoofing",
+    severity: raw !== undefined ? "HIGH" : "MEDIUM",
+    confidence: raw !== undefined ? 70 : 55,
+    file: declared.file,
+    line: declared.line,
+    title: "Sysvar account not pinned to the sysvar address",
+    description:
+      `\`${instruction.name}\` takes \`${account}\` as a raw account with no \`address = sysvar::...::ID\` constraint or key ` +
+      (raw !== undefined
+        ? "check, then reads it directly. A caller can pass an account holding fabricated sysvar data, e.g. a fake " +
+          "instructions sysvar that hides or invents the surrounding instructions of a transaction."
+        : "check. Whatever consumes it later cannot tell a real sysvar from an account holding fabricated data."),
+    evidence: declared.text,
+    programModule: instruction.programModule,
+    instruction: instruction.name,
+    accountField: account,
+    trace
+  });
+}
+
+function scanSysvars(
+  scannerId: string,
+  instructions: AnchorInstruction[],
+  allStructs: AnchorAccountsStruct[],
+  contents: Map<string, string>,
+  graph: RustCrateGraph
+): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const instruction of instructions) {
+    const body = instruction.fn.body;
+    const content = contents.get(instruction.file) ?? "";
+
+    if (!instruction.accountsStruct) {
+      // Native processors: only a raw read shows a binding is meant as a sysvar and trusted as one.
+      for (const name of nativeAccountNames(body, instruction.contextParam)) {
+        if (!looksLikeSysvar(body, name, name)) continue;
+        const use = sysvarUse(body, name);
+        if (use.checked || use.raw === undefined) continue;
+        const binding = body.search(new RegExp(`\\blet\\s+(?:mut\\s+)?${escapeRegExp(name)}\\b|\\blet\\s+\\[[^\\]]*\\b${escapeRegExp(name)}\\b`));
+        const line = bodyLine(content, instruction.fn, Math.max(binding, 0));
+        findings.push(
+          sysvarFinding(scannerId, instruction, name, { file: instruction.file, line, text: statementText(body, Math.max(binding, 0)) }, use.raw, content)
+        );
+      }
+      continue;
+    }
+
+    const struct = accountsStructFor(instruction, allStructs, graph);
+    if (!struct) continue;
+    for (const field of struct.fields) {
+      if (!isUncheckedField(field) || hasConstraint(field, "address")) continue;
+      const path = `${instruction.contextParam}.accounts.${field.name}`;
+      if (!looksLikeSysvar(body, field.name, path)) continue;
+      const use = sysvarUse(body, path);
+      if (use.checked) continue;
+      findings.push(
+        sysvarFinding(
+          scannerId,
+          instruction,
+          field.name,
+          { file: struct.file, line: field.line, text: `${struct.name}.${field.name}: ${field.rawType}` },
+          use.raw,
+          content
+        )
+      );
+    }
+  }
+
+  return findings;
+}
+
+export const solanaAccountSpoofingScanner: Scanner = {
+  id: "scanner.solana.account-spoofing",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+    const graph = await loadCrateGraph(rootPath, files);
+    const instructions = await collectProgramInstructions(files, graph);
+    const contents = new Map<string, string>();
+    for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+    const structsByFile = new Map(
+      files.map((file) => [file, extractAnchorAccounts(contents.get(file) ?? "", file)] as const)
+    );
+    const allStructs = [...structsByFile.values()].flat();
+    const findings: Finding[] = [];
+
+    for (const file of files) {
+      const content = contents.get(file) ?? "";
+      const fileFindings: Finding[] = [];
+
+      // Marker-based detection (golden repos / eval compatibility)
+      fileFindings.push(...markerFindings(this.id, file, content, MARKER_CLASSES, 85));
+
+      // Model-based detection: entries of remaining_accounts in every function that reads them
+      if (content.includes("remaining_accounts")) {
+        fileFindings.push(...scanRemainingAccounts(this.id, file, content, extractFunctions(content)));
+      }
+
+      findings.push(...scopeFindingsToInstructions(fileFindings, structsByFile.get(file) ?? [], instructions, graph));
+    }
+
+    // Sysvar accounts are judged per instruction, since the handler decides how the account is read.
+    findings.push(...attributeToCrates(scanSysvars(this.id, instructions, allStructs, contents, graph), graph));
+
+    return findings;
+  }
+};
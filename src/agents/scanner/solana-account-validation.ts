@@ -1,118 +1,346 @@
 import { promises as fs } from "node:fs";
-import type { Finding } from "../../types";
+import type { Finding, VulnClass } from "../../types";
 import {
-  findLineContaining,
-  listFilesRecursive,
-  makeFinding,
-  marker,
-  scanFileWithPatterns,
-  type PatternRule,
-  type Scanner
-} from "./base";
-
-const markerRules = [
-  {
-    tag: "missing_signer_check",
-    title: "Missing signer check on authority account",
-    description:
-      "Instruction uses an authority-like account without enforcing signer semantics."
-  },
-  {
-    tag: "missing_has_one",
-    title: "Missing has_one relationship constraint",
-    description:
-      "Account relationship constraints are missing, allowing owner/context substitution."
-  },
-  {
-    tag: "account_type_confusion",
-    title: "Account type confusion risk",
-    description:
-      "Account type validation appears weak and may allow wrong account struct substitution."
+  extractAnchorAccounts,
+  getConstraint,
+  hasConstraint,
+  isBoundTo,
+  isDataField,
+  isSignerField,
+  isUncheckedField,
+  type AnchorAccountField,
+  type AnchorAccountsStruct,
+  type AnchorInstruction
+} from "../../analysis/anchor-model";
+import { checksOwner } from "../../analysis/native-model";
+import { bodyLine } from "../../analysis/rust-items";
+import { escapeRegExp } from "../../analysis/rust-source";
+import { loadCrateGraph, type RustCrateGraph } from "../../analysis/rust-crates";
+import { taintedFlows, traceTaint } from "../../analysis/taint";
+import {
+  accountsStructFor,
+  collectProgramInstructions,
+  instructionsUsingStruct,
+  scopeFindingsToInstructions
+} from "./anchor-scope";
+import { listFilesRecursive, makeFinding, markerFindings, type Scanner } from "./base";
+
+const MARKER_CLASSES: VulnClass[] = [
+  "missing_signer_check",
+  "missing_has_one",
+  "account_type_confusion",
+  "missing_owner_check",
+  "duplicate_mutable_accounts"
+];
+
+const AUTHORITY_FIELD = /^(authority|admin|owner|payer|fee_payer)$/;
+const RELATIONSHIP_AUTHORITY_FIELD = /^(authority|admin|owner)$/;
+const DATA_FIELD =
+  /^(vault|treasury|pool|token_account|stake_account|reward_account|escrow|deposit|user_account|state)$/;
+/** SPL Token accounts are written by the token program, where a transfer to the same account is a no-op. */
+const TOKEN_PROGRAM_TYPE = /^(?:TokenAccount|Mint)$/;
+const DECODE = /\b(\w+)::(try_from_slice(?:_unchecked)?|try_deserialize(?:_unchecked)?|deserialize|unpack(?:_unchecked|_from_slice)?)\s*\(/g;
+
+function fieldEvidence(struct: AnchorAccountsStruct, field: AnchorAccountField): string {
+  return `${struct.name}.${field.name}: ${field.rawType}`;
+}
+
+/** Expressions in `body` that refer to `ctx.accounts.<field>`: the path itself plus `let` aliases of it. */
+function accountAliases(body: string, contextParam: string, field: string): string[] {
+  const path = `${contextParam}.accounts.${field}`;
+  const aliases = [path];
+  const alias = new RegExp(
+    `\\blet\\s+(?:mut\\s+)?(\\w+)\\s*(?::[^=]+)?=\\s*&?\\s*(?:mut\\s+)?${escapeRegExp(path)}(?:\\.to_account_info\\(\\))?\\s*;`,
+    "g"
+  );
+  for (const match of body.matchAll(alias)) aliases.push(match[1]);
+  return aliases;
+}
+
+/**
+ * Handlers that borrow and deserialize the data of an `AccountInfo` / `UncheckedAccount` field
+ * without an `owner =` constraint or a manual `owner` comparison.
+ */
+function scanOwnerChecks(
+  scannerId: string,
+  file: string,
+  content: string,
+  instructions: AnchorInstruction[],
+  allStructs: AnchorAccountsStruct[],
+  graph: RustCrateGraph
+): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const instruction of instructions.filter((candidate) => candidate.file === file && candidate.accountsStruct)) {
+    const struct = accountsStructFor(instruction, allStructs, graph);
+    if (!struct) continue;
+    const body = instruction.fn.body;
+
+    for (const field of struct.fields.filter(isUncheckedField)) {
+      // `address` and `seeds` pin the account to a known key, which implies its owner.
+      if (["owner", "address", "seeds"].some((key) => hasConstraint(field, key))) continue;
+      const custom = getConstraint(field, "constraint");
+      if (custom && /\.owner\b/.test(custom.value ?? "")) continue;
+
+      const accounts = accountAliases(body, instruction.contextParam, field.name);
+      if (accounts.some((account) => checksOwner(body, account))) continue;
+
+      const dataRef = accounts.map((account) => `${escapeRegExp(account)}\\s*\\.\\s*(?:data\\s*\\.\\s*borrow|try_borrow_data)\\b`);
+      const dataLocals = [...body.matchAll(new RegExp(`\\blet\\s+(?:mut\\s+)?(\\w+)\\s*(?::[^=]+)?=\\s*&?\\s*(?:${dataRef.join("|")})`, "g"))]
+        .map((match) => `\\b${match[1]}\\b`);
+      const readsData = new RegExp([...dataRef, ...dataLocals].join("|"));
+
+      for (const decode of body.matchAll(DECODE)) {
+        const offset = decode.index ?? 0;
+        const end = body.indexOf(";", offset);
+        const call = body.slice(offset, end < 0 ? body.length : end);
+        if (!readsData.test(call)) continue;
+
+        const line = bodyLine(content, instruction.fn, offset);
+        // Where the forged data ends up: a PDA seed, CPI program, signer seed, amount or realloc size.
+        const [flow] = taintedFlows(
+          traceTaint(content, file, instruction.fn, {
+            args: [],
+            uncheckedAccounts: [field.name],
+            contextParam: instruction.contextParam
+          })
+        );
+        findings.push(
+          makeFinding({
+            scannerId,
+            vulnClass: "missing_owner_check",
+            severity: "HIGH",
+            confidence: 70,
+            file,
+            line,
+            title: "Unchecked account deserialized without an owner check",
+            description:
+              `\`${instruction.name}\` deserializes \`${field.name}\` (${field.kind}) as \`${decode[1]}\` but neither an ` +
+              "`owner =` constraint nor a manual `owner` comparison restricts which program owns it. An attacker can pass " +
+              "an account they created with forged data in the expected layout.",
+            evidence: `${struct.name}.${field.name}: ${field.rawType}; ${decode[1]}::${decode[2]}(..) in ${instruction.name}()`,
+            programModule: instruction.programModule,
+            instruction: instruction.name,
+            accountField: field.name,
+            trace: [
+              { file: struct.file, line: field.line, label: `${struct.name}.${field.name} declared as ${field.kind}` },
+              { file, line, label: `deserialized as ${decode[1]}` },
+              ...(flow ? flow.path.slice(1) : [])
+            ]
+          })
+        );
+        break;
+      }
+    }
+  }
+
+  return findings;
+}
+
+function scanAccountsModel(scannerId: string, file: string, structs: AnchorAccountsStruct[]): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const struct of structs) {
+    const dataFields = struct.fields.filter(isDataField);
+    // A signer only authorizes something if an existing data account is tied back to it.
+    const bindsExistingData = dataFields.some(
+      (data) => !hasConstraint(data, "init") && !hasConstraint(data, "init_if_needed")
+    );
+
+    for (const field of struct.fields) {
+      if (AUTHORITY_FIELD.test(field.name) && isUncheckedField(field) && !isSignerField(field)) {
+        const unchecked = field.kind === "UncheckedAccount";
+        findings.push(
+          makeFinding({
+            scannerId,
+            vulnClass: "missing_signer_check",
+            severity: "HIGH",
+            confidence: unchecked ? 68 : 72,
+            file,
+            line: field.line,
+            title: unchecked
+              ? "Potential missing signer check on admin account"
+              : "Potential missing signer check on authority",
+            description: unchecked
+              ? "Account field with admin/authority naming pattern uses UncheckedAccount, bypassing signer verification."
+              : "Authority-like account uses raw AccountInfo instead of Signer<'info>, which does not enforce signature verification.",
+            evidence: fieldEvidence(struct, field),
+            accountField: field.name
+          })
+        );
+      }
+
+      if (DATA_FIELD.test(field.name) && isUncheckedField(field) && !hasConstraint(field, "address")) {
+        findings.push(
+          makeFinding({
+            scannerId,
+            vulnClass: "account_type_confusion",
+            severity: "MEDIUM",
+            confidence: 65,
+            file,
+            line: field.line,
+            title: "Data account uses raw AccountInfo instead of typed Account",
+            description:
+              "Data-holding account field uses AccountInfo instead of Account<'info, T>, bypassing account discriminator and deserialization checks.",
+            evidence: fieldEvidence(struct, field),
+            accountField: field.name
+          })
+        );
+      }
+
+      if (
+        RELATIONSHIP_AUTHORITY_FIELD.test(field.name) &&
+        field.kind === "Signer" &&
+        bindsExistingData &&
+        !isBoundTo(struct, field.name)
+      ) {
+        findings.push(
+          makeFinding({
+            scannerId,
+            vulnClass: "missing_has_one",
+            severity: "MEDIUM",
+            confidence: 60,
+            file,
+            line: field.line,
+            title: "Potential missing has_one relationship constraint",
+            description:
+              "Accounts struct contains authority field but no has_one constraint linking it to a data account, allowing owner substitution.",
+            evidence: `${fieldEvidence(struct, field)}; data accounts: ${dataFields.map((data) => data.name).join(", ")}`,
+            accountField: field.name
+          })
+        );
+      }
+    }
   }
-] as const;
-
-const patternRules: PatternRule[] = [
-  {
-    vulnClass: "missing_signer_check",
-    severity: "HIGH",
-    confidence: 72,
-    title: "Potential missing signer check on authority",
-    description:
-      "Authority-like account uses raw AccountInfo instead of Signer<'info>, which does not enforce signature verification.",
-    pattern: /pub\s+(authority|admin|owner|payer|fee_payer)\s*:\s*(?:Unchecked)?AccountInfo/,
-    mitigations: [
-      /Signer<'info>/,
-      /#\[account\([^\]]*signer/
-    ],
-    contextLines: 4
-  },
-  {
-    vulnClass: "missing_signer_check",
-    severity: "HIGH",
-    confidence: 68,
-    title: "Potential missing signer check on admin account",
-    description:
-      "Account field with admin/authority naming pattern uses UncheckedAccount, bypassing signer verification.",
-    pattern: /pub\s+(authority|admin|owner)\s*:\s*UncheckedAccount/,
-    mitigations: [/Signer<'info>/],
-    contextLines: 3
-  },
-  {
-    vulnClass: "account_type_confusion",
-    severity: "MEDIUM",
-    confidence: 65,
-    title: "Data account uses raw AccountInfo instead of typed Account",
-    description:
-      "Data-holding account field uses AccountInfo instead of Account<'info, T>, bypassing account discriminator and deserialization checks.",
-    pattern:
-      /pub\s+(vault|treasury|pool|token_account|stake_account|reward_account|escrow|deposit|user_account|state)\s*:\s*AccountInfo/,
-    mitigations: [/Account<'info,/, /CpiAccount<'info,/],
-    contextLines: 3
-  },
-  {
-    vulnClass: "missing_has_one",
-    severity: "MEDIUM",
-    confidence: 60,
-    title: "Potential missing has_one relationship constraint",
-    description:
-      "Accounts struct contains authority field but no has_one constraint linking it to a data account, allowing owner substitution.",
-    pattern: /pub\s+authority\s*:\s*Signer<'info>/,
-    mitigations: [/has_one\s*=\s*authority/],
-    contextLines: 20
+
+  return findings;
+}
+
+/** Whether two fields are pinned to different addresses: fixed addresses, or seeds with different literals. */
+function distinctAddresses(a: AnchorAccountField, b: AnchorAccountField): boolean {
+  if (hasConstraint(a, "address") || hasConstraint(b, "address")) return true;
+  const [seedsA, seedsB] = [a, b].map((field) => getConstraint(field, "seeds")?.value);
+  if (!seedsA || !seedsB) return false;
+  const literals = (seeds: string): string => [...seeds.matchAll(/b"[^"]*"/g)].map((match) => match[0]).sort().join(",");
+  return literals(seedsA) !== literals(seedsB);
+}
+
+/** `a.key()`, `ctx.accounts.a.key()` or `*a.key`: the address of account `name`. */
+function keyExpr(name: string): string {
+  return `[&*]?\\s*(?<![\\w.])(?:\\w+\\s*\\.\\s*)*${escapeRegExp(name)}\\s*\\.\\s*key\\b(?:\\s*\\(\\s*\\))?`;
+}
+
+/**
+ * A key inequality between `a` and `b` on either field or in a handler: `a.key() != b.key()` (in a
+ * `constraint` or `require!`), `require_keys_neq!(a.key(), b.key())` or `assert_ne!(a.key(), b.key())`.
+ * Equality checks and comparisons of other fields do not keep the accounts apart.
+ */
+function keysCompared(a: string, b: string, constraints: string[], bodies: string[]): boolean {
+  const [keyA, keyB] = [keyExpr(a), keyExpr(b)];
+  const inequality = new RegExp(
+    `${keyA}\\s*!=\\s*${keyB}|${keyB}\\s*!=\\s*${keyA}|` +
+      `\\b(?:require_keys_neq|assert_keys_neq|assert_ne)!\\s*\\(\\s*(?:${keyA}\\s*,\\s*${keyB}|${keyB}\\s*,\\s*${keyA})\\s*[,)]`
+  );
+  return [...constraints, ...bodies].some((text) => inequality.test(text));
+}
+
+/**
+ * Accounts structs with two or more `mut` program-owned accounts of the same type that nothing keeps
+ * apart. Anchor deserializes each field separately, so passing one account twice lets the later write win.
+ */
+function scanDuplicateMutables(
+  scannerId: string,
+  file: string,
+  structs: AnchorAccountsStruct[],
+  instructions: AnchorInstruction[],
+  graph: RustCrateGraph
+): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const struct of structs) {
+    const groups = new Map<string, AnchorAccountField[]>();
+    for (const field of struct.fields) {
+      if (!isDataField(field) || !hasConstraint(field, "mut") || TOKEN_PROGRAM_TYPE.test(field.innerType ?? "")) continue;
+      if (hasConstraint(field, "init") || hasConstraint(field, "init_if_needed")) continue;
+      const key = `${field.kind}<${field.innerType ?? field.rawType}>`;
+      groups.set(key, [...(groups.get(key) ?? []), field]);
+    }
+
+    const handlers = instructionsUsingStruct(instructions, struct, graph);
+    const bodies = handlers.map((handler) => handler.fn.body);
+    const constraints = struct.fields.flatMap((field) =>
+      field.constraints.filter((constraint) => constraint.key === "constraint").map((constraint) => constraint.value ?? "")
+    );
+
+    for (const fields of groups.values()) {
+      const pairs = fields.flatMap((a, index) => fields.slice(index + 1).map((b) => [a, b] as const));
+      const open = pairs.filter(
+        ([a, b]) => !distinctAddresses(a, b) && !keysCompared(a.name, b.name, constraints, bodies)
+      );
+      if (open.length === 0) continue;
+
+      const [first, second] = open[0];
+      const names = fields.filter((field) => open.some((pair) => pair.includes(field))).map((field) => field.name);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "duplicate_mutable_accounts",
+          severity: "HIGH",
+          confidence: 64,
+          file,
+          line: second.line,
+          title: "Mutable accounts of the same type can be the same account",
+          description:
+            `\`${struct.name}\` takes ${names.map((name) => `\`${name}\``).join(", ")} as mutable \`${first.rawType}\` ` +
+            `accounts, but no \`constraint = ${first.name}.key() != ${second.name}.key()\` or \`require_keys_neq!\` keeps ` +
+            "them apart. A caller can pass one account for both, so balances credited to one are overwritten by the other.",
+          evidence: `${struct.name}: ${names.join(", ")}: ${first.rawType} #[account(mut)]` +
+            (handlers.length > 0 ? `; handlers: ${handlers.map((handler) => handler.name).join(", ")}` : ""),
+          accountField: second.name,
+          trace: [
+            { file, line: first.line, label: `${struct.name}.${first.name} declared mut` },
+            { file, line: second.line, label: `${struct.name}.${second.name} declared mut with the same type` }
+          ]
+        })
+      );
+    }
   }
-];
+
+  return findings;
+}
 
 export const solanaAccountValidationScanner: Scanner = {
   id: "scanner.solana.account-validation",
   async scan(rootPath: string): Promise<Finding[]> {
     const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+    const graph = await loadCrateGraph(rootPath, files);
+    const instructions = await collectProgramInstructions(files, graph);
+    const contents = new Map<string, string>();
+    for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+    const structsByFile = new Map(
+      files.map((file) => [file, extractAnchorAccounts(contents.get(file) ?? "", file)] as const)
+    );
+    const allStructs = [...structsByFile.values()].flat();
     const findings: Finding[] = [];
 
     for (const file of files) {
-      const content = await fs.readFile(file, "utf8");
+      const content = contents.get(file) ?? "";
+      const structs = structsByFile.get(file) ?? [];
+      const fileFindings: Finding[] = [];
 
       // Marker-based detection (golden repos / eval compatibility)
-      for (const rule of markerRules) {
-        const token = marker(rule.tag);
-        if (!content.includes(token)) continue;
+      fileFindings.push(...markerFindings(this.id, file, content, MARKER_CLASSES, 88));
 
-        findings.push(
-          makeFinding({
-            scannerId: this.id,
-            vulnClass: rule.tag,
-            severity: "HIGH",
-            confidence: 88,
-            file,
-            line: findLineContaining(content, token),
-            title: rule.title,
-            description: rule.description,
-            evidence: `Found marker ${token}`
-          })
-        );
-      }
+      // Model-based detection over #[derive(Accounts)] structs
+      fileFindings.push(...scanAccountsModel(this.id, file, structs));
+
+      // Handler-level detection: raw account data deserialized without an owner check
+      fileFindings.push(...scanOwnerChecks(this.id, file, content, instructions, allStructs, graph));
+
+      // Struct-level detection: same-type mutable accounts the caller can alias
+      fileFindings.push(...scanDuplicateMutables(this.id, file, structs, instructions, graph));
 
-      // Pattern-based detection (real code analysis)
-      findings.push(...scanFileWithPatterns(this.id, file, content, patternRules));
+      findings.push(...scopeFindingsToInstructions(fileFindings, structs, instructions, graph));
     }
 
     return findings;
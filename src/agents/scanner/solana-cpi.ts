@@ -1,34 +1,30 @@
 import { promises as fs } from "node:fs";
-import type { Finding } from "../../types";
+import type { Finding, VulnClass } from "../../types";
+import {
+  extractAnchorAccounts,
+  findAccountField,
+  hasConstraint,
+  instructionAt,
+  isUncheckedField,
+  type AnchorAccountsStruct,
+  type AnchorInstruction
+} from "../../analysis/anchor-model";
+import { extractCpiFlow, type CpiFlowEvent } from "../../analysis/cpi-flow";
+import { extractFunctions } from "../../analysis/rust-items";
+import { importedSource, loadCrateGraph, visibleFiles, type RustCrateGraph } from "../../analysis/rust-crates";
+import { maskRust } from "../../analysis/rust-lexer";
+import { formatTaintPath, KEY_SEED, traceTaint, type TaintFlow } from "../../analysis/taint";
+import { accountsStructFor, collectProgramInstructions, scopeFindingsToInstructions, taintOptionsFor } from "./anchor-scope";
 import {
-  findLineContaining,
   listFilesRecursive,
   makeFinding,
-  marker,
+  markerFindings,
   scanFileWithPatterns,
   type PatternRule,
   type Scanner
 } from "./base";
 
-const markerRules = [
-  {
-    tag: "arbitrary_cpi",
-    title: "Arbitrary CPI target risk",
-    description:
-      "Program appears to allow user-controlled CPI target, which may permit malicious invocation."
-  },
-  {
-    tag: "cpi_signer_seed_bypass",
-    title: "Signer seed validation weakness in CPI",
-    description:
-      "CPI signer seed handling appears weak and may permit authority bypass."
-  },
-  {
-    tag: "cpi_reentrancy",
-    title: "Potential CPI reentrancy path",
-    description: "Instruction flow appears vulnerable to callback-based reentrancy."
-  }
-] as const;
+const MARKER_CLASSES: VulnClass[] = ["arbitrary_cpi", "cpi_signer_seed_bypass", "cpi_reentrancy"];
 
 const patternRules: PatternRule[] = [
   {
@@ -49,31 +45,6 @@ const patternRules: PatternRule[] = [
     ],
     contextLines: 8
   },
-  {
-    vulnClass: "arbitrary_cpi",
-    severity: "CRITICAL",
-    confidence: 72,
-    title: "User-supplied program account in CPI context",
-    description:
-      "CpiContext uses a program account that appears to be user-supplied AccountInfo, allowing arbitrary program invocation.",
-    pattern: /CpiContext::new\s*\(\s*ctx\.accounts\.\w+_program\.to_account_info\(\)/,
-    mitigations: [
-      /Program<'info,\s*System>/,
-      /Program<'info,\s*Token>/
-    ],
-    contextLines: 10
-  },
-  {
-    vulnClass: "cpi_reentrancy",
-    severity: "HIGH",
-    confidence: 65,
-    title: "State modification before CPI call",
-    description:
-      "Account data appears to be modified before an invoke() call in the same function. If the CPI target calls back, it may observe stale state.",
-    pattern: /\btry_borrow_mut_data\s*\(/,
-    mitigations: [],
-    contextLines: 0 // Checked manually below
-  },
   {
     vulnClass: "cpi_signer_seed_bypass",
     severity: "HIGH",
@@ -86,69 +57,255 @@ const patternRules: PatternRule[] = [
       /find_program_address\s*\(/,
       /Pubkey::create_program_address\s*\(/
     ],
-    contextLines: 10
+    contextLines: 10,
+    // Seed derivation helpers commonly live in a state or utils module.
+    crossFileMitigations: true
   }
 ];
 
+const CPI_CONTEXT_PROGRAM = /CpiContext::new(?:_with_signer)?\s*\(\s*ctx\.accounts\.(\w+)\.to_account_info\(\)/g;
+
+/**
+ * Accounts structs that `ctx.accounts` can mean at `line` of `file`: the `Context<T>` struct of the
+ * handler containing it, or every visible struct when no handler or struct resolves (helpers, native code).
+ */
+function structsInScope(
+  file: string,
+  line: number,
+  instructions: AnchorInstruction[],
+  allStructs: AnchorAccountsStruct[],
+  visibleStructs: AnchorAccountsStruct[],
+  graph: RustCrateGraph
+): AnchorAccountsStruct[] {
+  const instruction = instructionAt(
+    instructions.filter((candidate) => candidate.file === file),
+    line
+  );
+  const struct = instruction ? accountsStructFor(instruction, allStructs, graph) : undefined;
+  return struct ? [struct] : visibleStructs;
+}
+
+function scanCpiContextPrograms(
+  scannerId: string,
+  file: string,
+  content: string,
+  code: string,
+  structsAt: (line: number) => AnchorAccountsStruct[]
+): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const match of code.matchAll(CPI_CONTEXT_PROGRAM)) {
+    const line = content.slice(0, match.index).split(/\r?\n/).length;
+    const resolved = findAccountField(structsAt(line), match[1]);
+    if (!resolved) continue;
+    const { struct, field } = resolved;
+    // Program<'info, T>/Interface<'info, T> and address constraints pin the program ID.
+    if (!isUncheckedField(field) || hasConstraint(field, "address")) continue;
+
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "arbitrary_cpi",
+        severity: "CRITICAL",
+        confidence: 72,
+        file,
+        line,
+        title: "User-supplied program account in CPI context",
+        description:
+          "CpiContext uses a program account that is a user-supplied AccountInfo, allowing arbitrary program invocation.",
+        evidence: `${struct.name}.${field.name}: ${field.rawType}`,
+        accountField: field.name
+      })
+    );
+  }
+
+  return findings;
+}
+
+function mutationLabel(event: CpiFlowEvent): string {
+  return event.kind === "borrow_mut"
+    ? `mutable borrow of \`${event.account}\` (${event.text})`
+    : `write to \`${event.text}\``;
+}
+
+function scanReentrancy(
+  scannerId: string,
+  file: string,
+  content: string,
+  code: string,
+  structsAt: (line: number) => AnchorAccountsStruct[]
+): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const fn of extractFunctions(content)) {
+    const events = extractCpiFlow(content, code, fn, structsAt(fn.line));
+    const reported = new Set<string>();
+
+    for (const [index, mutation] of events.entries()) {
+      if (mutation.kind !== "borrow_mut" && mutation.kind !== "write") continue;
+      const account = mutation.account!;
+      if (reported.has(account)) continue;
+
+      const later = events.slice(index + 1);
+      const cpi = later.find((event) => event.kind === "cpi" && !event.controlled);
+      if (!cpi) continue;
+      const reloaded = later.some(
+        (event) => event.kind === "reload" && event.account === account && event.offset > cpi.offset
+      );
+      if (reloaded) continue;
+
+      reported.add(account);
+      const passedToCpi = new RegExp(`\\b${account}\\b`).test(cpi.callText ?? "");
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "cpi_reentrancy",
+          severity: "HIGH",
+          confidence: passedToCpi ? 70 : 62,
+          file,
+          line: cpi.line,
+          title: "Account state mutated before CPI to an uncontrolled program",
+          description:
+            `\`${account}\` is mutated in \`${fn.name}\` before ${cpi.text} targets a program that is not fixed, ` +
+            "and the account is not reloaded afterwards. A re-entrant callee can observe or overwrite inconsistent state.",
+          evidence: `${mutationLabel(mutation)} at line ${mutation.line}, ${cpi.text} at line ${cpi.line}, no ${account}.reload()`,
+          accountField: account,
+          trace: [
+            { file, line: mutation.line, label: mutationLabel(mutation) },
+            { file, line: cpi.line, label: `${cpi.text} to uncontrolled program${cpi.account ? ` \`${cpi.account}\`` : ""}` }
+          ]
+        })
+      );
+    }
+  }
+
+  return findings;
+}
+
+function sourceLabel(flow: TaintFlow): string {
+  return flow.source.kind === "account_data"
+    ? `data of unchecked account \`${flow.source.name}\``
+    : `argument \`${flow.source.name}\``;
+}
+
+/**
+ * Taint-based CPI checks: caller-chosen program ids and variable-length signer seeds, each with its
+ * source-to-sink path. Also returns the `class:line` keys the pass settled, reported or cleared: program
+ * ids pinned to a constant and signer seeds it could follow, which the line-level patterns then skip.
+ */
+function scanCpiTaint(
+  scannerId: string,
+  file: string,
+  content: string,
+  instructions: AnchorInstruction[],
+  allStructs: AnchorAccountsStruct[],
+  graph: RustCrateGraph
+): { findings: Finding[]; settled: Set<string> } {
+  const findings: Finding[] = [];
+  const settled = new Set<string>();
+
+  for (const instruction of instructions.filter((candidate) => candidate.file === file)) {
+    const options = taintOptionsFor(instruction, accountsStructFor(instruction, allStructs, graph));
+    for (const site of traceTaint(content, file, instruction.fn, options)) {
+      if (site.sink === "cpi_program" && site.pinned) settled.add(`arbitrary_cpi:${site.line}`);
+      if (site.sink === "signer_seed") settled.add(`cpi_signer_seed_bypass:${site.line}`);
+      const flow = site.flow;
+      if (!flow) continue;
+      const common = {
+        scannerId,
+        file,
+        line: flow.line,
+        evidence: formatTaintPath(flow),
+        programModule: instruction.programModule,
+        instruction: instruction.name,
+        trace: flow.path
+      };
+
+      if (flow.sink === "cpi_program") {
+        findings.push(
+          makeFinding({
+            ...common,
+            vulnClass: "arbitrary_cpi",
+            severity: "CRITICAL",
+            confidence: 80,
+            title: "Caller-chosen program id reaches a CPI",
+            description:
+              `The ${sourceLabel(flow)} of \`${instruction.name}\` becomes the program id of ${flow.text} without being ` +
+              "compared to a known program. An attacker can point the CPI at their own program, which receives the " +
+              "forwarded accounts and any PDA signatures."
+          })
+        );
+      } else if (
+        flow.sink === "signer_seed" &&
+        flow.source.variableLength &&
+        (flow.source.kind === "account_data" || !KEY_SEED.test(flow.expr))
+      ) {
+        findings.push(
+          makeFinding({
+            ...common,
+            vulnClass: "cpi_signer_seed_bypass",
+            severity: "HIGH",
+            confidence: 74,
+            title: "Caller-chosen bytes flow into CPI signer seeds",
+            description:
+              `The ${sourceLabel(flow)} of \`${instruction.name}\` reaches the signer seeds of ${flow.text}, and no ` +
+              "account key among the seeds ties them to the caller. With variable-length seed bytes the caller can make " +
+              "the program sign as a PDA that belongs to another user or account kind."
+          })
+        );
+      }
+    }
+  }
+
+  for (const finding of findings) settled.add(`${finding.vuln_class}:${finding.line}`);
+  return { findings, settled };
+}
+
 export const solanaCpiScanner: Scanner = {
   id: "scanner.solana.cpi",
   async scan(rootPath: string): Promise<Finding[]> {
     const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+    const graph = await loadCrateGraph(rootPath, files);
+    const instructions = await collectProgramInstructions(files, graph);
+    const contents = new Map<string, string>();
+    for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+    const structsByFile = new Map(
+      files.map((file) => [file, extractAnchorAccounts(contents.get(file) ?? "", file)] as const)
+    );
+    const allStructs = [...structsByFile.values()].flat();
     const findings: Finding[] = [];
 
     for (const file of files) {
-      const content = await fs.readFile(file, "utf8");
+      const content = contents.get(file) ?? "";
+      const structs = structsByFile.get(file) ?? [];
+      // Handlers in lib.rs commonly use Accounts structs declared in the instruction modules they import.
+      const visibleStructs = visibleFiles(graph, file).flatMap((other) => structsByFile.get(other) ?? []);
+      const structsAt = (line: number): AnchorAccountsStruct[] =>
+        structsInScope(file, line, instructions, allStructs, visibleStructs, graph);
+      const code = maskRust(content, ["code"]);
+      const fileFindings: Finding[] = [];
 
       // Marker-based detection (golden repos / eval compatibility)
-      for (const rule of markerRules) {
-        const token = marker(rule.tag);
-        if (!content.includes(token)) continue;
+      fileFindings.push(...markerFindings(this.id, file, content, MARKER_CLASSES, 90));
 
-        findings.push(
-          makeFinding({
-            scannerId: this.id,
-            vulnClass: rule.tag,
-            severity: "CRITICAL",
-            confidence: 90,
-            file,
-            line: findLineContaining(content, token),
-            title: rule.title,
-            description: rule.description,
-            evidence: `Found marker ${token}`
-          })
-        );
-      }
+      // Taint-based detection: caller-chosen program ids and signer seeds, with their source-to-sink paths
+      const taint = scanCpiTaint(this.id, file, content, instructions, allStructs, graph);
+      fileFindings.push(...taint.findings);
 
-      // Pattern-based detection (real code analysis)
-      findings.push(...scanFileWithPatterns(this.id, file, content, patternRules));
-
-      // File-level reentrancy check: borrow_mut_data before invoke in same file
-      if (content.includes("try_borrow_mut_data") && /\binvoke(?:_signed)?\s*\(/.test(content)) {
-        const lines = content.split(/\r?\n/);
-        let mutLine = -1;
-        let invokeLine = -1;
-        for (let i = 0; i < lines.length; i++) {
-          if (mutLine < 0 && /try_borrow_mut_data/.test(lines[i])) mutLine = i;
-          if (/\binvoke(?:_signed)?\s*\(/.test(lines[i])) invokeLine = i;
-        }
-        if (mutLine >= 0 && invokeLine > mutLine) {
-          findings.push(
-            makeFinding({
-              scannerId: this.id,
-              vulnClass: "cpi_reentrancy",
-              severity: "HIGH",
-              confidence: 62,
-              file,
-              line: invokeLine + 1,
-              title: "State mutation before CPI may enable reentrancy",
-              description:
-                "Account data is mutated via try_borrow_mut_data() before an invoke() call. " +
-                "If the CPI target calls back into this program, it may observe inconsistent state.",
-              evidence: `borrow_mut_data at line ${mutLine + 1}, invoke at line ${invokeLine + 1}`
-            })
-          );
-        }
-      }
+      // Pattern-based detection (real code analysis), skipped where the taint pass already settled the same call
+      fileFindings.push(
+        ...scanFileWithPatterns(this.id, file, content, patternRules, importedSource(graph, file)).filter(
+          (finding) => !taint.settled.has(`${finding.vuln_class}:${finding.line}`)
+        )
+      );
+
+      // Model-based detection: resolve CPI program accounts against #[derive(Accounts)] fields
+      fileFindings.push(...scanCpiContextPrograms(this.id, file, content, code, structsAt));
+
+      // Function-scoped reentrancy: account mutated before an uncontrolled CPI and never reloaded
+      fileFindings.push(...scanReentrancy(this.id, file, content, code, structsAt));
+
+      findings.push(...scopeFindingsToInstructions(fileFindings, structs, instructions, graph));
     }
 
     return findings;
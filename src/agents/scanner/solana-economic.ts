@@ -0,0 +1,300 @@
+import { promises as fs } from "node:fs";
+import type { Finding, FindingLocation, VulnClass } from "../../types";
+import {
+  extractAnchorAccounts,
+  findAccountField,
+  type AnchorAccountsStruct,
+  type AnchorInstruction
+} from "../../analysis/anchor-model";
+import { lexRust, maskRust } from "../../analysis/rust-lexer";
+import { hasZeroGuard, scanFunctionBodies, SHARE_PRICE_NAME, snippet } from "../../analysis/program-math";
+import { bodyLine, type RustFunction } from "../../analysis/rust-items";
+import { loadCrateGraph } from "../../analysis/rust-crates";
+import { collectProgramInstructions, scopeFindingsToInstructions } from "./anchor-scope";
+import { listFilesRecursive, makeFinding, markerFindings, type Scanner } from "./base";
+
+const MARKER_CLASSES: VulnClass[] = [
+  "unchecked_oracle_price",
+  "missing_slippage_check",
+  "spot_price_manipulation",
+  "fee_rounding"
+];
+
+/** Files that talk to an on-chain price oracle; other `get_result()`/`get_price()` calls are ignored. */
+const ORACLE_FILE = /\bpyth|\bswitchboard|\bPriceFeed\b|\bPriceUpdateV2\b|\bAggregatorAccountData\b/i;
+const ORACLE_READ =
+  /\b(get_price_unchecked|get_ema_price_unchecked|get_current_price|get_ema_price|get_price_no_older_than|get_ema_price_no_older_than|get_result|get_latest_price)\s*\(/g;
+const STALENESS_GUARD =
+  /no_older_than|check_staleness|publish_time|\bmax_age\b|stale|round_open_(?:slot|timestamp)|last_update(?:_slot|d_slot)?\b|valid_slot/i;
+const CONFIDENCE_GUARD = /\.conf\b|confidence|std_deviation|conf_interval/i;
+
+const SWAP_HANDLER = /(?:^|_)(swap|exchange|trade|buy|sell|remove_liquidity|withdraw_liquidity)(?:_|$)/;
+const SLIPPAGE_NAME =
+  /min(?:imum)?_?(?:amount_)?out|min_(?:received|output|return|tokens?|amount_[ab])|slippage|max_(?:amount_)?in\b|(?:min|max|limit)_price|price_limit|sqrt_price_limit/;
+const PRIMITIVE_TYPE = /^&?(?:mut\s+)?(?:u8|u16|u32|u64|u128|usize|i8|i16|i32|i64|i128|isize|bool|Pubkey|String|Vec<.*>)$/;
+
+const SPOT_ACCOUNT_NAME = /vault|pool|reserve|treasury/i;
+/** Time-weighted or virtual accounting means the computation does not read raw spot balances. */
+const SPOT_MITIGATION = /twap|virtual_|\bema\b|cumulative_price/i;
+
+const FEE_NAME = /fee|commission|royalt|\btax\b/i;
+const ROUND_UP =
+  /div_ceil|ceil_div|\bceil\b|round_up|\.max\(\s*1\s*\)|(?:\+|checked_add\s*\()\s*(?:\w*(?:BPS|DENOMINATOR|PRECISION|SCALE)\w*|\d[\d_]*)\s*-\s*1\b|\+\s*9_?999\b/;
+
+interface LetBinding {
+  name: string;
+  expr: string;
+  /** Offset of the `let` keyword */
+  offset: number;
+  /** Offset just past the statement's `;` */
+  end: number;
+}
+
+function letBindings(body: string): LetBinding[] {
+  return [...body.matchAll(/\blet\s+(?:mut\s+)?(\w+)\s*(?::\s*[\w<>]+\s*)?=([^;]*);/g)].map((match) => ({
+    name: match[1],
+    expr: match[2],
+    offset: match.index ?? 0,
+    end: (match.index ?? 0) + match[0].length
+  }));
+}
+
+function hasDivision(expr: string): boolean {
+  return /[^/]\/[^/=]|checked_div\s*\(|saturating_div\s*\(/.test(expr);
+}
+
+function scanOracleReads(scannerId: string, file: string, content: string, fn: RustFunction, body: string): Finding[] {
+  const findings: Finding[] = [];
+  const reads = [...body.matchAll(ORACLE_READ)];
+  if (reads.length === 0) return findings;
+
+  const staleness = STALENESS_GUARD.test(body);
+  const confidence = CONFIDENCE_GUARD.test(body);
+
+  for (const read of reads) {
+    const call = read[1];
+    const freshByApi = /no_older_than/.test(call);
+    const missing = [
+      ...(staleness || freshByApi ? [] : ["staleness"]),
+      ...(confidence ? [] : ["confidence interval"])
+    ];
+    if (missing.length === 0) continue;
+
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "unchecked_oracle_price",
+        severity: missing.length === 2 ? "HIGH" : "MEDIUM",
+        confidence: missing.length === 2 ? 64 : 56,
+        file,
+        line: bodyLine(content, fn, read.index ?? 0),
+        title: "Oracle price used without " + missing.join(" or ") + " check",
+        description:
+          `\`${fn.name}\` reads a price with \`${call}()\` but never checks its ${missing.join(" or ")}. A stale or ` +
+          "wide-confidence price can be used to borrow, liquidate or mint against a value the market no longer supports. " +
+          "Use `get_price_no_older_than` and reject prices whose `conf` is too large relative to `price`.",
+        evidence: snippet(body, read.index ?? 0)
+      })
+    );
+  }
+
+  return findings;
+}
+
+/** Argument names of a handler, expanding struct-typed params (`params: SwapParams`) into their fields. */
+function argumentNames(instruction: AnchorInstruction, sources: string[]): string[] {
+  const names: string[] = [];
+  for (const arg of instruction.args) {
+    names.push(arg.name);
+    const type = arg.type.replace(/\s+/g, " ").trim();
+    if (PRIMITIVE_TYPE.test(type)) continue;
+    const typeName = type.match(/(\w+)\s*(?:<[^>]*>)?$/)?.[1];
+    if (!typeName) continue;
+    for (const source of sources) {
+      const definition = source.match(new RegExp(`\\bstruct\\s+${typeName}\\b[^{;]*\\{([^}]*)\\}`));
+      if (!definition) continue;
+      names.push(...[...definition[1].matchAll(/\b(\w+)\s*:/g)].map((field) => field[1]));
+      break;
+    }
+  }
+  return names;
+}
+
+function scanSlippage(
+  scannerId: string,
+  file: string,
+  instructions: AnchorInstruction[],
+  sources: string[]
+): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const instruction of instructions.filter((candidate) => candidate.file === file)) {
+    // Native processors decode their arguments from raw instruction data, so there is no signature to inspect.
+    if (!instruction.accountsStruct || !SWAP_HANDLER.test(instruction.name)) continue;
+    const args = argumentNames(instruction, sources);
+    if (args.some((name) => SLIPPAGE_NAME.test(name))) continue;
+
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "missing_slippage_check",
+        severity: "HIGH",
+        confidence: 62,
+        file,
+        line: instruction.line,
+        title: "Swap instruction without minimum output",
+        description:
+          `\`${instruction.name}\` takes no minimum-output or price-limit argument, so the caller cannot bound what they ` +
+          "receive. A searcher can sandwich the transaction and capture the difference. Add a `minimum_amount_out` " +
+          "argument and require the computed output to meet it.",
+        evidence: `fn ${instruction.name}(${[instruction.contextParam, ...instruction.args.map((arg) => arg.name)].join(", ")})`,
+        programModule: instruction.programModule,
+        instruction: instruction.name
+      })
+    );
+  }
+
+  return findings;
+}
+
+function isTokenAccountField(structs: AnchorAccountsStruct[], name: string): boolean {
+  const resolved = findAccountField(structs, name);
+  if (!resolved) return SPOT_ACCOUNT_NAME.test(name);
+  return /^(?:[\w:]+::)?TokenAccount$/.test(resolved.field.innerType ?? "");
+}
+
+/** Share/price computations whose inputs come straight from live token-account balances. */
+function scanSpotPrices(
+  scannerId: string,
+  file: string,
+  content: string,
+  fn: RustFunction,
+  body: string,
+  structs: AnchorAccountsStruct[]
+): Finding[] {
+  const findings: Finding[] = [];
+  if (SPOT_MITIGATION.test(body)) return findings;
+
+  const aliases = new Map<string, string>();
+  for (const alias of body.matchAll(/\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=]+)?=\s*&?\s*(?:mut\s+)?ctx\.accounts\.(\w+)\s*;/g)) {
+    aliases.set(alias[1], alias[2]);
+  }
+
+  const spotRead = (expr: string): RegExpMatchArray | undefined => {
+    for (const match of expr.matchAll(/(?:\bctx\.accounts\.(\w+)|\b(\w+))\s*\.\s*(amount\b|lamports\(\))/g)) {
+      const account = match[1] ?? aliases.get(match[2]);
+      if (!account) continue;
+      if (match[3] === "amount" ? isTokenAccountField(structs, account) : SPOT_ACCOUNT_NAME.test(account)) return match;
+    }
+    return undefined;
+  };
+
+  // Locals holding a spot balance, and where that balance was read.
+  const spotLocals = new Map<string, number>();
+
+  for (const binding of letBindings(body)) {
+    const direct = spotRead(binding.expr);
+    const local = [...spotLocals.keys()].find((name) => new RegExp(`\\b${name}\\b`).test(binding.expr));
+    if (!direct && !local) continue;
+    const sourceOffset = direct ? binding.offset : spotLocals.get(local!)!;
+
+    if (SHARE_PRICE_NAME.test(binding.name) && hasDivision(binding.expr)) {
+      const trace: FindingLocation[] = [
+        { file, line: bodyLine(content, fn, sourceOffset), label: "spot balance read" },
+        { file, line: bodyLine(content, fn, binding.offset), label: `${binding.name} computed from it` }
+      ];
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "spot_price_manipulation",
+          severity: "HIGH",
+          confidence: 60,
+          file,
+          line: bodyLine(content, fn, binding.offset),
+          title: "Share/price computed from spot token balances",
+          description:
+            `\`${binding.name}\` is derived from a live token-account balance. Anyone can donate tokens to the account, or ` +
+            "move it with a flash loan in the same transaction, to skew the rate and mint or redeem at a manipulated price. " +
+            "Track reserves in program state or use a time-weighted price.",
+          evidence: snippet(body, binding.offset),
+          trace
+        })
+      );
+      continue;
+    }
+
+    spotLocals.set(binding.name, sourceOffset);
+  }
+
+  return findings;
+}
+
+/** `let fee = amount * FEE_BPS / 10_000;` rounds down, so small or split trades pay less (or nothing). */
+function scanFeeRounding(scannerId: string, file: string, content: string, fn: RustFunction, body: string): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const binding of letBindings(body)) {
+    if (!FEE_NAME.test(binding.name) || !hasDivision(binding.expr)) continue;
+    if (ROUND_UP.test(binding.expr)) continue;
+    if (hasZeroGuard(body.slice(binding.end), binding.name)) continue;
+
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "fee_rounding",
+        severity: "MEDIUM",
+        confidence: 58,
+        file,
+        line: bodyLine(content, fn, binding.offset),
+        title: "Fee truncates toward zero in the user's favour",
+        description:
+          `\`${binding.name}\` is computed with integer division that rounds down. Trades small enough that the fee ` +
+          "rounds to zero pay nothing, and splitting a trade shaves the remainder off every piece. Round fees up " +
+          "(e.g. `div_ceil`) or enforce a minimum fee.",
+        evidence: snippet(body, binding.offset)
+      })
+    );
+  }
+
+  return findings;
+}
+
+export const solanaEconomicScanner: Scanner = {
+  id: "scanner.solana.economic",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+    const graph = await loadCrateGraph(rootPath, files);
+    const instructions = await collectProgramInstructions(files, graph);
+    const contents = new Map<string, string>();
+    for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+    const sources = [...contents.values()];
+    const findings: Finding[] = [];
+
+    for (const file of files) {
+      const content = contents.get(file) ?? "";
+      const structs = extractAnchorAccounts(content, file);
+      const fileFindings: Finding[] = [];
+
+      // Marker-based detection (golden repos / eval compatibility)
+      fileFindings.push(...markerFindings(this.id, file, content, MARKER_CLASSES, 85));
+
+      // Instruction-level check: swap handlers without a caller-supplied output bound
+      fileFindings.push(...scanSlippage(this.id, file, instructions, sources));
+
+      // Function-level analysis over code only; string literals and comments are masked out.
+      const code = maskRust(lexRust(content), ["code"]);
+      const oracleFile = ORACLE_FILE.test(code);
+      fileFindings.push(
+        ...scanFunctionBodies(content, code, (fn, body) => [
+          ...(oracleFile ? scanOracleReads(this.id, file, content, fn, body) : []),
+          ...scanSpotPrices(this.id, file, content, fn, body, structs),
+          ...scanFeeRounding(this.id, file, content, fn, body)
+        ])
+      );
+
+      findings.push(...scopeFindingsToInstructions(fileFindings, structs, instructions, graph));
+    }
+
+    return findings;
+  }
+};
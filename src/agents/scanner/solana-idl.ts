@@ -0,0 +1,177 @@
+import { promises as fs } from "node:fs";
+import type { Finding } from "../../types";
+import { loadAnchorIdls, type AnchorIdl, type IdlAccountItem, type IdlInstruction } from "../../analysis/anchor-idl";
+import {
+  extractAnchorAccounts,
+  getConstraint,
+  hasConstraint,
+  isSignerField,
+  type AnchorAccountField,
+  type AnchorAccountsStruct,
+  type AnchorInstruction
+} from "../../analysis/anchor-model";
+import { crateIdent, loadCrateGraph, type RustCrateGraph } from "../../analysis/rust-crates";
+import { escapeRegExp } from "../../analysis/rust-source";
+import { accountsStructFor, collectProgramInstructions } from "./anchor-scope";
+import { listFilesRecursive, makeFinding, type Scanner } from "./base";
+
+/** Accounts whose role is to authorize; the IDL flags them as signers when the program requires a signature. */
+const AUTHORITY_NAME = /(?:^|_)(?:authority|admin|owner|operator|manager|governor)(?:_|$)/;
+
+interface SourceBinding {
+  instruction: AnchorInstruction;
+  struct?: AnchorAccountsStruct;
+}
+
+/** The source handler an IDL instruction describes; a stale IDL entry with no handler is skipped. */
+function bindToSource(
+  idl: AnchorIdl,
+  idlInstruction: IdlInstruction,
+  instructions: AnchorInstruction[],
+  structs: AnchorAccountsStruct[],
+  graph: RustCrateGraph
+): SourceBinding | undefined {
+  const candidates = instructions.filter((instruction) => instruction.name === idlInstruction.name);
+  // The IDL is named after the crate, which usually matches the #[program] module as well.
+  const instruction =
+    candidates.find((candidate) => candidate.crateName === crateIdent(idl.programName)) ??
+    candidates.find((candidate) => candidate.programModule === idl.programName) ??
+    candidates[0];
+  if (!instruction) return undefined;
+  return { instruction, struct: accountsStructFor(instruction, structs, graph) };
+}
+
+function checksSignerInBody(instruction: AnchorInstruction, account: string): boolean {
+  return new RegExp(`\\b${escapeRegExp(account)}\\s*\\.\\s*(?:to_account_info\\(\\)\\s*\\.\\s*)?is_signer\\b`).test(
+    instruction.fn.body
+  );
+}
+
+function scanIdlInstruction(
+  scannerId: string,
+  idl: AnchorIdl,
+  idlInstruction: IdlInstruction,
+  binding: SourceBinding
+): Finding[] {
+  const findings: Finding[] = [];
+  const { instruction, struct } = binding;
+  const fieldOf = (account: IdlAccountItem): AnchorAccountField | undefined =>
+    struct?.fields.find((field) => field.name === account.name);
+  const locate = (field: AnchorAccountField | undefined) =>
+    field && struct ? { file: struct.file, line: field.line } : { file: instruction.file, line: instruction.line };
+  const scope = {
+    scannerId,
+    crateName: instruction.crateName,
+    programModule: instruction.programModule,
+    instruction: instruction.name
+  };
+  // A stale IDL can lag the source, so signers declared in either count.
+  const signers = new Set([
+    ...idlInstruction.accounts.filter((account) => account.signer).map((account) => account.name),
+    ...(struct?.fields.filter(isSignerField).map((field) => field.name) ?? [])
+  ]);
+  let authorityReported = false;
+
+  for (const account of idlInstruction.accounts) {
+    const field = fieldOf(account);
+
+    // Authority-named account the client is never asked to sign for. PDAs and fixed addresses sign via the program.
+    if (AUTHORITY_NAME.test(account.name) && !account.signer && !account.address && !account.seeds) {
+      // The source is authoritative: a `Signer` there means the IDL is stale, not that the check is missing.
+      if (!(field && isSignerField(field)) && !checksSignerInBody(instruction, account.name)) {
+        findings.push(
+          makeFinding({
+            ...scope,
+            ...locate(field),
+            vulnClass: "missing_signer_check",
+            severity: "HIGH",
+            confidence: 66,
+            title: "IDL marks authority account as non-signer",
+            description:
+              `The IDL for \`${idl.programName}::${idlInstruction.name}\` lists \`${account.name}\` with signer = false, and the ` +
+              "handler never checks `is_signer`. Any caller can name the real authority without its signature.",
+            evidence: `${account.name}: writable=${account.writable}, signer=false${field ? `; source ${field.rawType}` : ""}`,
+            accountField: account.name,
+            trace: [
+              { file: idl.file, line: idlInstruction.line, label: `IDL instruction ${idlInstruction.name}: ${account.name} signer = false` },
+              { ...locate(field), label: `${account.name} declared in source` }
+            ]
+          })
+        );
+        authorityReported = true;
+      }
+    }
+
+    // Writable PDA addressed purely by instruction data: the caller picks which account is mutated.
+    const seeds = account.seeds ?? [];
+    const argSeeds = seeds.filter((seed) => seed.kind === "arg").map((seed) => seed.path ?? "?");
+    const signerScoped = seeds.some((seed) => seed.kind === "account" && seed.path !== undefined && signers.has(seed.path));
+    const initialized = field !== undefined && (hasConstraint(field, "init") || hasConstraint(field, "init_if_needed"));
+    if (account.writable && argSeeds.length > 0 && !signerScoped && !initialized) {
+      const seedsLine = field ? getConstraint(field, "seeds")?.line : undefined;
+      findings.push(
+        makeFinding({
+          ...scope,
+          ...locate(field),
+          ...(seedsLine ? { line: seedsLine } : {}),
+          vulnClass: "attacker_controlled_seed",
+          severity: "MEDIUM",
+          confidence: 56,
+          title: "Writable PDA derived only from instruction arguments",
+          description:
+            `\`${account.name}\` is writable and its seeds come from instruction arguments (${argSeeds.join(", ")}) without ` +
+            "a signer-derived component. A caller can select any account in the namespace by choosing the arguments.",
+          evidence: `${account.name}: seeds [${seeds.map((seed) => (seed.kind === "const" ? "const" : `${seed.kind}:${seed.path}`)).join(", ")}]`,
+          accountField: account.name
+        })
+      );
+    }
+  }
+
+  // A state-changing instruction that nobody signs is callable by anyone; reported once when no authority finding covers it.
+  const writable = idlInstruction.accounts.filter((account) => account.writable);
+  if (writable.length > 0 && signers.size === 0 && !authorityReported && !/\bis_signer\b/.test(instruction.fn.body)) {
+    findings.push(
+      makeFinding({
+        ...scope,
+        file: instruction.file,
+        line: instruction.line,
+        vulnClass: "missing_signer_check",
+        severity: "MEDIUM",
+        confidence: 58,
+        title: "Instruction mutates accounts without any signer",
+        description:
+          `The IDL for \`${idl.programName}::${idlInstruction.name}\` has no signer account, yet the instruction writes to ` +
+          `${writable.map((account) => `\`${account.name}\``).join(", ")}. Confirm it is meant to be permissionless.`,
+        evidence: `${idlInstruction.name}: writable [${writable.map((account) => account.name).join(", ")}], signers []`
+      })
+    );
+  }
+
+  return findings;
+}
+
+export const solanaIdlScanner: Scanner = {
+  id: "scanner.solana.idl",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const idls = await loadAnchorIdls(rootPath);
+    if (idls.length === 0) return [];
+
+    const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+    const graph = await loadCrateGraph(rootPath, files);
+    const instructions = await collectProgramInstructions(files, graph);
+    const structs: AnchorAccountsStruct[] = [];
+    for (const file of files) structs.push(...extractAnchorAccounts(await fs.readFile(file, "utf8"), file));
+
+    const findings: Finding[] = [];
+    for (const idl of idls) {
+      for (const idlInstruction of idl.instructions) {
+        const binding = bindToSource(idl, idlInstruction, instructions, structs, graph);
+        if (!binding) continue;
+        findings.push(...scanIdlInstruction(this.id, idl, idlInstruction, binding));
+      }
+    }
+
+    return findings;
+  }
+};
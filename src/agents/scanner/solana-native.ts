@@ -0,0 +1,262 @@
+import { promises as fs } from "node:fs";
+import type { Finding } from "../../types";
+import {
+  checksKey,
+  checksOwner,
+  checksSigner,
+  extractNativeProcessors,
+  findNativeAccount,
+  isNativeProgramSource,
+  type NativeAccount,
+  type NativeProcessor
+} from "../../analysis/native-model";
+import { bodyLine } from "../../analysis/rust-items";
+import { loadCrateGraph } from "../../analysis/rust-crates";
+import { matchingBracket } from "../../analysis/rust-source";
+import { taintedFlows, traceTaint } from "../../analysis/taint";
+import { attributeToCrates } from "./anchor-scope";
+import { listFilesRecursive, makeFinding, type Scanner } from "./base";
+
+/** Accounts whose key is treated as an authority; payers are excluded because the runtime enforces their signature on transfer. */
+const AUTHORITY_NAME = /authority|admin|owner|manager|operator|governor|initializer|signer/i;
+/** Fields that let a decoder tell account types apart when several share the program as owner. */
+const DISCRIMINATOR_FIELD = /\b(?:discriminator|account_type|account_kind|tag|kind)\s*:|\bkey\s*:\s*\w*(?:Key|Kind|Type)\b/;
+/** Ways a native instruction names the program it invokes. */
+const PROGRAM_ID_SOURCE =
+  /\bprogram_id\s*:\s*\*?\s*(\w+)\.key\b|\bInstruction::new_with_(?:bytes|borsh|bincode)\s*\(\s*\*?\s*(\w+)\.key\b|\b(?:spl_token(?:_2022)?|spl_associated_token_account)::instruction::\w+\s*\(\s*&?\s*(\w+)\.key\b/;
+
+function position(item: NativeAccount): string {
+  return `account #${item.index} \`${item.name}\``;
+}
+
+function scanSigners(scannerId: string, content: string, processor: NativeProcessor): Finding[] {
+  const findings: Finding[] = [];
+  const body = processor.fn.body;
+
+  for (const item of processor.accounts) {
+    if (!AUTHORITY_NAME.test(item.name)) continue;
+    // Only accounts whose identity is relied upon: compared, stored or passed as an authority.
+    if (!new RegExp(`\\b${item.name}\\.key\\b`).test(body)) continue;
+    if (checksSigner(body, item.name)) continue;
+
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "missing_signer_check",
+        severity: "HIGH",
+        confidence: 66,
+        file: processor.file,
+        line: item.line,
+        title: "Authority account never checked with is_signer",
+        description:
+          `\`${processor.name}\` reads ${position(item)} and relies on its key, but never checks \`${item.name}.is_signer\`. ` +
+          "Anyone can pass the real authority's address without its signature.",
+        evidence: content.split(/\r?\n/)[item.line - 1]?.trim() ?? item.name,
+        instruction: processor.name,
+        programModule: processor.programModule,
+        accountField: item.name
+      })
+    );
+  }
+
+  return findings;
+}
+
+function scanDeserialization(
+  scannerId: string,
+  content: string,
+  processor: NativeProcessor,
+  discriminatorless: Set<string>,
+  decodedTypes: number
+): Finding[] {
+  const findings: Finding[] = [];
+  const body = processor.fn.body;
+
+  for (const decode of processor.deserializations) {
+    const item = findNativeAccount(processor, decode.account)!;
+    // Where the decoded data ends up: a PDA seed, CPI program, signer seed, amount or realloc size.
+    const [flow] = taintedFlows(traceTaint(content, processor.file, processor.fn, { args: [], uncheckedAccounts: [decode.account] }));
+    const trace = [
+      { file: processor.file, line: item.line, label: `${position(item)} taken from the account list` },
+      { file: processor.file, line: decode.line, label: `decoded as ${decode.type}` },
+      ...(flow ? flow.path.slice(1) : [])
+    ];
+    const common = {
+      scannerId,
+      file: processor.file,
+      line: decode.line,
+      evidence: `${decode.type}::${decode.method}(&${decode.account}.data...)`,
+      instruction: processor.name,
+      programModule: processor.programModule,
+      accountField: decode.account,
+      trace
+    };
+
+    // A pinned address implies both owner and type: only this program can create an account at its own PDA.
+    if (checksKey(body, decode.account)) continue;
+
+    if (!checksOwner(body, decode.account)) {
+      findings.push(
+        makeFinding({
+          ...common,
+          vulnClass: "missing_owner_check",
+          severity: "HIGH",
+          confidence: 64,
+          title: "Account data decoded without an owner check",
+          description:
+            `\`${processor.name}\` decodes ${position(item)} as \`${decode.type}\` without checking ` +
+            `\`${decode.account}.owner\` against the program id. An attacker can pass an account they own with the same layout and forged contents.`
+        })
+      );
+      continue;
+    }
+
+    if (decodedTypes > 1 && discriminatorless.has(decode.type)) {
+      findings.push(
+        makeFinding({
+          ...common,
+          vulnClass: "account_type_confusion",
+          severity: "MEDIUM",
+          confidence: 58,
+          title: "try_from_slice without a type discriminator",
+          description:
+            `\`${decode.type}\` has no discriminator field, and the program decodes several account types from raw bytes. ` +
+            `Any other account owned by the program that happens to deserialize as \`${decode.type}\` is accepted in its place.`
+        })
+      );
+    }
+  }
+
+  return findings;
+}
+
+function scanInvokes(scannerId: string, content: string, processor: NativeProcessor): Finding[] {
+  const findings: Finding[] = [];
+  const body = processor.fn.body;
+
+  for (const call of body.matchAll(/\binvoke(?:_signed)?\s*\(/g)) {
+    const offset = call.index ?? 0;
+    const close = matchingBracket(body, offset + call[0].length - 1);
+    let args = body.slice(offset, close < 0 ? body.length : close);
+    // Follow `invoke(&ix, ..)` back to `let ix = ...;`.
+    const builder = args.match(/\(\s*&\s*(\w+)\s*,/)?.[1];
+    if (builder) {
+      const bindings = [
+        ...body.slice(0, offset).matchAll(new RegExp(`\\blet\\s+(?:mut\\s+)?${builder}\\b[^=]*=([\\s\\S]*?);`, "g"))
+      ];
+      const binding = bindings[bindings.length - 1];
+      if (binding) args = `${binding[1]} ${args}`;
+    }
+
+    const source = args.match(PROGRAM_ID_SOURCE);
+    const programAccount = source?.slice(1).find(Boolean);
+    if (!programAccount) continue;
+    const item = findNativeAccount(processor, programAccount);
+    if (!item || checksKey(body, programAccount)) continue;
+
+    const line = bodyLine(content, processor.fn, offset);
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "arbitrary_cpi",
+        severity: "CRITICAL",
+        confidence: 72,
+        file: processor.file,
+        line,
+        title: "CPI target program taken from an unchecked account",
+        description:
+          `\`${processor.name}\` invokes the program passed as ${position(item)} without comparing its key to the expected ` +
+          "program id. An attacker can substitute a malicious program that receives the forwarded accounts and signatures.",
+        evidence: `${source![0].trim()} ... ${call[0].replace(/\s*\($/, "")}()`,
+        instruction: processor.name,
+        programModule: processor.programModule,
+        accountField: programAccount,
+        trace: [
+          { file: processor.file, line: item.line, label: `${position(item)} taken from the account list` },
+          { file: processor.file, line, label: "invoked as the CPI program" }
+        ]
+      })
+    );
+  }
+
+  return findings;
+}
+
+/** `create_program_address` with a bump decoded from instruction data accepts any of the 255 bumps. */
+function scanBumps(scannerId: string, content: string, processor: NativeProcessor): Finding[] {
+  const findings: Finding[] = [];
+  const body = processor.fn.body;
+  if (/\bfind_program_address\s*\(/.test(body)) return findings;
+
+  for (const call of body.matchAll(/\bcreate_program_address\s*\(/g)) {
+    const offset = call.index ?? 0;
+    const close = matchingBracket(body, offset + call[0].length - 1);
+    const seeds = body.slice(offset, close < 0 ? body.length : close);
+    const bump = seeds.match(/&\s*\[\s*(\w+)\s*\]/)?.[1];
+    if (!bump || /^\d+$/.test(bump)) continue;
+    // Bumps read back from program-owned state were canonical when stored.
+    if (new RegExp(`\\blet\\s+${bump}\\s*=\\s*\\w+\\.\\w*bump\\b`).test(body)) continue;
+
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "non_canonical_bump",
+        severity: "HIGH",
+        confidence: 66,
+        file: processor.file,
+        line: bodyLine(content, processor.fn, offset),
+        title: "PDA derived with a caller-supplied bump",
+        description:
+          `\`${processor.name}\` derives a PDA with \`create_program_address\` using bump \`${bump}\` and never calls ` +
+          "`find_program_address`. A caller can supply a non-canonical bump and obtain a second valid address for the same seeds.",
+        evidence: `create_program_address(... &[${bump}] ...)`,
+        instruction: processor.name,
+        programModule: processor.programModule
+      })
+    );
+  }
+
+  return findings;
+}
+
+export const solanaNativeScanner: Scanner = {
+  id: "scanner.solana.native",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+    const contents = new Map<string, string>();
+    for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+
+    const processors = [...contents.entries()]
+      .filter(([, content]) => !content.includes("#[program]") && isNativeProgramSource(content))
+      .flatMap(([file, content]) => extractNativeProcessors(content, file));
+    if (processors.length === 0) return [];
+
+    // Discriminator analysis is program-wide: confusion needs at least two decodable account types.
+    const decoded = new Set(
+      processors.flatMap((processor) =>
+        processor.deserializations.filter((decode) => decode.method.startsWith("try_from_slice")).map((decode) => decode.type)
+      )
+    );
+    const discriminatorless = new Set(
+      [...decoded].filter((type) =>
+        [...contents.values()].some((content) => {
+          const definition = content.match(new RegExp(`\\bstruct\\s+${type}\\b[^{;]*\\{([^}]*)\\}`));
+          return definition !== null && !DISCRIMINATOR_FIELD.test(definition[1]);
+        })
+      )
+    );
+
+    const findings: Finding[] = [];
+    for (const processor of processors) {
+      const content = contents.get(processor.file) ?? "";
+      findings.push(
+        ...scanSigners(this.id, content, processor),
+        ...scanDeserialization(this.id, content, processor, discriminatorless, decoded.size),
+        ...scanInvokes(this.id, content, processor),
+        ...scanBumps(this.id, content, processor)
+      );
+    }
+
+    return attributeToCrates(findings, await loadCrateGraph(rootPath, files));
+  }
+};
@@ -1,34 +1,43 @@
 import { promises as fs } from "node:fs";
-import type { Finding } from "../../types";
+import type { Finding, VulnClass } from "../../types";
+import {
+  extractAnchorAccounts,
+  getConstraint,
+  hasConstraint,
+  type AnchorAccountsStruct,
+  type AnchorInstruction
+} from "../../analysis/anchor-model";
+import {
+  adjacentVariableSeeds,
+  anchorSeedSchemas,
+  buildPdaKindTable,
+  extractFieldTypes,
+  extractSeedConstants,
+  manualSeedSchemas,
+  schemaSignature,
+  schemasOverlap,
+  type PdaKind,
+  type PdaSchema
+} from "../../analysis/pda-seeds";
+import { crateOf, loadCrateGraph, type RustCrateGraph } from "../../analysis/rust-crates";
+import { constraintTaint, formatTaintPath, KEY_SEED, taintedFlows, traceTaint, type TaintFlow } from "../../analysis/taint";
+import {
+  accountsStructFor,
+  collectProgramInstructions,
+  instructionsUsingStruct,
+  scopeFindingsToInstructions,
+  taintOptionsFor
+} from "./anchor-scope";
 import {
-  findLineContaining,
   listFilesRecursive,
   makeFinding,
-  marker,
+  markerFindings,
   scanFileWithPatterns,
   type PatternRule,
   type Scanner
 } from "./base";
 
-const markerRules = [
-  {
-    tag: "non_canonical_bump",
-    title: "Non-canonical bump handling",
-    description:
-      "PDA derivation appears to allow non-canonical bump use, increasing spoof/collision risk."
-  },
-  {
-    tag: "seed_collision",
-    title: "PDA seed collision risk",
-    description: "Seed composition may permit collisions across logical account namespaces."
-  },
-  {
-    tag: "attacker_controlled_seed",
-    title: "Attacker-controlled PDA seed component",
-    description:
-      "Attacker input appears to influence PDA seeds without strong domain separation."
-  }
-] as const;
+const MARKER_CLASSES: VulnClass[] = ["non_canonical_bump", "seed_collision", "attacker_controlled_seed"];
 
 const patternRules: PatternRule[] = [
   {
@@ -40,6 +49,7 @@ const patternRules: PatternRule[] = [
       "Function accepts bump as u8 parameter instead of deriving it via find_program_address. " +
       "Attacker can supply a non-canonical bump to create a different PDA that passes validation.",
     pattern: /\bbump\s*:\s*u8\b/,
+    scope: ["code", "attribute"],
     mitigations: [
       // Safe if find_program_address is called to verify
       /find_program_address\s*\(/
@@ -59,6 +69,7 @@ const patternRules: PatternRule[] = [
       /find_program_address\s*\(/,
       /\bbump\s*=\s*bump\b/ // Anchor bump constraint
     ],
+    mitigationScope: ["code", "attribute"],
     contextLines: 15
   },
   {
@@ -70,9 +81,11 @@ const patternRules: PatternRule[] = [
       "PDA seeds appear to include data from instruction arguments without domain separation. " +
       "Attacker may craft inputs that collide with existing PDA addresses.",
     pattern: /seeds\s*=\s*\[.*\bctx\.accounts\.\w+\.key\(\)/,
+    scope: ["code", "attribute"],
     mitigations: [
       /b"[a-zA-Z_]+"/ // Static seed prefix provides domain separation
     ],
+    mitigationScope: ["code", "attribute", "string"],
     contextLines: 3
   },
   {
@@ -88,53 +101,248 @@ const patternRules: PatternRule[] = [
       /b"[a-zA-Z_]+"/, // Has a static seed prefix
       /b"[a-zA-Z_]+"\.as_ref\(\)/ // Has a static seed prefix via as_ref
     ],
+    mitigationScope: ["code", "string"],
     contextLines: 5
-  },
-  {
-    vulnClass: "non_canonical_bump",
-    severity: "MEDIUM",
-    confidence: 62,
-    title: "Anchor seeds constraint without bump verification",
-    description:
-      "Anchor #[account] seeds constraint does not include a bump field, meaning the canonical " +
-      "bump is not enforced and a non-canonical PDA could be supplied.",
-    pattern: /#\[account\([^)]*seeds\s*=/,
-    mitigations: [/bump\s*=/, /bump\s*\)/],
-    contextLines: 3
   }
 ];
 
+function scanSeedConstraints(scannerId: string, file: string, structs: AnchorAccountsStruct[]): Finding[] {
+  const findings: Finding[] = [];
+
+  for (const struct of structs) {
+    for (const field of struct.fields) {
+      const seeds = getConstraint(field, "seeds");
+      if (!seeds) continue;
+
+      if (!hasConstraint(field, "bump")) {
+        findings.push(
+          makeFinding({
+            scannerId,
+            vulnClass: "non_canonical_bump",
+            severity: "MEDIUM",
+            confidence: 62,
+            file,
+            line: seeds.line,
+            title: "Anchor seeds constraint without bump verification",
+            description:
+              "Anchor #[account] seeds constraint does not include a bump field, meaning the canonical " +
+              "bump is not enforced and a non-canonical PDA could be supplied.",
+            evidence: `${struct.name}.${field.name}: seeds = ${seeds.value ?? ""}`,
+            accountField: field.name
+          })
+        );
+      }
+    }
+  }
+
+  return findings;
+}
+
+/**
+ * Seeds that carry caller-chosen bytes of caller-chosen length, traced from a handler argument or
+ * unchecked account data into `find_program_address` / `create_program_address` or a `seeds` constraint.
+ */
+function scanSeedTaint(
+  scannerId: string,
+  file: string,
+  content: string,
+  structs: AnchorAccountsStruct[],
+  allStructs: AnchorAccountsStruct[],
+  instructions: AnchorInstruction[],
+  graph: RustCrateGraph
+): Finding[] {
+  const flows: { flow: TaintFlow; instruction: AnchorInstruction }[] = [];
+  for (const instruction of instructions.filter((candidate) => candidate.file === file)) {
+    const options = taintOptionsFor(instruction, accountsStructFor(instruction, allStructs, graph));
+    for (const flow of taintedFlows(traceTaint(content, file, instruction.fn, options))) flows.push({ flow, instruction });
+  }
+  for (const struct of structs) {
+    for (const instruction of instructionsUsingStruct(instructions, struct, graph)) {
+      for (const flow of constraintTaint(struct, instruction)) flows.push({ flow, instruction });
+    }
+  }
+
+  // A fixed-width key among the seeds (usually the signer's) confines the caller to their own accounts.
+  return flows
+    .filter(({ flow }) => flow.sink === "pda_seed" && flow.source.variableLength)
+    .filter(({ flow }) => flow.source.kind === "account_data" || !KEY_SEED.test(flow.expr))
+    .map(({ flow, instruction }) => {
+      const source = flow.source.kind === "account_data" ? `data of unchecked account \`${flow.source.name}\`` : `argument \`${flow.source.name}\``;
+      return makeFinding({
+        scannerId,
+        vulnClass: "attacker_controlled_seed",
+        severity: "HIGH",
+        confidence: 72,
+        file: flow.file,
+        line: flow.line,
+        title: "Caller-chosen bytes flow into PDA seeds",
+        description:
+          `The ${source} of \`${instruction.name}\` reaches the seeds of ${flow.text}, and no account key among the seeds ` +
+          "ties the address to the caller. Variable-length bytes let anyone derive, and squat or substitute, the PDA meant " +
+          "for another user or account kind. Add the owner's key to the seeds, or derive from fixed-width values.",
+        evidence: formatTaintPath(flow),
+        programModule: instruction.programModule,
+        instruction: instruction.name,
+        trace: flow.path
+      });
+    });
+}
+
+/** Every PDA derivation in the workspace, Anchor seeds constraints and manual derivations alike, grouped by crate. */
+function collectSeedSchemas(
+  files: string[],
+  structsByFile: Map<string, AnchorAccountsStruct[]>,
+  instructions: AnchorInstruction[],
+  graph: RustCrateGraph
+): Map<string, PdaSchema[]> {
+  const byCrate = new Map<string, PdaSchema[]>();
+  for (const crate of graph.crates) {
+    const crateFiles = files.filter((file) => crateOf(graph, file) === crate);
+    const code = crateFiles.map((file) => graph.sources.get(file) ?? "").join("\n");
+    const context = { constants: extractSeedConstants(code), fields: extractFieldTypes(code) };
+    const schemas: PdaSchema[] = [];
+    for (const file of crateFiles) {
+      for (const struct of structsByFile.get(file) ?? []) {
+        const args = instructionsUsingStruct(instructions, struct, graph).flatMap((instruction) => instruction.args);
+        schemas.push(...anchorSeedSchemas(struct, args, context));
+      }
+      schemas.push(...manualSeedSchemas(graph.sources.get(file) ?? "", file, context));
+    }
+    const unique = schemas.filter(
+      (schema, index) => schemas.findIndex((other) => other.file === schema.file && other.line === schema.line) === index
+    );
+    if (unique.length > 0) byCrate.set(crate.name, unique);
+  }
+  return byCrate;
+}
+
+function schemaLabel(schema: PdaSchema): string {
+  return `${schema.accountType ?? schema.site} ${schemaSignature(schema)}`;
+}
+
+/** Whether two derivations are the same account written two ways rather than two kinds sharing an address space. */
+function sameAccount(a: PdaSchema, b: PdaSchema): boolean {
+  if (a.accountType && a.accountType === b.accountType) return true;
+  // A manual derivation that recomputes an Anchor-constrained PDA often names its inputs differently.
+  const shape = (schema: PdaSchema) => schemaSignature({ ...schema, seeds: schema.seeds.map((seed) => ({ ...seed, root: undefined })) });
+  return a.source !== b.source && shape(a) === shape(b);
+}
+
+/**
+ * Program-wide seed schema analysis: PDA kinds whose seed tuples can concatenate to the same bytes,
+ * and single kinds with adjacent variable-length seeds whose boundary can be moved.
+ */
+function scanSeedSchemas(scannerId: string, schemas: PdaSchema[]): Finding[] {
+  const findings: Finding[] = [];
+  const kinds: PdaKind[] = buildPdaKindTable(schemas);
+
+  for (const [index, kind] of kinds.entries()) {
+    for (const other of kinds.slice(index + 1)) {
+      let pair: [PdaSchema, PdaSchema] | undefined;
+      for (const a of kind.schemas) {
+        pair = other.schemas.map((b) => [a, b] as [PdaSchema, PdaSchema]).find(([x, y]) => !sameAccount(x, y) && schemasOverlap(x, y));
+        if (pair) break;
+      }
+      if (!pair) continue;
+
+      const [first, second] = pair.sort((x, y) => x.file.localeCompare(y.file) || x.line - y.line);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "seed_collision",
+          severity: "HIGH",
+          confidence: 68,
+          file: first.file,
+          line: first.line,
+          title: "PDA seed schemas of two account kinds can collide",
+          description:
+            `Seeds of \`${first.site}\` and \`${second.site}\` concatenate to the same bytes for some inputs, and PDA ` +
+            "derivation hashes the concatenation. An attacker can choose keys or data so one kind's address is the other's, " +
+            "then pass an account of one kind where the other is expected or squat the address before it is created.",
+          evidence: `${schemaLabel(first)} vs ${schemaLabel(second)}`,
+          accountField: first.accountField,
+          trace: [
+            { file: first.file, line: first.line, label: `${first.site} derived from ${schemaSignature(first)}` },
+            { file: second.file, line: second.line, label: `${second.site} derived from ${schemaSignature(second)}` }
+          ]
+        })
+      );
+    }
+
+    const schema = kind.schemas.find((candidate) => adjacentVariableSeeds(candidate) >= 0);
+    if (!schema) continue;
+    const at = adjacentVariableSeeds(schema);
+    const [left, right] = [schema.seeds[at], schema.seeds[at + 1]];
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "seed_collision",
+        severity: "MEDIUM",
+        confidence: 64,
+        file: schema.file,
+        line: schema.line,
+        title: "Adjacent variable-length PDA seeds can be re-split",
+        description:
+          `\`${left.text}\` and \`${right.text}\` are both variable-length and sit next to each other in the seeds of ` +
+          `\`${schema.site}\`. Moving bytes from one to the other (\`"ab" + "c"\` vs \`"a" + "bc"\`) derives the same ` +
+          "address for two different logical accounts. Put a fixed-width or length-prefixed seed between them, or hash them.",
+        evidence: `${schemaLabel(schema)}`,
+        accountField: schema.accountField,
+        trace: kind.schemas.map((site) => ({ file: site.file, line: site.line, label: `${site.site} derived from ${schemaSignature(site)}` }))
+      })
+    );
+  }
+
+  return findings;
+}
+
 export const solanaPdaScanner: Scanner = {
   id: "scanner.solana.pda",
   async scan(rootPath: string): Promise<Finding[]> {
     const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+    const graph = await loadCrateGraph(rootPath, files);
+    const instructions = await collectProgramInstructions(files, graph);
+    const contents = new Map<string, string>();
+    for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+    const structsByFile = new Map(
+      files.map((file) => [file, extractAnchorAccounts(contents.get(file) ?? "", file)] as const)
+    );
+    const allStructs = [...structsByFile.values()].flat();
     const findings: Finding[] = [];
 
     for (const file of files) {
-      const content = await fs.readFile(file, "utf8");
+      const content = contents.get(file) ?? "";
+      const structs = structsByFile.get(file) ?? [];
+      const fileFindings: Finding[] = [];
 
       // Marker-based detection (golden repos / eval compatibility)
-      for (const rule of markerRules) {
-        const token = marker(rule.tag);
-        if (!content.includes(token)) continue;
+      fileFindings.push(...markerFindings(this.id, file, content, MARKER_CLASSES, 86));
+
+      // Pattern-based detection (real code analysis)
+      fileFindings.push(...scanFileWithPatterns(this.id, file, content, patternRules));
+
+      // Model-based detection over #[account(seeds = ...)] constraints
+      fileFindings.push(...scanSeedConstraints(this.id, file, structs));
+
+      // Taint-based detection: caller-chosen bytes traced into PDA seeds
+      fileFindings.push(...scanSeedTaint(this.id, file, content, structs, allStructs, instructions, graph));
 
+      findings.push(...scopeFindingsToInstructions(fileFindings, structs, instructions, graph));
+    }
+
+    // Program-wide seed schemas: each crate is its own program ID, so kinds are only compared within a crate.
+    for (const schemas of collectSeedSchemas(files, structsByFile, instructions, graph).values()) {
+      const schemaFindings = scanSeedSchemas(this.id, schemas);
+      for (const file of new Set(schemaFindings.map((finding) => finding.file))) {
         findings.push(
-          makeFinding({
-            scannerId: this.id,
-            vulnClass: rule.tag,
-            severity: "HIGH",
-            confidence: 86,
-            file,
-            line: findLineContaining(content, token),
-            title: rule.title,
-            description: rule.description,
-            evidence: `Found marker ${token}`
-          })
+          ...scopeFindingsToInstructions(
+            schemaFindings.filter((finding) => finding.file === file),
+            structsByFile.get(file) ?? [],
+            instructions,
+            graph
+          )
         );
       }
-
-      // Pattern-based detection (real code analysis)
-      findings.push(...scanFileWithPatterns(this.id, file, content, patternRules));
     }
 
     return findings;
@@ -0,0 +1,206 @@
+import { promises as fs } from "node:fs";
+import type { Finding, FindingLocation, InstructionPrivileges, VulnClass } from "../../types";
+import { extractAnchorAccounts, type AnchorAccountsStruct, type AnchorInstruction } from "../../analysis/anchor-model";
+import { privilegeModel, type PdaSignature, type PrivilegeModel } from "../../analysis/privilege";
+import { loadCrateGraph, type RustCrateGraph } from "../../analysis/rust-crates";
+import {
+  accountsStructFor,
+  attributeToCrates,
+  collectProgramInstructions,
+  scopeFindingsToInstructions
+} from "./anchor-scope";
+import { listFilesRecursive, makeFinding, markerFindings, type Scanner } from "./base";
+
+const MARKER_CLASSES: VulnClass[] = ["unnecessary_mut_account", "unnecessary_signer", "overprivileged_pda_signer"];
+
+export interface PrivilegeScan {
+  graph: RustCrateGraph;
+  contents: Map<string, string>;
+  structsByFile: Map<string, AnchorAccountsStruct[]>;
+  instructions: AnchorInstruction[];
+  models: PrivilegeModel[];
+}
+
+/** Privilege models of every Anchor instruction under `rootPath`, joined with their Accounts structs. */
+export async function loadPrivilegeModels(rootPath: string): Promise<PrivilegeScan> {
+  const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+  const graph = await loadCrateGraph(rootPath, files);
+  const instructions = await collectProgramInstructions(files, graph);
+  const contents = new Map<string, string>();
+  for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+  const structsByFile = new Map(
+    files.map((file) => [file, extractAnchorAccounts(contents.get(file) ?? "", file)] as const)
+  );
+  const allStructs = [...structsByFile.values()].flat();
+
+  const models: PrivilegeModel[] = [];
+  for (const instruction of instructions) {
+    if (!instruction.accountsStruct) continue;
+    const struct = accountsStructFor(instruction, allStructs, graph);
+    if (!struct) continue;
+    models.push(privilegeModel(contents.get(instruction.file) ?? "", instruction, struct));
+  }
+  return { graph, contents, structsByFile, instructions, models };
+}
+
+function sameStruct(a: AnchorAccountsStruct, b: AnchorAccountsStruct): boolean {
+  return a.file === b.file && a.name === b.name && a.line === b.line;
+}
+
+/**
+ * Mutability and signer requirements of a struct used by no instruction that needs them.
+ * A struct shared by several instructions keeps a privilege if any of them uses it, and is not
+ * judged at all when one of them hands its context to code the model cannot see.
+ */
+function unusedPrivileges(scannerId: string, struct: AnchorAccountsStruct, models: PrivilegeModel[]): Finding[] {
+  if (models.length === 0 || models.some((model) => model.opaque)) return [];
+  const findings: Finding[] = [];
+  const users = models.map((model) => `\`${model.instruction.name}\``).join(", ");
+  // A lone signer identifies the caller; when nothing checks it, the missing check is an
+  // account-validation finding rather than a surplus signature.
+  const cosigned = models[0].fields.filter((use) => use.signer).length > 1;
+
+  for (const [index, field] of struct.fields.entries()) {
+    const uses = models.map((model) => model.fields[index]);
+    if (uses[0].mutable && uses.every((use) => !use.written)) {
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unnecessary_mut_account",
+          severity: "LOW",
+          confidence: 55,
+          file: struct.file,
+          line: field.line,
+          title: "Account marked mutable but never written",
+          description:
+            `\`${struct.name}.${field.name}\` is declared \`mut\`, but ${users} never modify it and no constraint writes it. ` +
+            "A writable account is locked for the whole transaction and lets any later change or CPI in the handler " +
+            "alter it without the struct signalling that; drop `mut` if the account is only read.",
+          evidence: `${struct.name}.${field.name}: ${field.rawType}`,
+          accountField: field.name
+        })
+      );
+    }
+
+    if (cosigned && uses[0].signer && uses.every((use) => !use.authorizes)) {
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unnecessary_signer",
+          severity: "LOW",
+          confidence: 52,
+          file: struct.file,
+          line: field.line,
+          title: "Signer required but never used for authorization",
+          description:
+            `\`${struct.name}.${field.name}\` must co-sign, yet no constraint binds it and ${users} never read it. ` +
+            "Either a check tying this account to the state it should govern is missing, or the signature only " +
+            "makes the instruction harder to use and trains users to sign for accounts that grant nothing.",
+          evidence: `${struct.name}.${field.name}: ${field.rawType}`,
+          accountField: field.name
+        })
+      );
+    }
+  }
+  return findings;
+}
+
+/**
+ * PDAs whose signer seeds carry no per-entity component are one authority for the whole program.
+ * When such an authority signs several kinds of operation, a flaw in any path that can reach one of
+ * them spends the authority of all of them.
+ */
+function overprivilegedSigners(scannerId: string, models: PrivilegeModel[], graph: RustCrateGraph): Finding[] {
+  const groups = new Map<string, { model: PrivilegeModel; signature: PdaSignature }[]>();
+  for (const model of models) {
+    for (const signature of model.signatures) {
+      if (signature.scoped || !signature.operation.includes("::")) continue;
+      const key = `${model.instruction.crateName ?? ""}|${signature.literals.join(",")}`;
+      groups.set(key, [...(groups.get(key) ?? []), { model, signature }]);
+    }
+  }
+
+  const findings: Finding[] = [];
+  for (const sites of groups.values()) {
+    const operations = [...new Set(sites.map((site) => site.signature.operation))];
+    if (operations.length < 2) continue;
+    const [first] = sites;
+    const trace: FindingLocation[] = sites.map(({ model, signature }) => ({
+      file: signature.file,
+      line: signature.line,
+      label: `signs \`${signature.operation}\` in \`${model.instruction.name}\``
+    }));
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "overprivileged_pda_signer",
+        severity: "MEDIUM",
+        confidence: 56,
+        file: first.signature.file,
+        line: first.signature.line,
+        title: "PDA authority signs unrelated operations",
+        description:
+          `The PDA signed with seeds \`${first.signature.seeds}\` has no per-user or per-pool seed, yet it authorizes ` +
+          `${operations.map((operation) => `\`${operation}\``).join(", ")}. Every account it controls answers to any ` +
+          "instruction able to produce that signature; separate seeds per purpose (or per entity) would confine a flaw " +
+          "in one path to the accounts that path manages.",
+        evidence: first.signature.seeds,
+        crateName: first.model.instruction.crateName,
+        programModule: first.model.instruction.programModule,
+        instruction: first.model.instruction.name,
+        trace
+      })
+    );
+  }
+  return attributeToCrates(findings, graph);
+}
+
+/** Per-instruction privilege summary for the report: what each Accounts struct asks for and what the handler uses. */
+export function summarizePrivileges(models: PrivilegeModel[]): InstructionPrivileges[] {
+  return models.map((model) => ({
+    ...(model.instruction.crateName ? { crate: model.instruction.crateName } : {}),
+    program_module: model.instruction.programModule,
+    instruction: model.instruction.name,
+    file: model.instruction.file,
+    line: model.instruction.line,
+    mutable_accounts: model.fields
+      .filter((use) => use.mutable)
+      .map((use) => ({ account: use.field.name, written: use.written })),
+    signers: model.fields
+      .filter((use) => use.signer)
+      .map((use) => ({ account: use.field.name, authorizes: use.authorizes })),
+    pda_signers: model.signatures.map((signature) => ({
+      seeds: signature.seeds,
+      operation: signature.operation,
+      line: signature.line
+    })),
+    opaque: model.opaque
+  }));
+}
+
+export const solanaPrivilegeScanner: Scanner = {
+  id: "scanner.solana.privilege",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const { graph, contents, structsByFile, instructions, models } = await loadPrivilegeModels(rootPath);
+    const findings: Finding[] = [];
+
+    for (const [file, structs] of structsByFile) {
+      const content = contents.get(file) ?? "";
+      const fileFindings: Finding[] = [];
+
+      // Marker-based detection (golden repos / eval compatibility)
+      fileFindings.push(...markerFindings(this.id, file, content, MARKER_CLASSES, 85));
+
+      // Model-based detection: privileges each struct grants that none of its instructions use
+      for (const struct of structs) {
+        const users = models.filter((model) => sameStruct(model.struct, struct));
+        fileFindings.push(...unusedPrivileges(this.id, struct, users));
+      }
+
+      findings.push(...scopeFindingsToInstructions(fileFindings, structs, instructions, graph));
+    }
+
+    findings.push(...overprivilegedSigners(this.id, models, graph));
+    return findings;
+  }
+};
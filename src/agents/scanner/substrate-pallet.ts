@@ -0,0 +1,169 @@
+import type { Finding, FindingLocation } from "../../types";
+import { bodyLine, type RustFunction } from "../../analysis/rust-items";
+import { matchingBracket } from "../../analysis/rust-source";
+import {
+  checksSigner,
+  originChecks,
+  palletIterations,
+  palletWrites,
+  type Pallet,
+  type SubstrateProject
+} from "../../analysis/substrate-model";
+import { attributeToCrates } from "./anchor-scope";
+import { evidenceLine, makeFinding, type Scanner } from "./base";
+import { loadSubstrateProject, scanBalanceArithmetic } from "./substrate-scope";
+
+/**
+ * Signed-only dispatchables that change pallet-wide configuration (a `StorageValue`), or that
+ * drop the signer and still write storage, act for whoever submits the extrinsic: the usual
+ * result of `ensure_signed` where `ensure_root` or the pallet's admin origin was meant.
+ */
+function scanOrigins(scannerId: string, pallet: Pallet, project: SubstrateProject, content: string): Finding[] {
+  const findings: Finding[] = [];
+  for (const call of pallet.calls) {
+    const origin = originChecks(call);
+    if (!origin.signed || origin.root || origin.configured) continue;
+    const body = call.fn.body;
+    if (origin.signer && checksSigner(body, origin.signer)) continue;
+
+    const writes = palletWrites(body, project.storage);
+    const signerUsed = origin.signer ? new RegExp(`\\b${origin.signer}\\b`).test(body.replace(/\blet\s+\w+\s*=\s*ensure_signed[^;]*;/, "")) : false;
+    const privileged = writes.filter((write) => project.storage.get(write.store) === "value" || !signerUsed);
+    if (privileged.length === 0) continue;
+
+    const actions: FindingLocation[] = privileged.map((write) => ({
+      file: pallet.file,
+      line: bodyLine(content, call.fn, write.offset),
+      label: `\`${write.store}::${write.operation}\``
+    }));
+    const [first] = actions;
+    findings.push(
+      makeFinding({
+        scannerId,
+        vulnClass: "wrong_origin_check",
+        severity: "HIGH",
+        confidence: origin.signer ? 62 : 66,
+        file: pallet.file,
+        line: first.line,
+        title: "Privileged dispatchable accepts any signed origin",
+        description:
+          `\`${call.fn.name}\` only calls \`ensure_signed(${call.origin})\`${origin.signer ? ` and never compares \`${origin.signer}\` with a stored authority` : " and discards the signer"}, ` +
+          `yet writes ${[...new Set(privileged.map((write) => `\`${write.store}\``))].join(", ")}. Any account can submit this extrinsic. ` +
+          "Use `ensure_root`, or `T::AdminOrigin::ensure_origin`, for configuration changes, and check the signer against the account the write affects otherwise.",
+        evidence: evidenceLine(content, first.line),
+        programModule: "call",
+        instruction: call.fn.name,
+        trace: [{ file: pallet.file, line: call.fn.line, label: `\`${call.fn.name}\` (signed origin only)` }, ...actions]
+      })
+    );
+  }
+  return findings;
+}
+
+/**
+ * Whether the iterator built at `offset` is capped with `.take(n)`, either in the same
+ * expression or later through the `let` binding that holds it (`let mut iter = ..; iter.take(n)`).
+ */
+function boundedAt(body: string, offset: number): boolean {
+  const open = body.indexOf("(", offset);
+  const close = open < 0 ? -1 : matchingBracket(body, open);
+  const rest = body.slice(close + 1);
+  const end = rest.search(/[;{}]/);
+  if (/\.\s*take\s*\(/.test(end < 0 ? rest : rest.slice(0, end))) return true;
+
+  const statement = body.slice(body.lastIndexOf(";", offset) + 1, offset);
+  const binding = statement.match(/\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=]*)?=/)?.[1];
+  return Boolean(binding && new RegExp(`\\b${binding}\\b[^;{]*\\.\\s*take\\s*\\(`).test(body.slice(offset)));
+}
+
+function iterationFindings(
+  scannerId: string,
+  pallet: Pallet,
+  project: SubstrateProject,
+  content: string,
+  fn: RustFunction,
+  hook: boolean
+): Finding[] {
+  return palletIterations(fn.body, project.storage)
+    .filter((iteration) => !boundedAt(fn.body, iteration.offset))
+    .slice(0, 1)
+    .map((iteration) => {
+      const line = bodyLine(content, fn, iteration.offset);
+      return makeFinding({
+        scannerId,
+        vulnClass: "unbounded_weight",
+        severity: "HIGH",
+        confidence: hook ? 62 : 60,
+        file: pallet.file,
+        line,
+        title: hook ? "Block hook iterates storage without a bound" : "Dispatchable iterates storage without a bound",
+        description:
+          `\`${fn.name}\` walks every entry of \`${iteration.store}\` with \`${iteration.operation}()\` and no \`.take(n)\`. ` +
+          (hook
+            ? "Hooks run in every block before any extrinsic; once the map is large enough the block exceeds its weight limit and the chain stalls. "
+            : "The declared weight cannot grow with the map, so callers pay for a fraction of the work and a large map makes the call unexecutable. ") +
+          "Process a bounded batch per block or per call and keep a cursor.",
+        evidence: evidenceLine(content, line),
+        programModule: hook ? "hooks" : "call",
+        instruction: fn.name
+      });
+    });
+}
+
+function scanWeights(scannerId: string, pallet: Pallet, project: SubstrateProject, content: string): Finding[] {
+  const findings: Finding[] = [];
+  for (const hook of pallet.hooks) {
+    findings.push(...iterationFindings(scannerId, pallet, project, content, hook, true));
+  }
+  for (const call of pallet.calls) {
+    const iterated = iterationFindings(scannerId, pallet, project, content, call.fn, false);
+    findings.push(...iterated);
+    if (iterated.length > 0) continue;
+
+    // `Vec<_>` arguments have no length cap; the weight must scale with what the loop walks.
+    for (const param of call.fn.params.filter((candidate) => /^Vec\s*</.test(candidate.type))) {
+      if (call.weight && new RegExp(`\\b${param.name}\\b`).test(call.weight)) continue;
+      const loop = call.fn.body.search(new RegExp(`\\bfor\\s+[^;{]*\\bin\\s+&?\\s*${param.name}\\b|\\b${param.name}\\s*\\.\\s*(?:iter|into_iter|iter_mut)\\s*\\(`));
+      if (loop < 0) continue;
+      const line = bodyLine(content, call.fn, loop);
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "unbounded_weight",
+          severity: "MEDIUM",
+          confidence: 56,
+          file: pallet.file,
+          line,
+          title: "Loop over an unbounded Vec argument with a fixed weight",
+          description:
+            `\`${call.fn.name}\` loops over \`${param.name}: ${param.type}\`, but its weight ` +
+            `${call.weight ? `(\`${call.weight}\`) does not depend on \`${param.name}\`` : "is not declared"}. A caller can pass ` +
+            "an arbitrarily long list and pay for one item; take a `BoundedVec` and charge weight per element.",
+          evidence: evidenceLine(content, line),
+          programModule: "call",
+          instruction: call.fn.name
+        })
+      );
+    }
+  }
+  return findings;
+}
+
+function scanPallets(scannerId: string, project: SubstrateProject): Finding[] {
+  return project.pallets.flatMap((pallet) => {
+    const content = project.contents.get(pallet.file) ?? "";
+    return [
+      ...scanOrigins(scannerId, pallet, project, content),
+      ...scanWeights(scannerId, pallet, project, content),
+      ...scanBalanceArithmetic(scannerId, pallet.file, content, pallet.functions)
+    ];
+  });
+}
+
+export const substratePalletScanner: Scanner = {
+  id: "scanner.substrate.pallet",
+  async scan(rootPath: string): Promise<Finding[]> {
+    const project = await loadSubstrateProject(rootPath);
+    return attributeToCrates(scanPallets(this.id, project), project.graph);
+  }
+};
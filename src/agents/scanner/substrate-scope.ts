@@ -0,0 +1,52 @@
+import { promises as fs } from "node:fs";
+import type { Finding } from "../../types";
+import { loadCrateGraph } from "../../analysis/rust-crates";
+import { bodyLine, type RustFunction } from "../../analysis/rust-items";
+import { buildSubstrateProject, uncheckedBalanceArithmetic, type SubstrateProject } from "../../analysis/substrate-model";
+import { evidenceLine, listFilesRecursive, makeFinding } from "./base";
+
+/** Functions whose arithmetic moves value between accounts. */
+const VALUE_FLOW = /transfer|withdraw|deposit|mint|burn|send|pay|stake|claim|redeem|credit|debit/i;
+
+/** ink! contracts and FRAME pallets under `rootPath`, shared by the Substrate scanners and the threat model. */
+export async function loadSubstrateProject(rootPath: string): Promise<SubstrateProject> {
+  const files = await listFilesRecursive(rootPath, (filePath) => filePath.endsWith(".rs"));
+  const graph = await loadCrateGraph(rootPath, files);
+  const contents = new Map<string, string>();
+  for (const file of files) contents.set(file, await fs.readFile(file, "utf8"));
+  return buildSubstrateProject(graph, contents);
+}
+
+/** Unguarded `+`/`-` on balances in the value-moving functions of one ink! contract or pallet file. */
+export function scanBalanceArithmetic(scannerId: string, file: string, content: string, functions: RustFunction[]): Finding[] {
+  const findings: Finding[] = [];
+  for (const fn of functions.filter((candidate) => VALUE_FLOW.test(candidate.name))) {
+    const seen = new Set<number>();
+    for (const op of uncheckedBalanceArithmetic(fn.body)) {
+      const line = bodyLine(content, fn, op.offset);
+      if (seen.has(line)) continue;
+      seen.add(line);
+      const underflow = op.operator === "-";
+      findings.push(
+        makeFinding({
+          scannerId,
+          vulnClass: "integer_overflow",
+          severity: underflow ? "HIGH" : "MEDIUM",
+          confidence: underflow ? 62 : 56,
+          file,
+          line,
+          title: underflow ? "Unchecked balance subtraction" : "Unchecked balance addition",
+          description:
+            `\`${fn.name}\` computes \`${op.expression}\` with plain arithmetic and no prior bound check. ` +
+            (underflow
+              ? "If the amount exceeds the balance the result wraps to a huge value in builds without overflow checks, minting value out of nothing; "
+              : "A large enough amount wraps the total in builds without overflow checks; ") +
+            "use `checked_sub`/`checked_add` (or the `Currency` traits) and return an error instead.",
+          evidence: evidenceLine(content, line),
+          instruction: fn.name
+        })
+      );
+    }
+  }
+  return findings;
+}
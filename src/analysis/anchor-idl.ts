@@ -0,0 +1,160 @@
+/**
+ * Anchor IDL (`target/idl/*.json`) as an analysis input.
+ *
+ * The IDL is what clients build transactions from, so it states each instruction's account
+ * list with signer/writable flags and PDA seeds independently of how the source spells them.
+ * Both the legacy format (`isMut`/`isSigner`, camelCase names) and the Anchor 0.30+ format
+ * (`writable`/`signer`, `metadata.name`) are normalised to snake_case source names.
+ */
+
+import { promises as fs } from "node:fs";
+import path from "node:path";
+import { escapeRegExp, lineAt, lineStarts } from "./rust-source";
+
+export type IdlSeedKind = "const" | "arg" | "account";
+
+export interface IdlSeed {
+  kind: IdlSeedKind;
+  /** Root argument or account name for `arg`/`account` seeds, e.g. `params` in `params.id` */
+  path?: string;
+}
+
+export interface IdlAccountItem {
+  name: string;
+  writable: boolean;
+  signer: boolean;
+  optional: boolean;
+  /** Fixed address, e.g. a program or sysvar id */
+  address?: string;
+  seeds?: IdlSeed[];
+}
+
+export interface IdlInstruction {
+  name: string;
+  /** Line of the instruction's entry in the IDL file */
+  line: number;
+  /** Flattened account list; composite `Accounts` groups are inlined */
+  accounts: IdlAccountItem[];
+  args: string[];
+}
+
+export interface AnchorIdl {
+  file: string;
+  programName: string;
+  address?: string;
+  instructions: IdlInstruction[];
+  /** Names of the program's `#[account]` types */
+  accountTypes: string[];
+}
+
+type Json = Record<string, unknown>;
+
+function isObject(value: unknown): value is Json {
+  return typeof value === "object" && value !== null && !Array.isArray(value);
+}
+
+function asArray(value: unknown): unknown[] {
+  return Array.isArray(value) ? value : [];
+}
+
+/** `initializeVault` -> `initialize_vault`; snake_case names pass through unchanged. */
+export function toSnakeCase(name: string): string {
+  return name.replace(/([a-z0-9])([A-Z])/g, "$1_$2").replace(/([A-Z]+)([A-Z][a-z])/g, "$1_$2").toLowerCase();
+}
+
+function parseSeeds(pda: unknown): IdlSeed[] | undefined {
+  if (!isObject(pda)) return undefined;
+  const seeds: IdlSeed[] = [];
+  for (const seed of asArray(pda.seeds)) {
+    if (!isObject(seed)) continue;
+    const kind = seed.kind;
+    if (kind !== "const" && kind !== "arg" && kind !== "account") continue;
+    const root = typeof seed.path === "string" ? toSnakeCase(seed.path.split(".")[0]) : undefined;
+    seeds.push({ kind, path: root });
+  }
+  return seeds;
+}
+
+function parseAccounts(items: unknown[]): IdlAccountItem[] {
+  const accounts: IdlAccountItem[] = [];
+  for (const item of items) {
+    if (!isObject(item) || typeof item.name !== "string") continue;
+    if (Array.isArray(item.accounts)) {
+      accounts.push(...parseAccounts(item.accounts));
+      continue;
+    }
+    accounts.push({
+      name: toSnakeCase(item.name),
+      writable: item.writable === true || item.isMut === true,
+      signer: item.signer === true || item.isSigner === true,
+      optional: item.optional === true || item.isOptional === true,
+      address: typeof item.address === "string" ? item.address : undefined,
+      seeds: parseSeeds(item.pda)
+    });
+  }
+  return accounts;
+}
+
+/** Parse an IDL document; returns `undefined` for JSON that is not an Anchor IDL. */
+export function parseAnchorIdl(raw: string, file: string): AnchorIdl | undefined {
+  let doc: unknown;
+  try {
+    doc = JSON.parse(raw);
+  } catch {
+    return undefined;
+  }
+  if (!isObject(doc) || !Array.isArray(doc.instructions)) return undefined;
+
+  const metadata = isObject(doc.metadata) ? doc.metadata : {};
+  const name = typeof metadata.name === "string" ? metadata.name : typeof doc.name === "string" ? doc.name : undefined;
+  if (!name) return undefined;
+  const address =
+    typeof doc.address === "string" ? doc.address : typeof metadata.address === "string" ? metadata.address : undefined;
+
+  const starts = lineStarts(raw);
+  let cursor = Math.max(raw.indexOf('"instructions"'), 0);
+  const instructions: IdlInstruction[] = [];
+  for (const instruction of doc.instructions) {
+    if (!isObject(instruction) || typeof instruction.name !== "string") continue;
+    const entry = new RegExp(`"name"\\s*:\\s*"${escapeRegExp(instruction.name)}"`).exec(raw.slice(cursor));
+    const line = entry ? lineAt(starts, cursor + entry.index) : 1;
+    if (entry) cursor += entry.index + entry[0].length;
+    instructions.push({
+      name: toSnakeCase(instruction.name),
+      line,
+      accounts: parseAccounts(asArray(instruction.accounts)),
+      args: asArray(instruction.args)
+        .filter(isObject)
+        .map((arg) => arg.name)
+        .filter((argName): argName is string => typeof argName === "string")
+        .map(toSnakeCase)
+    });
+  }
+
+  const accountTypes = asArray(doc.accounts)
+    .filter(isObject)
+    .map((account) => account.name)
+    .filter((accountName): accountName is string => typeof accountName === "string");
+
+  return { file, programName: toSnakeCase(name), address, instructions, accountTypes };
+}
+
+/** Load every IDL under `<root>/target/idl`. Missing directories and unreadable files are skipped. */
+export async function loadAnchorIdls(rootPath: string): Promise<AnchorIdl[]> {
+  const idlDir = path.join(rootPath, "target", "idl");
+  let entries: string[];
+  try {
+    entries = await fs.readdir(idlDir);
+  } catch {
+    return [];
+  }
+
+  const idls: AnchorIdl[] = [];
+  for (const entry of entries.filter((name) => name.endsWith(".json")).sort()) {
+    const file = path.join(idlDir, entry);
+    const raw = await fs.readFile(file, "utf8").catch(() => undefined);
+    const idl = raw === undefined ? undefined : parseAnchorIdl(raw, file);
+    if (idl) idls.push(idl);
+  }
+  return idls;
+}
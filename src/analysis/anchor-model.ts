@@ -0,0 +1,353 @@
+/**
+ * Structured model of Anchor `#[derive(Accounts)]` structs.
+ *
+ * Scanners query this model instead of matching single lines, so a check on one
+ * field is never satisfied (or suppressed) by text belonging to a neighbouring field.
+ */
+
+import { extractFunctions, extractModules, type RustFunction, type RustParam } from "./rust-items";
+import { lineAt, lineStarts, matchingBracket, splitTopLevel, stripComments } from "./rust-source";
+
+export type AnchorAccountKind =
+  | "Signer"
+  | "Account"
+  | "AccountInfo"
+  | "UncheckedAccount"
+  | "Program"
+  | "AccountLoader"
+  | "SystemAccount"
+  | "InterfaceAccount"
+  | "Interface"
+  | "Sysvar"
+  | "Unknown";
+
+export interface AnchorConstraint {
+  /** Constraint key as written, e.g. `mut`, `has_one`, `seeds`, `token::mint` */
+  key: string;
+  /** Raw right-hand side for `key = value` constraints */
+  value?: string;
+  line: number;
+}
+
+export interface AnchorAccountField {
+  name: string;
+  line: number;
+  kind: AnchorAccountKind;
+  /** Type parameter of the wrapper, e.g. `Vault` in `Account<'info, Vault>` */
+  innerType?: string;
+  rawType: string;
+  boxed: boolean;
+  optional: boolean;
+  constraints: AnchorConstraint[];
+}
+
+export interface AnchorAccountsStruct {
+  name: string;
+  file: string;
+  line: number;
+  endLine: number;
+  fields: AnchorAccountField[];
+  /** Argument names declared through `#[instruction(...)]` */
+  instructionArgs: string[];
+}
+
+/** A handler inside a `#[program]` module, linked to the Accounts struct of its `Context<T>`. */
+export interface AnchorInstruction {
+  programModule: string;
+  name: string;
+  file: string;
+  line: number;
+  endLine: number;
+  /** Name of the `Context<T>` parameter, usually `ctx` */
+  contextParam: string;
+  accountsStruct?: string;
+  /** Instruction arguments after the context parameter */
+  args: RustParam[];
+  fn: RustFunction;
+  /** Crate the handler belongs to, once the crate graph is known */
+  crateName?: string;
+  /**
+   * The `#[program]` entry when it forwards `ctx` to a handler elsewhere, e.g.
+   * `instructions::deposit::handler(ctx, amount)`; `file`, `line` and `fn` then describe that handler.
+   */
+  entry?: { file: string; line: number; endLine: number };
+}
+
+const KNOWN_KINDS = new Set<AnchorAccountKind>([
+  "Signer",
+  "Account",
+  "AccountInfo",
+  "UncheckedAccount",
+  "Program",
+  "AccountLoader",
+  "SystemAccount",
+  "InterfaceAccount",
+  "Interface",
+  "Sysvar"
+]);
+
+function parseConstraints(args: string, offset: number, starts: number[]): AnchorConstraint[] {
+  const constraints: AnchorConstraint[] = [];
+  let cursor = 0;
+  for (const part of splitTopLevel(args)) {
+    cursor = args.indexOf(part, cursor);
+    const line = lineAt(starts, offset + cursor);
+    cursor += part.length;
+    let eq = -1;
+    for (let i = 0; i < part.length; i++) {
+      if (part[i] !== "=") continue;
+      const prev = part[i - 1];
+      const next = part[i + 1];
+      if (next === "=" || next === ">" || prev === "=" || prev === "!" || prev === "<" || prev === ">") continue;
+      eq = i;
+      break;
+    }
+    if (eq < 0) {
+      constraints.push({ key: part.replace(/\s+/g, ""), line });
+    } else {
+      constraints.push({
+        key: part.slice(0, eq).replace(/\s+/g, ""),
+        value: part.slice(eq + 1).trim(),
+        line
+      });
+    }
+  }
+  return constraints;
+}
+
+function parseFieldType(rawType: string): Pick<AnchorAccountField, "kind" | "innerType" | "boxed" | "optional"> {
+  let type = rawType.replace(/\s+/g, " ").trim();
+  let boxed = false;
+  let optional = false;
+
+  for (;;) {
+    const wrapper = type.match(/^(?:[\w:]+::)?(Box|Option)\s*<(.*)>$/);
+    if (!wrapper) break;
+    if (wrapper[1] === "Box") boxed = true;
+    else optional = true;
+    type = wrapper[2].trim();
+  }
+
+  const head = type.match(/^(?:[\w:]+::)?(\w+)\s*(?:<(.*)>)?$/);
+  if (!head) {
+    return { kind: "Unknown", boxed, optional };
+  }
+
+  const name = head[1] as AnchorAccountKind;
+  const kind = KNOWN_KINDS.has(name) ? name : "Unknown";
+  const typeArgs = head[2] ? splitTopLevel(head[2]).filter((arg) => !arg.startsWith("'")) : [];
+  return { kind, innerType: typeArgs[0], boxed, optional };
+}
+
+function parseInstructionArgs(args: string): string[] {
+  return splitTopLevel(args)
+    .map((part) => part.split(":")[0]?.trim())
+    .filter((name): name is string => Boolean(name));
+}
+
+/** Extract every `#[derive(Accounts)]` struct declared in a Rust source file. */
+export function extractAnchorAccounts(content: string, file: string): AnchorAccountsStruct[] {
+  const code = stripComments(content);
+  const starts = lineStarts(code);
+  const structs: AnchorAccountsStruct[] = [];
+  const derivePattern = /#\[\s*derive\s*\(([^\]]*)\)\s*\]/g;
+
+  for (const derive of code.matchAll(derivePattern)) {
+    if (!/\bAccounts\b/.test(derive[1])) continue;
+
+    // Collect further attributes between the derive and the struct keyword.
+    let cursor = (derive.index ?? 0) + derive[0].length;
+    const instructionArgs: string[] = [];
+    for (;;) {
+      const rest = code.slice(cursor);
+      const attr = rest.match(/^\s*#\[/);
+      if (!attr) break;
+      const open = cursor + attr[0].length - 1;
+      const close = matchingBracket(code, open);
+      if (close < 0) break;
+      const body = code.slice(open + 1, close).trim();
+      const instruction = body.match(/^instruction\s*\(([\s\S]*)\)$/);
+      if (instruction) instructionArgs.push(...parseInstructionArgs(instruction[1]));
+      cursor = close + 1;
+    }
+
+    const header = code.slice(cursor).match(/^\s*(?:pub(?:\s*\([^)]*\))?\s+)?struct\s+(\w+)[^{;]*/);
+    if (!header) continue;
+    const nameOffset = cursor + header[0].indexOf(header[1]);
+    const openBrace = cursor + header[0].length;
+    if (code[openBrace] !== "{") {
+      // Unit struct: `pub struct Empty;`
+      structs.push({
+        name: header[1],
+        file,
+        line: lineAt(starts, nameOffset),
+        endLine: lineAt(starts, nameOffset),
+        fields: [],
+        instructionArgs
+      });
+      continue;
+    }
+    const closeBrace = matchingBracket(code, openBrace);
+    if (closeBrace < 0) continue;
+
+    structs.push({
+      name: header[1],
+      file,
+      line: lineAt(starts, nameOffset),
+      endLine: lineAt(starts, closeBrace),
+      fields: parseFields(code, openBrace + 1, closeBrace, starts),
+      instructionArgs
+    });
+  }
+
+  return structs;
+}
+
+function parseFields(code: string, start: number, end: number, starts: number[]): AnchorAccountField[] {
+  const fields: AnchorAccountField[] = [];
+  let pending: AnchorConstraint[] = [];
+  let i = start;
+
+  while (i < end) {
+    const ch = code[i];
+    if (/\s|,/.test(ch)) {
+      i++;
+      continue;
+    }
+
+    if (ch === "#" && code[i + 1] === "[") {
+      const close = matchingBracket(code, i + 1);
+      if (close < 0 || close > end) break;
+      const attr = code.slice(i + 2, close);
+      const account = attr.match(/^\s*account\s*\(([\s\S]*)\)\s*$/);
+      if (account) {
+        pending.push(...parseConstraints(account[1], i + 2 + attr.indexOf(account[1]), starts));
+      }
+      i = close + 1;
+      continue;
+    }
+
+    // Field declaration runs until the next top-level comma.
+    let depth = 0;
+    let j = i;
+    for (; j < end; j++) {
+      const c = code[j];
+      if (c === "<" || c === "(" || c === "[") depth++;
+      else if ((c === ">" && code[j - 1] !== "-") || c === ")" || c === "]") depth--;
+      else if (c === "," && depth === 0) break;
+    }
+    const decl = code.slice(i, j);
+    const field = decl.match(/^(?:pub(?:\s*\([^)]*\))?\s+)?(\w+)\s*:\s*([\s\S]+)$/);
+    if (field) {
+      const rawType = field[2].replace(/\s+/g, " ").trim();
+      fields.push({
+        name: field[1],
+        line: lineAt(starts, i + decl.indexOf(field[1])),
+        rawType,
+        ...parseFieldType(rawType),
+        constraints: pending
+      });
+    }
+    pending = [];
+    i = j + 1;
+  }
+
+  return fields;
+}
+
+export function getConstraint(field: AnchorAccountField, key: string): AnchorConstraint | undefined {
+  return field.constraints.find((constraint) => constraint.key === key);
+}
+
+export function hasConstraint(field: AnchorAccountField, key: string): boolean {
+  return getConstraint(field, key) !== undefined;
+}
+
+export function isSignerField(field: AnchorAccountField): boolean {
+  return field.kind === "Signer" || hasConstraint(field, "signer");
+}
+
+export function isMutableField(field: AnchorAccountField): boolean {
+  return hasConstraint(field, "mut") || hasConstraint(field, "init") || hasConstraint(field, "init_if_needed");
+}
+
+/** Raw accounts that Anchor performs no owner, type or discriminator validation on. */
+export function isUncheckedField(field: AnchorAccountField): boolean {
+  return field.kind === "AccountInfo" || field.kind === "UncheckedAccount";
+}
+
+/** Deserialized, program-owned data accounts (`Account<T>`, `AccountLoader<T>`, `InterfaceAccount<T>`). */
+export function isDataField(field: AnchorAccountField): boolean {
+  return field.kind === "Account" || field.kind === "AccountLoader" || field.kind === "InterfaceAccount";
+}
+
+/** Whether another field pins `fieldName` through `has_one = <field>` or a `constraint` on `<field>.key()`. */
+export function isBoundTo(struct: AnchorAccountsStruct, fieldName: string): boolean {
+  const keyRef = new RegExp(`\\b${fieldName}\\.key\\(\\)`);
+  return struct.fields.some((field) =>
+    field.constraints.some(
+      (constraint) =>
+        (constraint.key === "has_one" && constraint.value?.split("@")[0].trim() === fieldName) ||
+        (constraint.key === "constraint" && keyRef.test(constraint.value ?? ""))
+    )
+  );
+}
+
+export function findAccountField(
+  structs: AnchorAccountsStruct[],
+  fieldName: string
+): { struct: AnchorAccountsStruct; field: AnchorAccountField } | undefined {
+  for (const struct of structs) {
+    const field = struct.fields.find((candidate) => candidate.name === fieldName);
+    if (field) return { struct, field };
+  }
+  return undefined;
+}
+
+/** Resolve `T` from `Context<T>`, `Context<'_, '_, '_, 'info, T<'info>>` and similar. */
+export function contextAccountsStruct(type: string): string | undefined {
+  const context = type.match(/^(?:[\w:]+::)?Context\s*<(.*)>$/);
+  if (!context) return undefined;
+  const args = splitTopLevel(context[1]).filter((arg) => !arg.startsWith("'"));
+  return args[args.length - 1]?.match(/^(?:[\w:]+::)?(\w+)/)?.[1];
+}
+
+/** Extract the instruction handlers of every `#[program]` module in a Rust source file. */
+export function extractProgramInstructions(content: string, file: string): AnchorInstruction[] {
+  const programs = extractModules(content).filter((module) => module.isProgram);
+  if (programs.length === 0) return [];
+
+  const instructions: AnchorInstruction[] = [];
+  for (const fn of extractFunctions(content)) {
+    const program = programs.find((module) => fn.line > module.line && fn.endLine <= module.endLine);
+    if (!program || !fn.isPublic) continue;
+
+    const [context, ...args] = fn.params;
+    if (!context || !/\bContext\s*</.test(context.type)) continue;
+
+    instructions.push({
+      programModule: program.name,
+      name: fn.name,
+      file,
+      line: fn.line,
+      endLine: fn.endLine,
+      contextParam: context.name,
+      accountsStruct: contextAccountsStruct(context.type),
+      args,
+      fn
+    });
+  }
+
+  return instructions;
+}
+
+export function instructionsUsing(
+  instructions: AnchorInstruction[],
+  structName: string
+): AnchorInstruction[] {
+  return instructions.filter((instruction) => instruction.accountsStruct === structName);
+}
+
+export function instructionAt(instructions: AnchorInstruction[], line: number): AnchorInstruction | undefined {
+  return instructions.find((instruction) => line >= instruction.line && line <= instruction.endLine);
+}
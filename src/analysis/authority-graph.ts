@@ -0,0 +1,191 @@
+/**
+ * Authority map of Anchor programs.
+ *
+ * Nodes are instructions, account types, PDAs, signers and the programs invoked through CPI;
+ * edges are the constraints binding accounts together, the accounts feeding PDA seeds, the CPIs
+ * each handler makes (and the PDA signing them) and the accounts each handler writes.
+ */
+
+import type { AuthorityEdge, AuthorityEdgeKind, AuthorityGraph, AuthorityNode, AuthorityNodeKind } from "../types";
+import { getConstraint, type AnchorAccountField, type AnchorAccountsStruct } from "./anchor-model";
+import { seedLiterals, type PrivilegeModel } from "./privilege";
+
+/** Constraints whose value names another account of the struct the field is tied to. */
+const BINDING_CONSTRAINTS = [
+  "has_one",
+  "address",
+  "token::mint",
+  "token::authority",
+  "associated_token::mint",
+  "associated_token::authority",
+  "mint::authority",
+  "mint::freeze_authority"
+];
+/** Program behind a CPI builder module, for calls that do not pass a program account. */
+const BUILDER_PROGRAM: Record<string, string> = {
+  token: "Token",
+  token_interface: "TokenInterface",
+  token_2022: "Token2022",
+  system_program: "System",
+  system_instruction: "System",
+  associated_token: "AssociatedToken"
+};
+const MAX_BOUNDARIES = 24;
+
+/** Seeds as a short label: `[b"vault", user]` for `&[&[b"vault", user.key().as_ref()]]`. */
+function compactSeeds(seeds: string): string {
+  return seeds
+    .replace(/^\s*&?\s*\[\s*&?\s*\[/, "[")
+    .replace(/\]\s*\]\s*$/, "]")
+    .replace(/\s*\.\s*(?:key|as_ref|as_bytes|as_slice|to_le_bytes|to_be_bytes)\s*\(\s*\)/g, "")
+    .replace(/\s+/g, " ")
+    .trim();
+}
+
+class GraphBuilder {
+  private readonly nodes = new Map<string, AuthorityNode>();
+  private readonly edges = new Map<string, AuthorityEdge>();
+  /** PDA nodes by crate, with the literal seeds that identify them to CPI signatures. */
+  readonly pdas: { id: string; crate: string; literals: string }[] = [];
+
+  has(key: string): boolean {
+    return this.nodes.has(key);
+  }
+
+  node(key: string, kind: AuthorityNodeKind, label: string, crate?: string): string {
+    const existing = this.nodes.get(key);
+    if (existing) return existing.id;
+    const id = `n${this.nodes.size}`;
+    this.nodes.set(key, { id, kind, label, ...(crate ? { crate } : {}) });
+    return id;
+  }
+
+  edge(from: string, to: string, kind: AuthorityEdgeKind, label: string): void {
+    const key = `${from}|${to}|${kind}|${label}`;
+    if (!this.edges.has(key)) this.edges.set(key, { from, to, kind, label });
+  }
+
+  /** Accounts the handlers only read, with no constraint or seed tying them to anything, are left out. */
+  build(): AuthorityGraph {
+    const edges = [...this.edges.values()];
+    const linked = new Set(edges.flatMap((edge) => [edge.from, edge.to]));
+    return { nodes: [...this.nodes.values()].filter((node) => linked.has(node.id)), edges };
+  }
+}
+
+function fieldNode(graph: GraphBuilder, field: AnchorAccountField, crate: string): string {
+  if (field.kind === "Signer") return graph.node(`signer:${crate}:${field.name}`, "signer", field.name, crate);
+
+  const seeds = getConstraint(field, "seeds");
+  if (seeds?.value && !getConstraint(field, "seeds::program")) {
+    const compact = compactSeeds(seeds.value);
+    const key = `pda:${crate}:${compact}`;
+    const fresh = !graph.has(key);
+    const id = graph.node(key, "pda", `${field.innerType ?? field.name} ${compact}`, crate);
+    if (fresh) graph.pdas.push({ id, crate, literals: seedLiterals(seeds.value).join(",") });
+    return id;
+  }
+
+  if (field.kind === "Program" || field.kind === "Interface") {
+    const program = field.innerType ?? field.name;
+    return graph.node(`program:${program}`, "program", program);
+  }
+  if (field.innerType && ["Account", "AccountLoader", "InterfaceAccount", "Sysvar"].includes(field.kind)) {
+    return graph.node(`type:${crate}:${field.innerType}`, "account_type", field.innerType, crate);
+  }
+  const unchecked = field.kind === "UncheckedAccount" || field.kind === "AccountInfo";
+  return graph.node(`account:${crate}:${field.name}`, "account_type", unchecked ? `${field.name} (unchecked)` : field.name, crate);
+}
+
+function bindingTarget(struct: AnchorAccountsStruct, value: string): AnchorAccountField | undefined {
+  // `has_one = admin @ Error::Unauthorized`, `address = config.auditor`, `token::mint = mint`
+  const name = value.trim().match(/^(\w+)/)?.[1];
+  return name ? struct.fields.find((field) => field.name === name) : undefined;
+}
+
+function cpiProgram(graph: GraphBuilder, model: PrivilegeModel, operation: string, programField?: string): string {
+  const field = programField ? model.struct.fields.find((candidate) => candidate.name === programField) : undefined;
+  if (field) return fieldNode(graph, field, model.instruction.crateName ?? "");
+  const builder = operation.includes("::") ? operation.split("::")[0] : undefined;
+  const program = builder ? BUILDER_PROGRAM[builder] : undefined;
+  return program
+    ? graph.node(`program:${program}`, "program", program)
+    : graph.node("program:?", "program", "unresolved program");
+}
+
+/** Build the authority graph of a program (or workspace) from the per-instruction privilege models. */
+export function buildAuthorityGraph(models: PrivilegeModel[]): AuthorityGraph {
+  const graph = new GraphBuilder();
+
+  for (const model of models) {
+    const { instruction, struct } = model;
+    const crate = instruction.crateName ?? "";
+    const ix = graph.node(
+      `ix:${crate}:${instruction.programModule}::${instruction.name}`,
+      "instruction",
+      `${instruction.programModule}::${instruction.name}`,
+      crate
+    );
+
+    for (const [index, field] of struct.fields.entries()) {
+      const use = model.fields[index];
+      const node = fieldNode(graph, field, crate);
+      if (use.signer) graph.edge(node, ix, "authorizes", use.authorizes ? "authorizes" : "signs, unused");
+      if (use.mutable && use.written) {
+        const lifecycle = ["init", "init_if_needed", "close", "realloc"].find((key) => getConstraint(field, key));
+        graph.edge(ix, node, "mutates", lifecycle ?? "writes");
+      }
+
+      for (const constraint of field.constraints) {
+        if (!BINDING_CONSTRAINTS.includes(constraint.key) || !constraint.value) continue;
+        const target = bindingTarget(struct, constraint.value);
+        if (target && target !== field) graph.edge(node, fieldNode(graph, target, crate), "constraint", constraint.key);
+      }
+
+      const seeds = getConstraint(field, "seeds")?.value;
+      if (seeds && !getConstraint(field, "seeds::program")) {
+        for (const source of struct.fields) {
+          if (source !== field && new RegExp(`(?<![\\w.])${source.name}\\b`).test(seeds)) {
+            graph.edge(fieldNode(graph, source, crate), node, "seeds", "seed");
+          }
+        }
+      }
+    }
+
+    for (const cpi of model.cpis) {
+      const program = cpiProgram(graph, model, cpi.operation, cpi.programField);
+      graph.edge(ix, program, "cpi", cpi.operation);
+      if (!cpi.signature) continue;
+      const literals = cpi.signature.literals.join(",");
+      const pda =
+        graph.pdas.find((candidate) => candidate.crate === crate && candidate.literals === literals)?.id ??
+        graph.node(`pda:${crate}:${compactSeeds(cpi.signature.seeds)}`, "pda", `PDA ${compactSeeds(cpi.signature.seeds)}`, crate);
+      graph.edge(pda, program, "signs", cpi.operation);
+    }
+  }
+
+  return graph.build();
+}
+
+/** Trust boundaries the graph makes explicit: signer authority, PDA signing authority and CPI targets. */
+export function authorityBoundaries(graph: AuthorityGraph): string[] {
+  const byId = new Map(graph.nodes.map((node) => [node.id, node] as const));
+  const grouped = new Map<string, Set<string>>();
+  const add = (key: string, value: string): void => {
+    grouped.set(key, (grouped.get(key) ?? new Set()).add(value));
+  };
+
+  for (const edge of graph.edges) {
+    const from = byId.get(edge.from);
+    const to = byId.get(edge.to);
+    if (!from || !to) continue;
+    if (edge.kind === "authorizes" && edge.label === "authorizes") add(`Signer ${from.label} -> `, to.label);
+    if (edge.kind === "signs") add(`PDA ${from.label} -> ${to.label} CPI: `, edge.label);
+    if (edge.kind === "cpi") add(`Program -> ${to.label} via CPI from `, from.label);
+  }
+
+  return [...grouped.entries()]
+    .map(([prefix, values]) => `${prefix}${[...values].sort().join(", ")}`)
+    .sort()
+    .slice(0, MAX_BOUNDARIES);
+}
@@ -0,0 +1,149 @@
+/**
+ * Structured model of CosmWasm contracts built on `cosmwasm-std`.
+ *
+ * A contract exposes fixed entry points (`instantiate`, `execute`, `query`, `migrate`, `reply`,
+ * `sudo`) and `execute` usually dispatches each `ExecuteMsg` variant to a handler function.
+ * Routes here pair every variant with the code that runs for it, so checks can ask what a
+ * variant does (storage writes, funds moved, submessages) and whether it looks at `info.sender`.
+ * Singleton `Item` storage holds contract-wide configuration; writing one is a privileged action.
+ */
+
+import { crateIdent, type RustCrateGraph } from "./rust-crates";
+import { bodyLine, isRustTestFile, productionFunctions, type RustFunction } from "./rust-items";
+import { escapeRegExp, stripComments } from "./rust-source";
+
+export type CosmwasmEntryPointName = "instantiate" | "execute" | "query" | "migrate" | "reply" | "sudo";
+
+export interface ContractFunction {
+  file: string;
+  fn: RustFunction;
+}
+
+export interface CosmwasmEntryPoint extends ContractFunction {
+  name: CosmwasmEntryPointName;
+}
+
+/** Code that runs for one `ExecuteMsg` variant. */
+export interface ExecuteRoute {
+  variant: string;
+  file: string;
+  /** Line of the `ExecuteMsg::Variant` match arm */
+  line: number;
+  /** Function whose body holds the code: the handler, or `execute` itself for inline arms */
+  target: ContractFunction;
+  /** Range of `target.fn.body` belonging to the route (the whole body for a handler) */
+  start: number;
+  end: number;
+  /** Whether `execute` checks the sender before dispatching */
+  guarded: boolean;
+}
+
+export interface CosmwasmContract {
+  graph: RustCrateGraph;
+  contents: Map<string, string>;
+  /** Non-test functions of every contract file */
+  functions: ContractFunction[];
+  entryPoints: CosmwasmEntryPoint[];
+  routes: ExecuteRoute[];
+  /** Names of `Item` storage constants */
+  items: Set<string>;
+}
+
+const ENTRY_POINTS = new Set<string>(["instantiate", "execute", "query", "migrate", "reply", "sudo"]);
+const ITEM_DECL = /\b(?:const|static)\s+(\w+)\s*:\s*Item\s*</g;
+const ARM = /\bExecuteMsg\s*::\s*(\w+)\s*(?:\{[^{}]*\}|\([^()]*\))?\s*(?:if\b[^=]*)?=>/g;
+/** Storage writes: `CONFIG.save(..)`, `BALANCES.update(..)`, `PENDING.remove(..)`. */
+const STORAGE_WRITE = /\b(\w+)\s*\.\s*(save|update|remove)\s*\(\s*[^,()]*\bstorage\b/g;
+/** Checks that name the caller: a comparison against `info.sender` or a helper handed the whole `MessageInfo`. */
+const AUTH_HELPER = /\b\w*(?:owner|admin|auth|only|permission|role|allowlist|whitelist|operator)\w*\s*\([^;]*\binfo\b/i;
+
+/** Whether a `Cargo.toml` depends on `cosmwasm-std`. */
+export function isCosmwasmManifest(text: string): boolean {
+  return /^\s*cosmwasm[-_]std\s*=|^\s*\[(?:\w+\.)?dependencies\.cosmwasm[-_]std\]/m.test(text);
+}
+
+function entryPointName(fn: RustFunction): CosmwasmEntryPointName | undefined {
+  if (!ENTRY_POINTS.has(fn.name)) return undefined;
+  return fn.params.some((param) => /\bEnv\b/.test(param.type)) ? (fn.name as CosmwasmEntryPointName) : undefined;
+}
+
+/** Name of the `MessageInfo` parameter of a handler, if it takes one. */
+export function infoParam(fn: RustFunction): string | undefined {
+  return fn.params.find((param) => /\bMessageInfo\b/.test(param.type))?.name;
+}
+
+/** Crate functions `code` calls by name (`update_config(..)`, `execute::update_config(..)`, `Self::x(..)`). */
+export function calledFunctions(code: string, functions: ContractFunction[], exclude?: RustFunction): ContractFunction[] {
+  const names = new Set([...code.matchAll(/\b(\w+)\s*\(/g)].map((match) => match[1]));
+  return functions.filter((candidate) => candidate.fn !== exclude && names.has(candidate.fn.name));
+}
+
+/**
+ * Whether `code` authenticates the caller: it reads `<info>.sender`, hands `info` to an
+ * owner/admin helper, or calls a crate helper with `info` that reads `.sender` itself.
+ */
+export function checksSender(code: string, info: string | undefined, functions: ContractFunction[]): boolean {
+  if (/\.\s*sender\b/.test(code)) return true;
+  if (!info || info.startsWith("_")) return false;
+  if (AUTH_HELPER.test(code.replace(new RegExp(`\\b${escapeRegExp(info)}\\b`, "g"), "info"))) return true;
+  const passesInfo = new RegExp(`\\b(\\w+)\\s*\\([^;]*\\b${escapeRegExp(info)}\\b`, "g");
+  const helpers = new Set([...code.matchAll(passesInfo)].map((match) => match[1]));
+  return functions.some((candidate) => helpers.has(candidate.fn.name) && /\.\s*sender\b/.test(candidate.fn.body));
+}
+
+function executeRoutes(entry: CosmwasmEntryPoint, functions: ContractFunction[], content: string): ExecuteRoute[] {
+  const body = entry.fn.body;
+  const arms = [...body.matchAll(ARM)];
+  if (arms.length === 0) return [];
+  const guarded = /\.\s*sender\b/.test(body.slice(0, arms[0].index ?? 0));
+
+  return arms.map((arm, index) => {
+    const start = (arm.index ?? 0) + arm[0].length;
+    const end = index + 1 < arms.length ? arms[index + 1].index ?? body.length : body.length;
+    const line = bodyLine(content, entry.fn, arm.index ?? 0);
+    // `ExecuteMsg::X { .. } => execute_x(deps, info, ..)`: the first call to a crate function is the handler.
+    const call = body.slice(start, end).match(/^\s*\{?\s*(?:\w+\s*::\s*)*(\w+)\s*\(/);
+    const handler = call ? functions.find((candidate) => candidate.fn.name === call[1] && candidate.fn !== entry.fn) : undefined;
+    return handler
+      ? { variant: arm[1], file: entry.file, line, target: handler, start: 0, end: handler.fn.body.length, guarded }
+      : { variant: arm[1], file: entry.file, line, target: entry, start, end, guarded };
+  });
+}
+
+/** Entry points, execute routes and storage of the `cosmwasm-std` crates among `contents`. */
+export function buildCosmwasmContract(graph: RustCrateGraph, contents: Map<string, string>): CosmwasmContract {
+  const contractCrates = new Set(
+    graph.crates.filter((crate) => crate.dependencies.some((dependency) => crateIdent(dependency) === "cosmwasm_std")).map((crate) => crate.name)
+  );
+  const inContract = (file: string): boolean =>
+    !isRustTestFile(file) && (contractCrates.size === 0 || contractCrates.has(graph.modules.get(file)?.crate ?? ""));
+
+  const functions: ContractFunction[] = [];
+  const items = new Set<string>();
+  for (const [file, content] of contents) {
+    if (!inContract(file)) continue;
+    for (const fn of productionFunctions(content)) functions.push({ file, fn });
+    for (const decl of stripComments(content).matchAll(ITEM_DECL)) items.add(decl[1]);
+  }
+
+  const entryPoints: CosmwasmEntryPoint[] = [];
+  for (const candidate of functions) {
+    const name = entryPointName(candidate.fn);
+    if (name) entryPoints.push({ ...candidate, name });
+  }
+  const routes = entryPoints
+    .filter((entry) => entry.name === "execute")
+    .flatMap((entry) => executeRoutes(entry, functions, contents.get(entry.file) ?? ""));
+
+  return { graph, contents, functions, entryPoints, routes, items };
+}
+
+/** Source of a route: the handler body, or the arm of `execute` for inline arms. */
+export function routeCode(route: ExecuteRoute): string {
+  return route.target.fn.body.slice(route.start, route.end);
+}
+
+/** Storage writes in `code`, with their offset and the storage constant written. */
+export function storageWrites(code: string): { offset: number; store: string; operation: string }[] {
+  return [...code.matchAll(STORAGE_WRITE)].map((match) => ({ offset: match.index ?? 0, store: match[1], operation: match[2] }));
+}
@@ -0,0 +1,134 @@
+/**
+ * Per-function ordering of account mutations, cross-program invocations and reloads.
+ *
+ * Events are listed in source order within a single function body, so callers can
+ * reason about "mutated before CPI" without mixing up unrelated handlers in one file.
+ */
+
+import { findAccountField, type AnchorAccountsStruct } from "./anchor-model";
+import { bodyLine, type RustFunction } from "./rust-items";
+import { matchingBracket } from "./rust-source";
+
+export type CpiFlowEventKind = "borrow_mut" | "write" | "cpi" | "reload";
+
+export interface CpiFlowEvent {
+  kind: CpiFlowEventKind;
+  /** Account the event touches; for `cpi` events, the program account or builder used */
+  account?: string;
+  line: number;
+  /** Offset within the function body */
+  offset: number;
+  text: string;
+  /** For `cpi` events: whether the callee is a fixed program that cannot call back */
+  controlled?: boolean;
+  /** For `cpi` events: the full call text, used to see which accounts are passed along */
+  callText?: string;
+}
+
+/** Programs that never re-enter the caller: System, legacy SPL Token and the ATA program. */
+const KNOWN_PROGRAM =
+  /system_instruction::|system_program::(?:id|ID)\b|spl_token::instruction::|spl_token::id\(\)|token::ID\b|spl_associated_token_account::|associated_token::id\(\)/;
+const KNOWN_PROGRAM_TYPE = /^(System|Token|AssociatedToken)$/;
+
+const ALIAS = /\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=]+)?=\s*&\s*mut\s+ctx\.accounts\.(\w+)\s*;/g;
+const BORROW_MUT =
+  /\b(\w+)(?:\.to_account_info\(\))?\s*\.\s*(?:try_borrow_mut_data|try_borrow_mut_lamports)\s*\(|\b(\w+)\.(?:data|lamports)\.borrow_mut\s*\(/g;
+const WRITE = /(?:\bctx\.accounts\.(\w+)|\b(\w+))\.(\w+)(?:\.\w+)*\s*(?:[+\-*/|&^]|<<|>>)?=(?![=>])/g;
+const INVOKE = /\binvoke(?:_signed)?\s*\(/g;
+const CPI_CONTEXT = /\bCpiContext::new(?:_with_signer)?\s*\(/g;
+const RELOAD = /(?:\bctx\.accounts\.(\w+)|\b(\w+))\.reload\s*\(/g;
+
+function callText(body: string, openParen: number): string {
+  const close = matchingBracket(body, openParen);
+  return body.slice(openParen + 1, close < 0 ? body.length : close);
+}
+
+/** Resolve the expression behind `invoke(&ix, ...)`: either inline or a `let ix = ...;` binding. */
+function instructionBuilder(body: string, args: string): string {
+  const first = args.split(",")[0]?.replace(/[&\s]/g, "") ?? "";
+  if (!/^\w+$/.test(first)) return args;
+  const binding = body.match(new RegExp(`\\blet\\s+(?:mut\\s+)?${first}\\b[^=]*=([\\s\\S]*?);`));
+  return binding ? `${binding[1]} ${args}` : args;
+}
+
+function isControlledProgramExpr(expr: string, structs: AnchorAccountsStruct[]): boolean {
+  if (KNOWN_PROGRAM.test(expr)) return true;
+  const account = expr.match(/ctx\.accounts\.(\w+)/)?.[1];
+  if (!account) return false;
+  const resolved = findAccountField(structs, account);
+  if (!resolved) return false;
+  const { field } = resolved;
+  return field.kind === "Program" && KNOWN_PROGRAM_TYPE.test(field.innerType ?? "");
+}
+
+/**
+ * Ordered mutation / CPI / reload events of one function body, matched against `code` (the file with
+ * only code regions kept) so string literals such as log messages cannot pose as calls or writes.
+ * `structs` are the Accounts structs `ctx.accounts` may refer to, ideally just the handler's own.
+ */
+export function extractCpiFlow(
+  content: string,
+  code: string,
+  fn: RustFunction,
+  structs: AnchorAccountsStruct[]
+): CpiFlowEvent[] {
+  const body = code.slice(fn.bodyOffset, fn.bodyOffset + fn.body.length);
+  const aliases = new Map<string, string>();
+  for (const alias of body.matchAll(ALIAS)) aliases.set(alias[1], alias[2]);
+  const resolve = (name: string): string => aliases.get(name) ?? name;
+
+  const events: CpiFlowEvent[] = [];
+  const push = (event: Omit<CpiFlowEvent, "line">): void => {
+    events.push({ ...event, line: bodyLine(content, fn, event.offset) });
+  };
+
+  for (const match of body.matchAll(BORROW_MUT)) {
+    const account = match[1] ?? match[2];
+    push({ kind: "borrow_mut", account: resolve(account), offset: match.index ?? 0, text: match[0].trim() });
+  }
+
+  for (const match of body.matchAll(WRITE)) {
+    const direct = match[1];
+    const local = match[2];
+    // Only writes through ctx.accounts.<name> or a `&mut ctx.accounts.<name>` alias are account state.
+    if (!direct && !(local && aliases.has(local))) continue;
+    push({
+      kind: "write",
+      account: direct ?? resolve(local),
+      offset: match.index ?? 0,
+      text: match[0].replace(/\s*(?:[+\-*/|&^]|<<|>>)?=$/, "").trim()
+    });
+  }
+
+  for (const match of body.matchAll(INVOKE)) {
+    const offset = match.index ?? 0;
+    const args = callText(body, offset + match[0].length - 1);
+    push({
+      kind: "cpi",
+      offset,
+      text: match[0].replace(/\s*\($/, "()"),
+      controlled: KNOWN_PROGRAM.test(instructionBuilder(body, args)),
+      callText: args
+    });
+  }
+
+  for (const match of body.matchAll(CPI_CONTEXT)) {
+    const offset = match.index ?? 0;
+    const args = callText(body, offset + match[0].length - 1);
+    const program = args.split(",")[0]?.trim() ?? "";
+    push({
+      kind: "cpi",
+      account: program.match(/ctx\.accounts\.(\w+)/)?.[1],
+      offset,
+      text: match[0].replace(/\s*\($/, "()"),
+      controlled: isControlledProgramExpr(program, structs),
+      callText: args
+    });
+  }
+
+  for (const match of body.matchAll(RELOAD)) {
+    push({ kind: "reload", account: resolve(match[1] ?? match[2]), offset: match.index ?? 0, text: match[0].trim() });
+  }
+
+  return events.sort((a, b) => a.offset - b.offset);
+}
@@ -0,0 +1,171 @@
+/**
+ * Structured model of native (non-Anchor) Solana programs built on `solana_program`.
+ *
+ * Native processors receive `accounts: &[AccountInfo]` and pull accounts positionally with
+ * `next_account_info`, so the order of those calls is the instruction's account schema.
+ * The checks Anchor derives from constraints are written by hand (`is_signer`,
+ * `owner == program_id`, key comparisons); helpers here report which ones a processor performs.
+ */
+
+import path from "node:path";
+import { bodyLine, extractFunctions, type RustFunction } from "./rust-items";
+import { escapeRegExp, stripComments } from "./rust-source";
+
+export interface NativeAccount {
+  name: string;
+  /** Position in the instruction's account list, in `next_account_info` order */
+  index: number;
+  line: number;
+  /** Offset of the binding within the processor body */
+  offset: number;
+}
+
+export interface NativeDeserialization {
+  /** Account whose data is decoded */
+  account: string;
+  /** Decoded type, e.g. `Vault` in `Vault::try_from_slice(&vault.data.borrow())` */
+  type: string;
+  /** Decoder used: `try_from_slice`, `unpack`, `deserialize`, ... */
+  method: string;
+  line: number;
+  offset: number;
+}
+
+export interface NativeProcessor {
+  /** Source module the processor lives in (file stem), standing in for Anchor's `#[program]` module */
+  programModule: string;
+  name: string;
+  file: string;
+  line: number;
+  endLine: number;
+  /** Name of the `&[AccountInfo]` parameter, usually `accounts` */
+  accountsParam: string;
+  accounts: NativeAccount[];
+  deserializations: NativeDeserialization[];
+  fn: RustFunction;
+}
+
+/** `require_keys_eq!(a, b)`, `assert_eq!(a, b)` and friends compare their first two arguments. */
+const EQ_MACRO = "\\b(?:require_keys_eq|require_eq|assert_keys_eq|assert_eq)!?\\s*\\([^;]*";
+/** Helpers that compare an account's owner with the program id; `set_owner(..)` and the like do not. */
+const OWNER_HELPER = "\\b(?:assert_owned_by|assert_owner|check_account_owner|check_program_account)";
+
+const ACCOUNTS_PARAM = /^&\s*(?:'\w+\s+)?\[\s*AccountInfo\b/;
+const NEXT_ACCOUNT = /\blet\s+(\w+)\s*(?::[^=]+)?=\s*(?:next_account_info\s*\(|\w+\s*\.\s*next\s*\(\s*\))/g;
+const DESERIALIZE =
+  /\b(\w+)::(try_from_slice(?:_unchecked)?|unpack(?:_unchecked|_from_slice)?|deserialize|try_deserialize(?:_unchecked)?)\s*\(\s*(?:&\s*)?(?:mut\s+)?(?:&\s*)?(?:mut\s+)?(\w+)\s*\.\s*(?:data\s*\.\s*borrow\s*\(\s*\)|try_borrow_data\s*\(\s*\)\s*\??|data\b)/g;
+
+/** `process_instruction(program_id: &Pubkey, accounts: &[AccountInfo], ..)`, the shape the entrypoint dispatches to. */
+function isProcessorSignature(fn: RustFunction): boolean {
+  return (
+    fn.params.some((param) => /^&\s*(?:'\w+\s+)?Pubkey$/.test(param.type)) &&
+    fn.params.some((param) => ACCOUNTS_PARAM.test(param.type))
+  );
+}
+
+/**
+ * Whether a Rust source file belongs to a native `solana_program` program: it declares the
+ * entrypoint or a processor. `next_account_info` alone also shows up in Anchor helper modules.
+ */
+export function isNativeProgramSource(content: string): boolean {
+  return /\bentrypoint!\s*\(/.test(stripComments(content)) || extractFunctions(content).some(isProcessorSignature);
+}
+
+function destructuredAccounts(body: string, accountsParam: string): { name: string; offset: number }[] {
+  const match = body.match(new RegExp(`\\blet\\s+\\[([^\\]]*)\\]\\s*=\\s*(?:&\\s*)?\\*?${escapeRegExp(accountsParam)}\\b`));
+  if (!match) return [];
+  const offset = match.index ?? 0;
+  return match[1]
+    .split(",")
+    .map((part) => part.replace(/\bref\b|&/g, "").trim())
+    .map((name) => ({ name, offset }));
+}
+
+/** Names bound to entries of the `&[AccountInfo]` slice, in binding order. */
+export function nativeAccountNames(body: string, accountsParam: string): string[] {
+  return [
+    ...[...body.matchAll(NEXT_ACCOUNT)].map((match) => ({ name: match[1], offset: match.index ?? 0 })),
+    ...destructuredAccounts(body, accountsParam)
+  ]
+    .sort((a, b) => a.offset - b.offset)
+    .map((binding) => binding.name)
+    .filter((name) => /^[A-Za-z]\w*$/.test(name));
+}
+
+/** Extract the processors of a native program: functions that read accounts from an `&[AccountInfo]` slice. */
+export function extractNativeProcessors(content: string, file: string): NativeProcessor[] {
+  const processors: NativeProcessor[] = [];
+
+  for (const fn of extractFunctions(content)) {
+    const accountsParam = fn.params.find((param) => ACCOUNTS_PARAM.test(param.type))?.name;
+    if (!accountsParam) continue;
+
+    const bindings = [
+      ...[...fn.body.matchAll(NEXT_ACCOUNT)].map((match) => ({ name: match[1], offset: match.index ?? 0 })),
+      ...destructuredAccounts(fn.body, accountsParam)
+    ].sort((a, b) => a.offset - b.offset);
+    // `..` and `_` hold positions without naming an account.
+    const accounts: NativeAccount[] = [];
+    bindings.forEach((binding, index) => {
+      if (!/^[A-Za-z]\w*$/.test(binding.name)) return;
+      accounts.push({ ...binding, index, line: bodyLine(content, fn, binding.offset) });
+    });
+    if (accounts.length === 0) continue;
+
+    const names = new Set(accounts.map((account) => account.name));
+    const deserializations: NativeDeserialization[] = [];
+    for (const match of fn.body.matchAll(DESERIALIZE)) {
+      if (!names.has(match[3])) continue;
+      deserializations.push({
+        account: match[3],
+        type: match[1],
+        method: match[2],
+        offset: match.index ?? 0,
+        line: bodyLine(content, fn, match.index ?? 0)
+      });
+    }
+
+    processors.push({
+      programModule: path.basename(file, ".rs"),
+      name: fn.name,
+      file,
+      line: fn.line,
+      endLine: fn.endLine,
+      accountsParam,
+      accounts,
+      deserializations,
+      fn
+    });
+  }
+
+  return processors;
+}
+
+export function findNativeAccount(processor: NativeProcessor, name: string): NativeAccount | undefined {
+  return processor.accounts.find((account) => account.name === name);
+}
+
+/** `if !authority.is_signer { ... }`, or a helper such as `assert_signer(authority)`. */
+export function checksSigner(body: string, account: string): boolean {
+  const name = escapeRegExp(account);
+  return new RegExp(`\\b${name}\\.is_signer\\b|\\b\\w*signer\\w*\\s*\\(\\s*&?\\s*${name}\\b`).test(body);
+}
+
+/** `vault.owner != program_id`, `check_program_account(token.owner)`, or an owner helper such as `assert_owned_by(vault, ..)`. */
+export function checksOwner(body: string, account: string): boolean {
+  const name = escapeRegExp(account);
+  return new RegExp(
+    `\\b${name}\\.owner\\s*(?:==|!=)|(?:==|!=)\\s*&?\\s*\\*?\\s*${name}\\.owner\\b|\\b${name}\\.owner\\.eq\\s*\\(|${EQ_MACRO}\\b${name}\\.owner\\b|` +
+      `\\bcheck_program_account\\s*\\(\\s*&?\\s*\\*?\\s*${name}\\.owner\\b|${OWNER_HELPER}\\s*\\(\\s*&?\\s*${name}\\b`
+  ).test(body);
+}
+
+/** `*program.key != spl_token::id()`, `spl_token::check_id(program.key)` and similar pins of an account's address. */
+export function checksKey(body: string, account: string): boolean {
+  const name = escapeRegExp(account);
+  const key = `${name}\\.key(?:\\s*\\(\\s*\\))?`;
+  return new RegExp(
+    `\\*?\\s*\\b${key}\\s*(?:==|!=)|(?:==|!=)\\s*&?\\s*\\*?\\s*\\b${key}|\\b${key}\\.eq\\s*\\(|` +
+      `\\bcheck_(?:id|program_account)\\s*\\(\\s*&?\\s*\\*?\\s*${key}|${EQ_MACRO}\\b${key}`
+  ).test(body);
+}
@@ -0,0 +1,304 @@
+/**
+ * PDA seed schemas across a program.
+ *
+ * A PDA address hashes the concatenation of its seeds with the program ID, so seed boundaries
+ * do not survive hashing: `[b"ab", b"c"]` and `[b"a", b"bc"]` derive the same address. Each seed is
+ * classified as literal bytes, a fixed-width value or a variable-length slice, and derivations
+ * are compared as byte patterns to find account kinds whose addresses can coincide.
+ */
+
+import { getConstraint, type AnchorAccountsStruct } from "./anchor-model";
+import { extractFunctions, type RustParam } from "./rust-items";
+import { lineAt, lineStarts, matchingBracket, splitTopLevel, stripComments } from "./rust-source";
+
+export type SeedKind = "literal" | "fixed" | "variable" | "unknown";
+
+export interface SeedComponent {
+  text: string;
+  kind: SeedKind;
+  /** Byte content of a `literal` seed */
+  bytes?: string;
+  /** Width of a `fixed` seed, e.g. 32 for a pubkey or 8 for `u64::to_le_bytes()` */
+  width?: number;
+  /** Account, argument or field the seed is taken from, e.g. `owner` for `owner.key().as_ref()` */
+  root?: string;
+}
+
+export interface PdaSchema {
+  file: string;
+  line: number;
+  source: "anchor" | "manual";
+  /** `Struct.field` for Anchor seeds constraints, the enclosing function for manual derivations */
+  site: string;
+  /** Account data type when the derivation site declares it, e.g. `UserProfile` */
+  accountType?: string;
+  /** Accounts field of an Anchor seeds constraint */
+  accountField?: string;
+  seeds: SeedComponent[];
+}
+
+/** Derivations with the same seed signature address the same kind of account. */
+export interface PdaKind {
+  signature: string;
+  schemas: PdaSchema[];
+  accountTypes: string[];
+}
+
+/** Solana caps every seed at 32 bytes. */
+export const MAX_SEED_LEN = 32;
+
+const INT_WIDTH: Record<string, number> = {
+  u8: 1, i8: 1, u16: 2, i16: 2, u32: 4, i32: 4, u64: 8, i64: 8, u128: 16, i128: 16, usize: 8, isize: 8
+};
+const DERIVATION = /\b(find_program_address|create_program_address)\s*\(/g;
+const SEED_CONST = /\bconst\s+([A-Z][A-Z0-9_]*)\s*:\s*&(?:'static\s+)?(?:\[u8(?:\s*;\s*\d+)?\]|str)\s*=\s*b?"((?:[^"\\]|\\.)*)"/g;
+const STRUCT_FIELD = /\bpub\s+(\w+)\s*:\s*([^,\n}]+)/g;
+
+function unescapeBytes(text: string): string {
+  return text.replace(/\\x([0-9a-fA-F]{2})|\\(.)/g, (_, hex: string | undefined, ch: string | undefined) =>
+    hex ? String.fromCharCode(parseInt(hex, 16)) : ch === "n" ? "\n" : ch === "0" ? "\0" : (ch ?? "")
+  );
+}
+
+/** Byte-string constants such as `pub const USER_SEED: &[u8] = b"user";`. */
+export function extractSeedConstants(code: string): Map<string, string> {
+  const constants = new Map<string, string>();
+  for (const match of code.matchAll(SEED_CONST)) constants.set(match[1], unescapeBytes(match[2]));
+  return constants;
+}
+
+/** Declared types of struct fields by name, used to size seeds such as `pool.mint.as_ref()`. */
+export function extractFieldTypes(code: string): Map<string, string> {
+  const types = new Map<string, string>();
+  for (const match of code.matchAll(STRUCT_FIELD)) {
+    if (!types.has(match[1])) types.set(match[1], match[2].trim());
+  }
+  return types;
+}
+
+export interface SeedTypeContext {
+  /** Parameter and local types in scope of the derivation */
+  locals: Map<string, string>;
+  /** Struct field types across the crate */
+  fields: Map<string, string>;
+  constants: Map<string, string>;
+}
+
+function widthOfType(type: string | undefined): Pick<SeedComponent, "kind" | "width"> | undefined {
+  if (!type) return undefined;
+  const bare = type.replace(/^&(?:'\w+\s+)?(?:mut\s+)?/, "").trim();
+  if (/^(?:\w+::)*Pubkey$/.test(bare)) return { kind: "fixed", width: 32 };
+  const array = bare.match(/^\[\s*u8\s*;\s*(\d+)\s*\]$/);
+  if (array) return { kind: "fixed", width: Number(array[1]) };
+  if (/^(?:String|str|Vec\s*<\s*u8\s*>|\[\s*u8\s*\])$/.test(bare)) return { kind: "variable" };
+  return undefined;
+}
+
+function rootName(path: string): string {
+  const segments = path.replace(/^ctx\s*\.\s*accounts\s*\./, "").split(".").map((segment) => segment.trim());
+  return segments[segments.length - 1].replace(/_(?:key|pubkey|bytes|seed)$/, "");
+}
+
+/** Classify one seed expression from a seeds array. */
+export function classifySeed(text: string, context: SeedTypeContext): SeedComponent {
+  let expr = text.trim();
+  // Peel borrows and slice adaptors that do not change the bytes.
+  for (;;) {
+    const next = expr
+      .replace(/^&\s*(?:mut\s+)?/, "")
+      .replace(/\s*\.\s*(?:as_ref|as_slice|to_vec|as_bytes)\s*\(\s*\)$/, "")
+      .replace(/\s*\[\s*\.\.\s*\]$/, "")
+      .trim();
+    if (next === expr) break;
+    expr = next;
+  }
+  const asBytes = /\.\s*as_bytes\s*\(\s*\)\s*(?:\[\s*\.\.\s*\])?$/.test(text.trim());
+
+  const literal = expr.match(/^b?"((?:[^"\\]|\\.)*)"$/);
+  if (literal) return { text, kind: "literal", bytes: unescapeBytes(literal[1]) };
+
+  const constant = expr.match(/^(?:\w+::)*([A-Z][A-Z0-9_]*)$/);
+  if (constant) {
+    const bytes = context.constants.get(constant[1]);
+    return bytes !== undefined ? { text, kind: "literal", bytes } : { text, kind: "unknown" };
+  }
+
+  // `[bump]`-style single-byte arrays.
+  if (/^\[[^\],;]+\]$/.test(expr)) return { text, kind: "fixed", width: 1, root: "bump" };
+
+  const key = expr.match(/^([\w.\s]+?)\s*\.\s*key\s*(?:\(\s*\))?$/) ?? expr.match(/^([\w.\s]+?)\s*\.\s*to_bytes\s*\(\s*\)$/);
+  if (key) return { text, kind: "fixed", width: 32, root: rootName(key[1]) };
+  if (/^(?:\w+::)*(?:id\s*\(\s*\)|ID)$/.test(expr)) return { text, kind: "fixed", width: 32, root: expr };
+
+  const int = expr.match(/^\(?([\w.\s]+?)\)?\s*\.\s*to_(?:le|be)_bytes\s*\(\s*\)$/);
+  if (int) {
+    const cast = expr.match(/\bas\s+(\w+)\s*\)?\s*\.\s*to_/);
+    const path = int[1].replace(/\s+as\s+\w+$/, "");
+    const name = path.split(".").pop()!.trim();
+    const type = cast?.[1] ?? context.locals.get(name) ?? (path.includes(".") ? context.fields.get(name) : undefined);
+    const width = type ? INT_WIDTH[type.trim()] : undefined;
+    return width ? { text, kind: "fixed", width, root: rootName(path) } : { text, kind: "unknown", root: rootName(path) };
+  }
+
+  const ident = expr.match(/^[\w.\s]+$/);
+  if (ident) {
+    const name = expr.split(".").pop()!.trim();
+    const type = expr.includes(".") ? context.fields.get(name) : context.locals.get(name) ?? context.fields.get(name);
+    const sized = widthOfType(type);
+    if (sized) return { text, ...sized, root: rootName(expr) };
+    if (asBytes) return { text, kind: "variable", root: rootName(expr) };
+  }
+
+  return { text, kind: "unknown" };
+}
+
+function seedList(text: string): string[] {
+  const trimmed = text.trim().replace(/^&\s*/, "");
+  const open = trimmed.indexOf("[");
+  if (open < 0) return [];
+  const close = matchingBracket(trimmed, open);
+  return splitTopLevel(trimmed.slice(open + 1, close < 0 ? trimmed.length : close));
+}
+
+/** Seed schemas of the `seeds = [...]` constraints in Anchor Accounts structs. */
+export function anchorSeedSchemas(
+  struct: AnchorAccountsStruct,
+  argTypes: RustParam[],
+  context: Omit<SeedTypeContext, "locals">
+): PdaSchema[] {
+  const locals = new Map(argTypes.map((arg) => [arg.name, arg.type] as const));
+  const schemas: PdaSchema[] = [];
+  for (const field of struct.fields) {
+    const seeds = getConstraint(field, "seeds");
+    // `seeds::program` derives under another program ID, a separate address space.
+    if (!seeds?.value || getConstraint(field, "seeds::program")) continue;
+    schemas.push({
+      file: struct.file,
+      line: seeds.line,
+      source: "anchor",
+      site: `${struct.name}.${field.name}`,
+      accountType: field.innerType,
+      accountField: field.name,
+      seeds: seedList(seeds.value).map((seed) => classifySeed(seed, { ...context, locals }))
+    });
+  }
+  return schemas;
+}
+
+/** Seed schemas of `find_program_address` / `create_program_address` calls in function bodies. */
+export function manualSeedSchemas(content: string, file: string, context: Omit<SeedTypeContext, "locals">): PdaSchema[] {
+  const code = stripComments(content);
+  const starts = lineStarts(code);
+  const schemas: PdaSchema[] = [];
+
+  for (const fn of extractFunctions(code)) {
+    const locals = new Map(fn.params.map((param) => [param.name, param.type] as const));
+    for (const local of fn.body.matchAll(/\blet\s+(?:mut\s+)?(\w+)\s*:\s*([^=;]+?)\s*=/g)) locals.set(local[1], local[2]);
+    for (const local of fn.body.matchAll(/\blet\s+(?:mut\s+)?(\w+)\s*=\s*[^;]*?\.key\s*\(\s*\)\s*;/g)) {
+      if (!locals.has(local[1])) locals.set(local[1], "Pubkey");
+    }
+
+    for (const call of fn.body.matchAll(DERIVATION)) {
+      const open = (call.index ?? 0) + call[0].length - 1;
+      const close = matchingBracket(fn.body, open);
+      if (close < 0) continue;
+      const [seedsArg] = splitTopLevel(fn.body.slice(open + 1, close));
+      if (!seedsArg) continue;
+      let seeds = seedList(seedsArg).map((seed) => classifySeed(seed, { ...context, locals }));
+      // `create_program_address` takes the bump as its last seed; `find_program_address` appends it itself.
+      if (call[1] === "create_program_address" && seeds[seeds.length - 1]?.width === 1) seeds = seeds.slice(0, -1);
+      if (seeds.length === 0) continue;
+      schemas.push({
+        file,
+        line: lineAt(starts, fn.bodyOffset + (call.index ?? 0)),
+        source: "manual",
+        site: `${fn.name}()`,
+        seeds
+      });
+    }
+  }
+
+  return schemas;
+}
+
+function componentSignature(seed: SeedComponent): string {
+  switch (seed.kind) {
+    case "literal":
+      return `b${JSON.stringify(seed.bytes)}`;
+    case "fixed":
+      return `${seed.root ?? "?"}[${seed.width}]`;
+    case "variable":
+      return `${seed.root ?? "?"}[..]`;
+    default:
+      return `?${seed.text.replace(/\s+/g, "")}`;
+  }
+}
+
+/** Seed tuple as a compact string, e.g. `[b"user", owner[32]]`. */
+export function schemaSignature(schema: PdaSchema): string {
+  return `[${schema.seeds.map(componentSignature).join(", ")}]`;
+}
+
+/** Group derivations into PDA kinds: the same seed signature derives the same account. */
+export function buildPdaKindTable(schemas: PdaSchema[]): PdaKind[] {
+  const kinds = new Map<string, PdaKind>();
+  for (const schema of schemas) {
+    const signature = schemaSignature(schema);
+    let kind = kinds.get(signature);
+    if (!kind) {
+      kind = { signature, schemas: [], accountTypes: [] };
+      kinds.set(signature, kind);
+    }
+    kind.schemas.push(schema);
+    if (schema.accountType && !kind.accountTypes.includes(schema.accountType)) kind.accountTypes.push(schema.accountType);
+  }
+  return [...kinds.values()];
+}
+
+type Atom = { byte: string } | "any" | "opt";
+
+/** Expand seeds to byte atoms: known bytes, any single byte, or an optional byte of a variable-length seed. */
+function atoms(schema: PdaSchema): Atom[] | undefined {
+  const out: Atom[] = [];
+  for (const seed of schema.seeds) {
+    if (seed.kind === "unknown") return undefined;
+    if (seed.kind === "literal") for (const byte of seed.bytes ?? "") out.push({ byte });
+    else if (seed.kind === "fixed") for (let i = 0; i < (seed.width ?? 0); i++) out.push("any");
+    else for (let i = 0; i < MAX_SEED_LEN; i++) out.push("opt");
+  }
+  return out;
+}
+
+/**
+ * Whether some values of the two schemas' variable seeds yield the same concatenated bytes.
+ * Schemas with a seed of unknown shape are never reported.
+ */
+export function schemasOverlap(a: PdaSchema, b: PdaSchema): boolean {
+  const left = atoms(a);
+  const right = atoms(b);
+  if (!left || !right) return false;
+
+  const width = right.length + 1;
+  const reach = new Uint8Array((left.length + 1) * width);
+  reach[left.length * width + right.length] = 1;
+  for (let i = left.length; i >= 0; i--) {
+    for (let j = right.length; j >= 0; j--) {
+      if (i === left.length && j === right.length) continue;
+      const x = left[i];
+      const y = right[j];
+      let ok = false;
+      if (x === "opt" && reach[(i + 1) * width + j]) ok = true;
+      else if (y === "opt" && reach[i * width + j + 1]) ok = true;
+      else if (x !== undefined && y !== undefined && reach[(i + 1) * width + j + 1]) {
+        ok = typeof x === "string" || typeof y === "string" || x.byte === y.byte;
+      }
+      reach[i * width + j] = ok ? 1 : 0;
+    }
+  }
+  return reach[0] === 1;
+}
+
+/** Index of the first of two adjacent variable-length seeds, whose boundary an attacker can shift. */
+export function adjacentVariableSeeds(schema: PdaSchema): number {
+  return schema.seeds.findIndex((seed, index) => seed.kind === "variable" && schema.seeds[index + 1]?.kind === "variable");
+}
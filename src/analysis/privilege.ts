@@ -0,0 +1,311 @@
+/**
+ * Least-privilege model of Anchor instructions.
+ *
+ * Joins each handler body with its Accounts struct to see which of the privileges the struct asks
+ * for are used: accounts declared `mut` that are written, `Signer`s whose signature authorizes
+ * something, and the PDA authorities the handler signs CPIs with, by seeds and operation.
+ */
+
+import type { AnchorAccountField, AnchorAccountsStruct, AnchorInstruction } from "./anchor-model";
+import { hasConstraint, isSignerField } from "./anchor-model";
+import { bodyLine } from "./rust-items";
+import { escapeRegExp, matchingBracket, splitTopLevel } from "./rust-source";
+import { KEY_SEED } from "./taint";
+
+export interface FieldPrivilege {
+  field: AnchorAccountField;
+  /** Declared `mut` (or implied mutable by `init`, `close`, `realloc`) */
+  mutable: boolean;
+  /** The handler or a constraint writes the account, or it escapes somewhere that may */
+  written: boolean;
+  signer: boolean;
+  /** A constraint or the handler relies on the signature */
+  authorizes: boolean;
+}
+
+export interface PdaSignature {
+  /** Signer seeds with local bindings expanded */
+  seeds: string;
+  /** Literal and constant seeds, the part of the derivation fixed by the program */
+  literals: string[];
+  /** Whether the seeds include an account key or other per-entity value */
+  scoped: boolean;
+  /** CPI the seeds sign, e.g. `token::transfer` */
+  operation: string;
+  file: string;
+  line: number;
+}
+
+export interface CpiCall {
+  /** e.g. `token::transfer`, or `invoke` when the instruction builder cannot be resolved */
+  operation: string;
+  /** Accounts field holding the program a `CpiContext` targets */
+  programField?: string;
+  line: number;
+  /** PDA signature the call carries */
+  signature?: PdaSignature;
+}
+
+export interface PrivilegeModel {
+  instruction: AnchorInstruction;
+  struct: AnchorAccountsStruct;
+  fields: FieldPrivilege[];
+  signatures: PdaSignature[];
+  cpis: CpiCall[];
+  /** `ctx` or `ctx.accounts` is handed to code outside the handler, so unused privileges cannot be judged */
+  opaque: boolean;
+}
+
+/** Constraints that make Anchor itself write the account they sit on. */
+const SELF_WRITING = ["init", "init_if_needed", "close", "realloc", "zero"];
+/** Constraints whose value names an account Anchor debits or credits. */
+const WRITES_TARGET = ["payer", "close", "realloc::payer"];
+
+const READ_METHODS = new Set(["key", "lamports", "data_len", "data_is_empty", "load", "reload", "try_borrow_data", "is_signer", "is_writable", "owner"]);
+const MUT_METHODS =
+  /^(?:borrow_mut|push|push_str|insert|remove|clear|extend|extend_from_slice|retain|truncate|swap|fill|copy_from_slice|clone_from_slice|set|append|drain|sort|pop|get_mut|iter_mut|as_mut|resize|take|replace)$/;
+const READ_MACRO = /^\s*(?:require\w*|assert\w*|msg|emit)!/;
+const SIGNER_CALL = [
+  { pattern: /\binvoke_signed(?:_unchecked)?\s*\(/g, arg: 2 },
+  { pattern: /\bCpiContext\s*::\s*new_with_signer\s*\(/g, arg: 2 },
+  { pattern: /\.\s*with_signer\s*\(/g, arg: 0 }
+];
+const CPI_CALL = /\binvoke(?:_signed)?(?:_unchecked)?\s*\(|\bCpiContext\s*::\s*new(?:_with_signer)?\s*\(/g;
+const OPERATION = /\b((?:\w+\s*::\s*)*(?:token|token_interface|token_2022|system_program|associated_token|\w+_instruction|instruction))\s*::\s*(\w+)\s*\(/;
+
+/** The statement around `offset`, walking out of any call arguments or struct literals it sits in. */
+function statementBounds(body: string, offset: number): [number, number] {
+  let start = offset;
+  let depth = 0;
+  while (start > 0) {
+    const ch = body[start - 1];
+    if (ch === ")" || ch === "]" || ch === "}") depth++;
+    else if (ch === "(" || ch === "[") depth = Math.max(0, depth - 1);
+    else if (ch === "{") {
+      if (depth === 0) break;
+      depth--;
+    } else if (ch === ";" && depth === 0) break;
+    start--;
+  }
+  const end = body.indexOf(";", offset);
+  return [start, end < 0 ? body.length : end];
+}
+
+/** `let` aliases of an account path: `let vault = &mut ctx.accounts.vault;` and similar. */
+function aliasesOf(body: string, path: string): { name: string; offset: number; end: number }[] {
+  const alias = new RegExp(
+    `\\blet\\s+(?:mut\\s+)?(\\w+)\\s*(?::[^=]+)?=\\s*&?\\s*(?:mut\\s+)?${escapeRegExp(path)}\\s*;`,
+    "g"
+  );
+  return [...body.matchAll(alias)].map((match) => ({ name: match[1], offset: match.index ?? 0, end: (match.index ?? 0) + match[0].length }));
+}
+
+/** Whether one mention of an account (at `offset`, `length` chars) can change it. */
+function mentionWrites(body: string, offset: number, length: number): boolean {
+  const after = body.slice(offset + length);
+  const before = body.slice(Math.max(0, offset - 8), offset);
+  const member = after.match(/^\s*\.\s*(\w+)/);
+  if (!member) {
+    if (/&\s*mut\s*$/.test(before)) return true;
+    const [start] = statementBounds(body, offset);
+    return !READ_MACRO.test(body.slice(start, offset));
+  }
+
+  const rest = after.slice(member[0].length);
+  if (/^\s*\(/.test(rest)) return !READ_METHODS.has(member[1]);
+  // Field access: follow the member chain, then look at what is done with it.
+  const chain = rest.match(/^(?:\s*\.\s*\w+(?!\s*\())*/)?.[0] ?? "";
+  const tail = rest.slice(chain.length);
+  if (/^\s*(?:[+\-*/%|&^]|<<|>>)?=(?![=>])/.test(tail)) return true;
+  const method = tail.match(/^\s*\.\s*(\w+)\s*\(/);
+  return method ? MUT_METHODS.test(method[1]) : false;
+}
+
+function fieldWritten(body: string, contextParam: string, field: AnchorAccountField): boolean {
+  const path = `${contextParam}.accounts.${field.name}`;
+  const aliases = aliasesOf(body, path);
+  const bindings = new Set(aliases.map((alias) => alias.offset));
+  const pathMention = new RegExp(`(?<![\\w.])${escapeRegExp(path)}\\b`, "g");
+  for (const match of body.matchAll(pathMention)) {
+    const offset = match.index ?? 0;
+    // The alias binding itself: `&mut` there only means the alias may write, which its own uses show.
+    if ([...bindings].some((start) => offset > start && offset < start + body.slice(start).indexOf(";"))) continue;
+    if (mentionWrites(body, offset, match[0].length)) return true;
+  }
+  for (const alias of aliases) {
+    const mention = new RegExp(`(?<![\\w.])${escapeRegExp(alias.name)}\\b`, "g");
+    mention.lastIndex = alias.end;
+    for (let match = mention.exec(body); match; match = mention.exec(body)) {
+      if (mentionWrites(body, match.index, match[0].length)) return true;
+    }
+  }
+  return false;
+}
+
+/** Whether `ctx` or `ctx.accounts` itself (not one of its fields) is passed to other code. */
+function contextEscapes(body: string, contextParam: string, struct: AnchorAccountsStruct): boolean {
+  const ctx = escapeRegExp(contextParam);
+  if (new RegExp(`[(,]\\s*(?:&\\s*(?:mut\\s+)?)?${ctx}\\s*[,)]`).test(body)) return true;
+  const names = new Set(struct.fields.map((field) => field.name));
+  for (const match of body.matchAll(new RegExp(`(?<![\\w.])${ctx}\\s*\\.\\s*accounts\\b(\\s*\\.\\s*(\\w+))?`, "g"))) {
+    if (!match[2] || !names.has(match[2])) return true;
+  }
+  return false;
+}
+
+function mentions(text: string, name: string): boolean {
+  return new RegExp(`(?<![\\w])${escapeRegExp(name)}\\b`).test(text);
+}
+
+/** Substitute single-name `let` bindings into an expression, a few levels deep. */
+function expandBindings(body: string, expr: string): string {
+  const bindings = new Map<string, string>();
+  for (const match of body.matchAll(/\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=]+)?=(?![=>])/g)) {
+    const start = (match.index ?? 0) + match[0].length;
+    const end = body.indexOf(";", start);
+    bindings.set(match[1], body.slice(start, end < 0 ? body.length : end).trim());
+  }
+  let expanded = expr;
+  for (let depth = 0; depth < 3; depth++) {
+    const next = expanded.replace(/(?<![\w.:])[a-z_]\w*\b(?!\s*[(!:])/g, (name) => bindings.get(name) ?? name);
+    if (next === expanded) break;
+    expanded = next;
+  }
+  return expanded.replace(/\s+/g, " ").trim();
+}
+
+/** Literal and constant seeds of a seeds expression, deduplicated and sorted. */
+export function seedLiterals(seeds: string): string[] {
+  return [...seeds.matchAll(/b"[^"]*"|"[^"]*"|\b[A-Z][A-Z0-9_]*(?:\s*::\s*[A-Z][A-Z0-9_]*)*\b/g)]
+    .map((match) => match[0].replace(/\s+/g, ""))
+    .filter((literal, index, all) => all.indexOf(literal) === index)
+    .sort();
+}
+
+/** Seeds that leave anything besides literals, constants and bumps identify one entity among many. */
+function seedsScoped(seeds: string): boolean {
+  if (KEY_SEED.test(seeds)) return true;
+  const rest = seeds
+    .replace(/b"[^"]*"|"[^"]*"/g, " ")
+    .replace(/\b[A-Z][A-Z0-9_]*(?:\s*::\s*[A-Z][A-Z0-9_]*)*\b/g, " ")
+    .replace(/[\w.]*bump[\w.]*/g, " ")
+    .replace(/\b(?:as_ref|as_bytes|as_slice|to_le_bytes|to_be_bytes)\s*\(\s*\)/g, " ")
+    .replace(/\b(?:ctx|self|accounts)\b/g, " ");
+  return /[a-z_]\w*/i.test(rest);
+}
+
+/** The CPI a signer call belongs to: `token::transfer(CpiContext::new_with_signer(..))`, or an invoked builder. */
+function signedOperation(body: string, offset: number, args: string[]): string {
+  const [start, end] = statementBounds(body, offset);
+  const statement = body.slice(start, end);
+  const binding = statement.match(/^\s*let\s+(?:mut\s+)?(\w+)\s*(?::[^=]+)?=/);
+  let search = statement;
+  if (binding) {
+    // `let cpi_ctx = CpiContext::new_with_signer(..); token::transfer(cpi_ctx, amount)?;`
+    const use = body.slice(end).match(new RegExp(`\\b((?:\\w+\\s*::\\s*)+\\w+)\\s*\\(\\s*${escapeRegExp(binding[1])}\\b`));
+    if (use) return use[1].replace(/\s+/g, "");
+  }
+  if (/^\s*(?:&\s*)?\w+\s*$/.test(args[0] ?? "") && /\binvoke/.test(statement)) {
+    search = expandBindings(body, args[0]);
+  }
+  const operation = search.match(OPERATION);
+  if (!operation) return statement.match(/\binvoke(?:_signed)?/)?.[0] ?? "CPI";
+  const path = operation[1].replace(/\s+/g, "").split("::");
+  return `${path[path.length - 1]}::${operation[2]}`;
+}
+
+function callArgs(body: string, match: RegExpMatchArray): string[] {
+  const open = (match.index ?? 0) + match[0].length - 1;
+  const close = matchingBracket(body, open);
+  return splitTopLevel(body.slice(open + 1, close < 0 ? body.length : close));
+}
+
+function pdaSignatures(content: string, instruction: AnchorInstruction): { signature: PdaSignature; offset: number }[] {
+  const body = instruction.fn.body;
+  const signatures: { signature: PdaSignature; offset: number }[] = [];
+  for (const call of SIGNER_CALL) {
+    for (const match of body.matchAll(call.pattern)) {
+      const args = callArgs(body, match);
+      const raw = args[call.arg];
+      if (raw === undefined) continue;
+      const seeds = expandBindings(body, raw);
+      signatures.push({
+        signature: {
+          seeds,
+          literals: seedLiterals(seeds),
+          scoped: seedsScoped(seeds),
+          operation: signedOperation(body, match.index ?? 0, args),
+          file: instruction.file,
+          line: bodyLine(content, instruction.fn, match.index ?? 0)
+        },
+        offset: match.index ?? 0
+      });
+    }
+  }
+  return signatures.sort((a, b) => a.offset - b.offset);
+}
+
+/** Cross-program invocations of a handler, with the program account and PDA signature of each. */
+function cpiCalls(
+  content: string,
+  instruction: AnchorInstruction,
+  signatures: { signature: PdaSignature; offset: number }[]
+): CpiCall[] {
+  const body = instruction.fn.body;
+  const contextParam = escapeRegExp(instruction.contextParam);
+  const calls: CpiCall[] = [];
+  for (const match of body.matchAll(CPI_CALL)) {
+    const offset = match.index ?? 0;
+    const args = callArgs(body, match);
+    const [start, end] = statementBounds(body, offset);
+    const builder = /\binvoke/.test(match[0]) ? expandBindings(body, args[0] ?? "") : args[0] ?? "";
+    const program =
+      builder.match(new RegExp(`^\\s*&?\\s*${contextParam}\\s*\\.\\s*accounts\\s*\\.\\s*(\\w+)`)) ??
+      builder.match(new RegExp(`\\bprogram_id\\s*:\\s*(?:\\*\\s*)?${contextParam}\\s*\\.\\s*accounts\\s*\\.\\s*(\\w+)`));
+    const signature = signatures.find((candidate) => candidate.offset >= start && candidate.offset <= end)?.signature;
+    calls.push({
+      operation: signedOperation(body, offset, args),
+      ...(program ? { programField: program[1] } : {}),
+      line: bodyLine(content, instruction.fn, offset),
+      ...(signature ? { signature } : {})
+    });
+  }
+  return calls;
+}
+
+/** Privileges an instruction's Accounts struct grants, and which of them its handler uses. */
+export function privilegeModel(content: string, instruction: AnchorInstruction, struct: AnchorAccountsStruct): PrivilegeModel {
+  const body = instruction.fn.body;
+  const constraints = struct.fields.flatMap((field) => field.constraints.map((constraint) => ({ owner: field, constraint })));
+
+  const fields = struct.fields.map((field): FieldPrivilege => {
+    const selfWriting = SELF_WRITING.some((key) => hasConstraint(field, key));
+    const targeted = constraints.some(
+      ({ constraint }) => WRITES_TARGET.includes(constraint.key) && constraint.value?.trim() === field.name
+    );
+    const signer = isSignerField(field);
+    // `has_one = user`, `token::authority = user`, `payer = user`, or a check on the signer's own address.
+    const referenced = constraints.some(
+      ({ owner, constraint }) =>
+        (constraint.value !== undefined && mentions(constraint.value, field.name)) ||
+        (owner === field && ["address", "constraint"].includes(constraint.key))
+    );
+    return {
+      field,
+      mutable: hasConstraint(field, "mut") || selfWriting,
+      written: selfWriting || targeted || fieldWritten(body, instruction.contextParam, field),
+      signer,
+      authorizes: signer && (referenced || mentions(body, `${instruction.contextParam}.accounts.${field.name}`))
+    };
+  });
+
+  const signatures = pdaSignatures(content, instruction);
+  return {
+    instruction,
+    struct,
+    fields,
+    signatures: signatures.map(({ signature }) => signature),
+    cpis: cpiCalls(content, instruction, signatures),
+    opaque: contextEscapes(body, instruction.contextParam, struct)
+  };
+}
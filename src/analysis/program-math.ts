@@ -0,0 +1,53 @@
+/**
+ * Helpers shared by the arithmetic and economic scanners: naming of share/price locals, zero-result
+ * guards and per-function scanning over a masked view of a file.
+ */
+
+import type { Finding } from "../types";
+import { productionFunctions, type RustFunction } from "./rust-items";
+import { escapeRegExp } from "./rust-source";
+
+/** Locals holding minted shares, prices, exchange rates or swap output, where rounding picks a winner. */
+export const SHARE_PRICE_NAME = /(shares?|price|rate|exchange|lp_|mint_amount|amount_out|out_amount|value)/i;
+
+/** Whether `body` compares `name` against zero (`!= 0`, `> 0`, `is_zero()`, `require_gt!(name, 0)`, ...). */
+export function hasZeroGuard(body: string, name: string): boolean {
+  const escaped = escapeRegExp(name);
+  return new RegExp(
+    `\\b${escaped}\\s*(?:!=|>)\\s*0\\b|\\b0\\s*(?:!=|<)\\s*${escaped}\\b|\\b${escaped}\\s*==\\s*0\\b|\\b${escaped}\\.is_zero\\(\\)|\\b${escaped}\\s*>=\\s*1\\b|require_(?:gt|neq)!\\(\\s*${escaped}\\s*,\\s*0\\b`
+  ).test(body);
+}
+
+/** The trimmed line of `code` containing `offset`, shortened for evidence. */
+export function snippet(code: string, offset: number): string {
+  const start = code.lastIndexOf("\n", offset) + 1;
+  const end = code.indexOf("\n", offset);
+  const line = code.slice(start, end < 0 ? code.length : end).trim();
+  return line.length > 120 ? `${line.slice(0, 117)}...` : line;
+}
+
+/**
+ * Run `scan` over every non-test function of `content`, passing its body as it appears in `code` (a
+ * masked view of the same file). Nested functions are scanned both on their own and as part of the
+ * enclosing body, so a finding repeated at the same line is kept once.
+ */
+export function scanFunctionBodies(
+  content: string,
+  code: string,
+  scan: (fn: RustFunction, body: string) => Finding[]
+): Finding[] {
+  const findings: Finding[] = [];
+  const seen = new Set<string>();
+
+  for (const fn of productionFunctions(content)) {
+    const body = code.slice(fn.bodyOffset, fn.bodyOffset + fn.body.length);
+    for (const finding of scan(fn, body)) {
+      const key = `${finding.vuln_class}:${finding.line}:${finding.title}`;
+      if (seen.has(key)) continue;
+      seen.add(key);
+      findings.push(finding);
+    }
+  }
+
+  return findings;
+}
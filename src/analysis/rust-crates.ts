@@ -0,0 +1,416 @@
+/**
+ * Crate and module graph of the Rust sources under a scan root.
+ *
+ * Anchor workspaces keep each program in `programs/<name>` and split it across modules:
+ * handlers in `instructions/*.rs`, Accounts structs beside them and state in `state.rs`.
+ * The graph maps every file to its crate and module path and records which modules a file
+ * pulls in through `mod` and `use`, so an analysis can look past the file it is scanning.
+ */
+
+import { promises as fs } from "node:fs";
+import type { Dirent } from "node:fs";
+import path from "node:path";
+import { extractModules } from "./rust-items";
+import { escapeRegExp, lineAt, lineStarts, matchingBracket, splitTopLevel, stripComments } from "./rust-source";
+
+export interface RustCrate {
+  /** Package name as used in `use` paths (`-` normalised to `_`) */
+  name: string;
+  /** Directory holding the crate's Cargo.toml, or the scan root for manifest-less sources */
+  dir: string;
+  manifest?: string;
+  /** `src/lib.rs`, `src/main.rs` or the `[lib] path` of the manifest */
+  rootFile?: string;
+  /** Runtime dependency names as written in the manifest */
+  dependencies: string[];
+}
+
+export interface RustModuleFile {
+  file: string;
+  crate: string;
+  /** Module path from the crate root, e.g. `["instructions", "deposit"]`; empty for the root file */
+  path: string[];
+  /** Files declared as child modules through `mod name;` */
+  children: string[];
+  /** Module files brought into scope with `use` (crate, self, super and sibling-crate paths) */
+  imports: string[];
+  /** Subset of `imports` re-exported with `pub use` */
+  reexports: string[];
+}
+
+export interface RustCrateGraph {
+  root: string;
+  crates: RustCrate[];
+  modules: Map<string, RustModuleFile>;
+  sources: Map<string, string>;
+}
+
+export interface CargoManifest {
+  packageName?: string;
+  /** `[workspace] members` globs */
+  members: string[];
+  /** `[workspace] exclude` globs */
+  exclude: string[];
+  dependencies: string[];
+  /** `[lib] path` */
+  libPath?: string;
+}
+
+const MOD_DECL = /(#\[\s*path\s*=\s*"([^"]+)"\s*\]\s*)?(?:\bpub(?:\s*\([^)]*\))?\s+)?\bmod\s+(\w+)\s*;/g;
+const USE_DECL = /(\bpub(?:\s*\([^)]*\))?\s+)?\buse\s+([^;]+);/g;
+
+function unquote(value: string): string {
+  return value.trim().replace(/^["']|["']$/g, "");
+}
+
+function stringArray(value: string): string[] {
+  return [...value.matchAll(/"([^"]*)"|'([^']*)'/g)].map((match) => match[1] ?? match[2]);
+}
+
+export function crateIdent(name: string): string {
+  return name.replace(/-/g, "_");
+}
+
+/** Minimal Cargo.toml reader: package name, workspace members, runtime dependencies and `[lib] path`. */
+export function parseCargoManifest(text: string): CargoManifest {
+  const manifest: CargoManifest = { members: [], exclude: [], dependencies: [] };
+  const lines = text.split(/\r?\n/);
+  let section = "";
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i].replace(/\s#.*$|^#.*$/, "").trim();
+    if (!line) continue;
+
+    const header = line.match(/^\[\s*([^\]]+?)\s*\]$/);
+    if (header) {
+      section = header[1];
+      // `[dependencies.anchor-lang]` and `[target.'cfg(..)'.dependencies.x]` tables.
+      const table = section.match(/(?:^|\.)dependencies\.([\w-]+)$/);
+      if (table) manifest.dependencies.push(table[1]);
+      continue;
+    }
+
+    const entry = line.match(/^([\w-]+)\s*=\s*(.*)$/);
+    if (!entry) continue;
+    const key = entry[1];
+    let value = entry[2];
+    // Arrays may span several lines.
+    while (value.startsWith("[") && !value.includes("]") && i + 1 < lines.length) {
+      value += ` ${lines[++i].replace(/\s#.*$|^#.*$/, "")}`;
+    }
+
+    if (section === "package" && key === "name") manifest.packageName = unquote(value);
+    else if (section === "workspace" && key === "members") manifest.members.push(...stringArray(value));
+    else if (section === "workspace" && key === "exclude") manifest.exclude.push(...stringArray(value));
+    else if (section === "lib" && key === "path") manifest.libPath = unquote(value);
+    else if (/(?:^|\.)dependencies$/.test(section) && section !== "workspace.dependencies") {
+      manifest.dependencies.push(key);
+    }
+  }
+
+  return manifest;
+}
+
+/** Expand a workspace member glob such as `programs/*` to existing directories. */
+async function expandMember(root: string, pattern: string): Promise<string[]> {
+  let dirs = [root];
+  for (const segment of pattern.split("/").filter(Boolean)) {
+    const next: string[] = [];
+    for (const dir of dirs) {
+      if (!segment.includes("*")) {
+        next.push(path.join(dir, segment));
+        continue;
+      }
+      const matcher = new RegExp(`^${segment.split("*").map(escapeRegExp).join(".*")}$`);
+      const entries: Dirent[] = await fs.readdir(dir, { withFileTypes: true }).catch(() => []);
+      for (const entry of entries) {
+        if (entry.isDirectory() && matcher.test(entry.name)) next.push(path.join(dir, entry.name));
+      }
+    }
+    dirs = next;
+  }
+  return dirs;
+}
+
+async function readManifest(file: string): Promise<CargoManifest | undefined> {
+  try {
+    return parseCargoManifest(await fs.readFile(file, "utf8"));
+  } catch {
+    return undefined;
+  }
+}
+
+function isWithin(dir: string, file: string): boolean {
+  const rel = path.relative(dir, file);
+  return rel === "" || (!rel.startsWith("..") && !path.isAbsolute(rel));
+}
+
+function crateRootFile(dir: string, files: Set<string>, libPath?: string): string | undefined {
+  const candidates = [
+    ...(libPath ? [path.join(dir, libPath)] : []),
+    path.join(dir, "src", "lib.rs"),
+    path.join(dir, "src", "main.rs"),
+    path.join(dir, "lib.rs")
+  ];
+  return candidates.find((candidate) => files.has(candidate));
+}
+
+/**
+ * Crates under the root. A root `[workspace]` decides membership the way cargo does; without one,
+ * every package manifest is a crate. Sources outside any crate form an implicit crate at the root.
+ */
+async function discoverCrates(rootPath: string, manifests: string[], files: Set<string>): Promise<RustCrate[]> {
+  const packages = new Map<string, CargoManifest>();
+  for (const manifest of manifests) {
+    const parsed = await readManifest(manifest);
+    if (parsed) packages.set(path.dirname(manifest), parsed);
+  }
+
+  let crateDirs = [...packages.keys()].filter((dir) => packages.get(dir)?.packageName);
+  const workspace = packages.get(rootPath);
+  if (workspace && workspace.members.length > 0) {
+    const members = new Set<string>();
+    for (const pattern of workspace.members) for (const dir of await expandMember(rootPath, pattern)) members.add(dir);
+    for (const pattern of workspace.exclude) for (const dir of await expandMember(rootPath, pattern)) members.delete(dir);
+    if (workspace.packageName) members.add(rootPath);
+    crateDirs = [...members].filter((dir) => packages.get(dir)?.packageName);
+  }
+
+  const crates: RustCrate[] = crateDirs.map((dir) => {
+    const manifest = packages.get(dir)!;
+    return {
+      name: crateIdent(manifest.packageName!),
+      dir,
+      manifest: path.join(dir, "Cargo.toml"),
+      rootFile: crateRootFile(dir, files, manifest.libPath),
+      dependencies: manifest.dependencies
+    };
+  });
+
+  const orphaned = [...files].some((file) => !crates.some((crate) => isWithin(crate.dir, file)));
+  if (orphaned && !crates.some((crate) => crate.dir === rootPath)) {
+    crates.push({
+      name: crateIdent(path.basename(rootPath)),
+      dir: rootPath,
+      rootFile: crateRootFile(rootPath, files),
+      dependencies: workspace?.dependencies ?? []
+    });
+  }
+
+  return crates;
+}
+
+/** The crate owning `file`: the innermost crate directory containing it. */
+function owningCrate(crates: RustCrate[], file: string): RustCrate | undefined {
+  return crates
+    .filter((crate) => isWithin(crate.dir, file))
+    .sort((a, b) => b.dir.length - a.dir.length)[0];
+}
+
+/** Directory that holds the child modules of `file`: its own directory for mod.rs/crate roots, else `<stem>/`. */
+function childModuleDir(file: string, isCrateRoot: boolean): string {
+  const base = path.basename(file);
+  if (isCrateRoot || base === "mod.rs") return path.dirname(file);
+  return path.join(path.dirname(file), path.basename(file, ".rs"));
+}
+
+/** Module path for a file no `mod` declaration reaches, guessed from its location under `src/`. */
+function guessModulePath(crate: RustCrate, file: string): string[] {
+  const src = path.join(crate.dir, "src");
+  const rel = path.relative(isWithin(src, file) ? src : crate.dir, file).replace(/\.rs$/, "");
+  const segments = rel.split(path.sep).filter(Boolean);
+  const last = segments[segments.length - 1];
+  if (last === "mod" || (segments.length === 1 && (last === "lib" || last === "main"))) segments.pop();
+  return segments;
+}
+
+/** Expand a `use` tree (`a::{b, c::*}`) into one segment list per imported path. */
+export function useTreePaths(tree: string, prefix: string[] = []): string[][] {
+  const text = tree.replace(/\s+as\s+\w+/g, "").replace(/\s+/g, "");
+  const brace = text.indexOf("{");
+  if (brace < 0) return [[...prefix, ...text.split("::").filter(Boolean)]];
+  const close = matchingBracket(text, brace);
+  const head = text.slice(0, brace).split("::").filter(Boolean);
+  const inner = text.slice(brace + 1, close < 0 ? text.length : close);
+  return splitTopLevel(inner).flatMap((part) => useTreePaths(part, [...prefix, ...head]));
+}
+
+function pathKey(segments: string[]): string {
+  return segments.join("::");
+}
+
+/** Build the crate and module graph for the given Rust files. */
+export async function loadCrateGraph(rootPath: string, files: string[]): Promise<RustCrateGraph> {
+  // Paths stay in the form `files` uses (as listed from `rootPath`), so lookups need no normalisation.
+  const root = rootPath;
+  const fileSet = new Set(files);
+  const manifestFiles: string[] = [];
+  const walk = async (dir: string): Promise<void> => {
+    const entries: Dirent[] = await fs.readdir(dir, { withFileTypes: true }).catch(() => []);
+    for (const entry of entries) {
+      if (entry.isDirectory()) {
+        if (entry.name !== "target" && entry.name !== "node_modules" && !entry.name.startsWith(".")) {
+          await walk(path.join(dir, entry.name));
+        }
+      } else if (entry.name === "Cargo.toml") {
+        manifestFiles.push(path.join(dir, entry.name));
+      }
+    }
+  };
+  await walk(root);
+
+  const crates = await discoverCrates(root, manifestFiles, fileSet);
+  const sources = new Map<string, string>();
+  for (const file of files) sources.set(file, stripComments(await fs.readFile(file, "utf8")));
+
+  const modules = new Map<string, RustModuleFile>();
+  const byPath = new Map<string, Map<string, string>>();
+  const register = (file: string, crate: RustCrate, segments: string[]): void => {
+    modules.set(file, { file, crate: crate.name, path: segments, children: [], imports: [], reexports: [] });
+    let index = byPath.get(crate.name);
+    if (!index) {
+      index = new Map();
+      byPath.set(crate.name, index);
+    }
+    if (!index.has(pathKey(segments))) index.set(pathKey(segments), file);
+  };
+
+  // Follow `mod name;` declarations from each crate root.
+  for (const crate of crates) {
+    if (!crate.rootFile) continue;
+    const queue: string[] = [crate.rootFile];
+    register(crate.rootFile, crate, []);
+    while (queue.length > 0) {
+      const file = queue.shift()!;
+      const module = modules.get(file)!;
+      const code = sources.get(file) ?? "";
+      const starts = lineStarts(code);
+      const inline = extractModules(code);
+      for (const decl of code.matchAll(MOD_DECL)) {
+        const line = lineAt(starts, decl.index ?? 0);
+        const nesting = inline.filter((candidate) => line > candidate.line && line < candidate.endLine).map((m) => m.name);
+        const dir = path.join(childModuleDir(file, file === crate.rootFile), ...nesting);
+        const candidates = decl[2]
+          ? [path.join(path.dirname(file), ...nesting, decl[2])]
+          : [path.join(dir, `${decl[3]}.rs`), path.join(dir, decl[3], "mod.rs")];
+        const child = candidates.find((candidate) => fileSet.has(candidate));
+        if (!child || modules.has(child)) continue;
+        register(child, crate, [...module.path, ...nesting, decl[3]]);
+        module.children.push(child);
+        queue.push(child);
+      }
+    }
+  }
+
+  // Files no declaration reaches still belong to the crate around them.
+  for (const file of files) {
+    if (modules.has(file)) continue;
+    const crate = owningCrate(crates, file);
+    if (crate) register(file, crate, guessModulePath(crate, file));
+  }
+
+  // Resolve `use` declarations to the module files they name.
+  const crateNames = new Set(crates.map((crate) => crate.name));
+  for (const module of modules.values()) {
+    const code = sources.get(module.file) ?? "";
+    const starts = lineStarts(code);
+    const inline = extractModules(code);
+    const index = byPath.get(module.crate) ?? new Map<string, string>();
+
+    for (const decl of code.matchAll(USE_DECL)) {
+      const line = lineAt(starts, decl.index ?? 0);
+      const scope = [
+        ...module.path,
+        ...inline.filter((candidate) => line > candidate.line && line < candidate.endLine).map((m) => m.name)
+      ];
+      for (const segments of useTreePaths(decl[2])) {
+        const target = resolveUsePath(segments, scope, module.crate, index, byPath, crateNames);
+        if (!target || target === module.file) continue;
+        if (!module.imports.includes(target)) module.imports.push(target);
+        if (decl[1] && !module.reexports.includes(target)) module.reexports.push(target);
+      }
+    }
+  }
+
+  return { root, crates, modules, sources };
+}
+
+function resolveUsePath(
+  segments: string[],
+  scope: string[],
+  crate: string,
+  index: Map<string, string>,
+  byPath: Map<string, Map<string, string>>,
+  crateNames: Set<string>
+): string | undefined {
+  let base: string[];
+  // `use a::{self, b}` imports `a` itself.
+  let rest = segments.filter((segment, position) => position === 0 || segment !== "self");
+  let lookup = index;
+
+  if (rest[0] === "crate") {
+    base = [];
+    rest = rest.slice(1);
+  } else if (rest[0] === "self") {
+    base = scope;
+    rest = rest.slice(1);
+  } else if (rest[0] === "super") {
+    base = scope;
+    while (rest[0] === "super") {
+      base = base.slice(0, -1);
+      rest = rest.slice(1);
+    }
+  } else if (index.has(pathKey([...scope, rest[0]]))) {
+    base = scope;
+  } else if (crateNames.has(rest[0]) && rest[0] !== crate) {
+    lookup = byPath.get(rest[0]) ?? new Map();
+    base = [];
+    rest = rest.slice(1);
+  } else {
+    return undefined;
+  }
+
+  // The longest prefix naming a module file; what follows are items (or `*`) inside it.
+  for (let length = rest.length; length >= 0; length--) {
+    const file = lookup.get(pathKey([...base, ...rest.slice(0, length)]));
+    if (file) return file;
+  }
+  return undefined;
+}
+
+export function crateOf(graph: RustCrateGraph, file: string): RustCrate | undefined {
+  const name = graph.modules.get(file)?.crate;
+  return graph.crates.find((crate) => crate.name === name);
+}
+
+export function sameCrate(graph: RustCrateGraph, a: string, b: string): boolean {
+  const crate = graph.modules.get(a)?.crate;
+  return crate !== undefined && crate === graph.modules.get(b)?.crate;
+}
+
+/** `vault::instructions::deposit` for `programs/vault/src/instructions/deposit.rs`. */
+export function modulePathOf(graph: RustCrateGraph, file: string): string | undefined {
+  const module = graph.modules.get(file);
+  return module ? pathKey([module.crate, ...module.path]) : undefined;
+}
+
+/** Files whose items are in scope in `file`: the file, the modules it imports, and what those re-export. */
+export function visibleFiles(graph: RustCrateGraph, file: string): string[] {
+  const visible = [file];
+  const queue = [...(graph.modules.get(file)?.imports ?? [])];
+  while (queue.length > 0) {
+    const next = queue.shift()!;
+    if (visible.includes(next)) continue;
+    visible.push(next);
+    queue.push(...(graph.modules.get(next)?.reexports ?? []));
+  }
+  return visible;
+}
+
+/** Comment-stripped source of the modules `file` imports, for checks whose evidence may live next door. */
+export function importedSource(graph: RustCrateGraph, file: string): string {
+  return visibleFiles(graph, file)
+    .slice(1)
+    .map((other) => graph.sources.get(other) ?? "")
+    .join("\n");
+}
@@ -0,0 +1,146 @@
+import { lineAt, lineStarts, matchingBracket, splitTopLevel, stripComments } from "./rust-source";
+
+export interface RustParam {
+  name: string;
+  type: string;
+}
+
+export interface RustFunction {
+  name: string;
+  isPublic: boolean;
+  line: number;
+  endLine: number;
+  params: RustParam[];
+  returnType?: string;
+  /** Comment-stripped body text between the braces */
+  body: string;
+  /** Offset of `body` within the file, for mapping body positions back to lines */
+  bodyOffset: number;
+}
+
+export interface RustModule {
+  name: string;
+  line: number;
+  endLine: number;
+  /** Whether the module carries Anchor's `#[program]` attribute */
+  isProgram: boolean;
+}
+
+const FN_HEADER = /\b(pub(?:\s*\([^)]*\))?\s+)?(?:const\s+|async\s+|unsafe\s+|extern\s+"[^"]*"\s+)*fn\s+(\w+)\s*(?:<[^(]*?>)?\s*\(/g;
+const MOD_HEADER = /(#\[\s*program\s*\]\s*)?(?:\bpub(?:\s*\([^)]*\))?\s+)?\bmod\s+(\w+)\s*\{/g;
+
+/** Offset of the `:` separating a parameter pattern from its type, skipping `::` and nested patterns. */
+function typeColon(part: string): number {
+  let depth = 0;
+  for (let i = 0; i < part.length; i++) {
+    const ch = part[i];
+    if (ch === "(" || ch === "[" || ch === "{" || ch === "<") depth++;
+    else if (ch === ")" || ch === "]" || ch === "}" || ch === ">") depth--;
+    else if (ch === ":" && depth === 0) {
+      if (part[i + 1] === ":") {
+        i++;
+        continue;
+      }
+      return i;
+    }
+  }
+  return -1;
+}
+
+function parseParams(text: string): RustParam[] {
+  const params: RustParam[] = [];
+  for (const part of splitTopLevel(text)) {
+    const colon = typeColon(part);
+    if (colon < 0) continue; // `self`, `&mut self`
+    const pattern = part.slice(0, colon).replace(/\bmut\b/, "").trim();
+    const type = part.slice(colon + 1).replace(/\s+/g, " ").trim();
+    if (/^\w+$/.test(pattern)) {
+      params.push({ name: pattern, type });
+      continue;
+    }
+    // Destructured extractors such as `Path(name): Path<String>` bind each inner name to the whole type.
+    for (const name of pattern.match(/\b[a-z_][a-z0-9_]*\b/g) ?? []) {
+      if (!["mut", "ref", "_"].includes(name)) params.push({ name, type });
+    }
+  }
+  return params;
+}
+
+/** Extract every function item (free functions, impl methods, nested fns) that has a body. */
+export function extractFunctions(content: string): RustFunction[] {
+  const code = stripComments(content);
+  const starts = lineStarts(code);
+  const functions: RustFunction[] = [];
+
+  for (const header of code.matchAll(FN_HEADER)) {
+    const headerStart = header.index ?? 0;
+    const openParen = headerStart + header[0].length - 1;
+    const closeParen = matchingBracket(code, openParen);
+    if (closeParen < 0) continue;
+
+    // Skip the return type and where-clause up to the body (or `;` for declarations).
+    let cursor = closeParen + 1;
+    while (cursor < code.length && code[cursor] !== "{" && code[cursor] !== ";") cursor++;
+    if (code[cursor] !== "{") continue;
+    const closeBrace = matchingBracket(code, cursor);
+    if (closeBrace < 0) continue;
+
+    const signatureTail = code.slice(closeParen + 1, cursor);
+    const returnType = signatureTail.match(/->\s*([\s\S]*?)\s*(?:\bwhere\b[\s\S]*)?$/)?.[1]?.trim();
+    const nameOffset = headerStart + header[0].lastIndexOf(header[2]);
+
+    functions.push({
+      name: header[2],
+      isPublic: Boolean(header[1]),
+      line: lineAt(starts, nameOffset),
+      endLine: lineAt(starts, closeBrace),
+      params: parseParams(code.slice(openParen + 1, closeParen)),
+      returnType: returnType || undefined,
+      body: code.slice(cursor + 1, closeBrace),
+      bodyOffset: cursor + 1
+    });
+  }
+
+  return functions;
+}
+
+/** Extract inline module declarations (`mod name { ... }`), flagging Anchor `#[program]` modules. */
+export function extractModules(content: string): RustModule[] {
+  const code = stripComments(content);
+  const starts = lineStarts(code);
+  const modules: RustModule[] = [];
+
+  for (const header of code.matchAll(MOD_HEADER)) {
+    const headerStart = header.index ?? 0;
+    const openBrace = headerStart + header[0].length - 1;
+    const closeBrace = matchingBracket(code, openBrace);
+    if (closeBrace < 0) continue;
+    const name = header[2];
+    modules.push({
+      name,
+      line: lineAt(starts, headerStart + header[0].lastIndexOf(name)),
+      endLine: lineAt(starts, closeBrace),
+      isProgram: Boolean(header[1])
+    });
+  }
+
+  return modules;
+}
+
+/** Line number of an offset inside a function body. */
+export function bodyLine(content: string, fn: RustFunction, bodyIndex: number): number {
+  return content.slice(0, fn.bodyOffset + bodyIndex).split(/\r?\n/).length;
+}
+
+/** Test-only sources: `tests/` and `testing/` trees, multitest harnesses and `*_test(s).rs` files. */
+export function isRustTestFile(file: string): boolean {
+  return /(?:^|[\\/])(?:tests?|testing|multitest)(?:[\\/]|\.rs$)|_tests?\.rs$/.test(file);
+}
+
+/** Functions outside `#[cfg(test)]`-style `mod tests { .. }` blocks. */
+export function productionFunctions(content: string): RustFunction[] {
+  const testModules = extractModules(content).filter((module) => /test/.test(module.name));
+  return extractFunctions(content).filter(
+    (fn) => !testModules.some((module) => fn.line > module.line && fn.line <= module.endLine)
+  );
+}
@@ -0,0 +1,164 @@
+/**
+ * Lightweight Rust lexer that classifies every byte of a source file as code,
+ * comment, attribute or string-literal content.
+ *
+ * It understands line/doc comments, nested block comments, escaped, byte, C and raw
+ * (`r#"..."#`) strings, char literals versus lifetimes, and `#[...]` / `#![...]`
+ * attributes. Masked views keep offsets and line breaks, so positions found in a
+ * view map straight back to the original file.
+ */
+
+export type RustRegion = "code" | "comment" | "attribute" | "string";
+
+export interface RustLexed {
+  content: string;
+  /** Region of each UTF-16 code unit of `content`, indexed by offset */
+  regions: RustRegion[];
+}
+
+/** Every region except comments: what the compiler actually sees. */
+export const NON_COMMENT_REGIONS: readonly RustRegion[] = ["code", "attribute", "string"];
+
+function isIdentChar(ch: string | undefined): boolean {
+  return ch !== undefined && /\w/.test(ch);
+}
+
+/** Recognise a string literal (`"`, `b"`, `c"`, `r#"`, `br#"`, ...) starting at `i`. */
+function stringLiteralAt(content: string, i: number): { open: number; hashes: number; raw: boolean } | undefined {
+  if (isIdentChar(content[i - 1])) return undefined;
+  let j = i;
+  if (content[j] === "b" || content[j] === "c") j++;
+  let raw = false;
+  if (content[j] === "r") {
+    raw = true;
+    j++;
+  }
+  let hashes = 0;
+  if (raw) {
+    while (content[j] === "#") {
+      hashes++;
+      j++;
+    }
+  }
+  if (content[j] !== '"') return undefined;
+  return { open: j, hashes, raw };
+}
+
+/** End offset (exclusive) of a char literal starting at the quote `i`, or -1 for a lifetime/label. */
+function charLiteralEnd(content: string, i: number): number {
+  if (content[i + 1] === "\\") {
+    const close = content.indexOf("'", i + 3);
+    return close > 0 && close - i <= 12 ? close + 1 : -1;
+  }
+  // A single code point (possibly a surrogate pair) followed by the closing quote.
+  const width = content.codePointAt(i + 1)! > 0xffff ? 2 : 1;
+  return content[i + 1 + width] === "'" && content[i + 1] !== "\n" ? i + 2 + width : -1;
+}
+
+export function lexRust(content: string): RustLexed {
+  const regions = new Array<RustRegion>(content.length).fill("code");
+  // Bracket depth inside the current attribute; 0 when outside any attribute.
+  let attrDepth = 0;
+  let i = 0;
+
+  const mark = (from: number, to: number, region: RustRegion): void => {
+    for (let k = from; k < to && k < content.length; k++) regions[k] = region;
+  };
+
+  while (i < content.length) {
+    const ch = content[i];
+    const next = content[i + 1];
+    const literalRegion: RustRegion = attrDepth > 0 ? "attribute" : "string";
+    const codeRegion: RustRegion = attrDepth > 0 ? "attribute" : "code";
+
+    if (ch === "/" && next === "/") {
+      const end = content.indexOf("\n", i);
+      const stop = end < 0 ? content.length : end;
+      mark(i, stop, "comment");
+      i = stop;
+      continue;
+    }
+
+    if (ch === "/" && next === "*") {
+      let depth = 0;
+      let j = i;
+      while (j < content.length) {
+        if (content[j] === "/" && content[j + 1] === "*") {
+          depth++;
+          j += 2;
+        } else if (content[j] === "*" && content[j + 1] === "/") {
+          depth--;
+          j += 2;
+          if (depth === 0) break;
+        } else {
+          j++;
+        }
+      }
+      mark(i, j, "comment");
+      i = j;
+      continue;
+    }
+
+    const literal = ch === '"' || ch === "b" || ch === "c" || ch === "r" ? stringLiteralAt(content, i) : undefined;
+    if (literal) {
+      let j = literal.open + 1;
+      if (literal.raw) {
+        const terminator = `"${"#".repeat(literal.hashes)}`;
+        const close = content.indexOf(terminator, j);
+        j = close < 0 ? content.length : close;
+      } else {
+        while (j < content.length && content[j] !== '"') j += content[j] === "\\" ? 2 : 1;
+      }
+      const closeEnd = Math.min(content.length, j + 1 + (literal.raw ? literal.hashes : 0));
+      // Delimiters stay code so masked views still read `"   "`; only the contents are literal text.
+      mark(i, literal.open + 1, codeRegion);
+      mark(literal.open + 1, j, literalRegion);
+      mark(j, closeEnd, codeRegion);
+      i = closeEnd;
+      continue;
+    }
+
+    if (ch === "'" || (ch === "b" && next === "'" && !isIdentChar(content[i - 1]))) {
+      const quote = ch === "'" ? i : i + 1;
+      const end = charLiteralEnd(content, quote);
+      if (end > 0) {
+        mark(i, quote + 1, codeRegion);
+        mark(quote + 1, end - 1, literalRegion);
+        mark(end - 1, end, codeRegion);
+        i = end;
+        continue;
+      }
+    }
+
+    if (attrDepth === 0 && ch === "#") {
+      const open = next === "!" && content[i + 2] === "[" ? i + 2 : next === "[" ? i + 1 : -1;
+      if (open > 0) {
+        mark(i, open + 1, "attribute");
+        attrDepth = 1;
+        i = open + 1;
+        continue;
+      }
+    }
+
+    if (attrDepth > 0) {
+      if (ch === "[") attrDepth++;
+      else if (ch === "]") attrDepth--;
+    }
+
+    regions[i] = codeRegion;
+    i++;
+  }
+
+  return { content, regions };
+}
+
+/** Blank out every byte outside `keep`, preserving offsets and line breaks. */
+export function maskRust(source: string | RustLexed, keep: readonly RustRegion[]): string {
+  const lexed = typeof source === "string" ? lexRust(source) : source;
+  const kept = new Set(keep);
+  const out = lexed.content.split("");
+  for (let i = 0; i < out.length; i++) {
+    if (!kept.has(lexed.regions[i]) && out[i] !== "\n" && out[i] !== "\r") out[i] = " ";
+  }
+  return out.join("");
+}
@@ -0,0 +1,92 @@
+/**
+ * Text-level helpers shared by the Rust analyses. All of them preserve byte offsets so
+ * positions found in a masked copy of a file map straight back to the original lines.
+ */
+
+import { maskRust, NON_COMMENT_REGIONS } from "./rust-lexer";
+
+/** Replace comment bodies with spaces, keeping offsets and line numbers intact. */
+export function stripComments(content: string): string {
+  return maskRust(content, NON_COMMENT_REGIONS);
+}
+
+export function escapeRegExp(text: string): string {
+  return text.replace(/[.*+?^${}()|[\]\\]/g, "\\$&");
+}
+
+export function lineStarts(content: string): number[] {
+  const starts = [0];
+  for (let i = 0; i < content.length; i++) {
+    if (content[i] === "\n") starts.push(i + 1);
+  }
+  return starts;
+}
+
+export function lineAt(starts: number[], offset: number): number {
+  let lo = 0;
+  let hi = starts.length - 1;
+  while (lo < hi) {
+    const mid = (lo + hi + 1) >> 1;
+    if (starts[mid] <= offset) lo = mid;
+    else hi = mid - 1;
+  }
+  return lo + 1;
+}
+
+/** Offset of the bracket closing the one at `open`, skipping string literals. */
+export function matchingBracket(text: string, open: number): number {
+  const pairs: Record<string, string> = { "(": ")", "[": "]", "{": "}" };
+  const stack: string[] = [];
+  for (let i = open; i < text.length; i++) {
+    const ch = text[i];
+    if (ch === '"') {
+      i++;
+      while (i < text.length && text[i] !== '"') {
+        if (text[i] === "\\") i++;
+        i++;
+      }
+      continue;
+    }
+    if (pairs[ch]) {
+      stack.push(pairs[ch]);
+    } else if (ch === stack[stack.length - 1]) {
+      stack.pop();
+      if (stack.length === 0) return i;
+    }
+  }
+  return -1;
+}
+
+/** Split on commas that are not nested inside brackets, generics or strings. */
+export function splitTopLevel(text: string, separator = ","): string[] {
+  const parts: string[] = [];
+  let depth = 0;
+  let angle = 0;
+  let current = "";
+  for (let i = 0; i < text.length; i++) {
+    const ch = text[i];
+    if (ch === '"') {
+      let j = i + 1;
+      while (j < text.length && text[j] !== '"') {
+        if (text[j] === "\\") j++;
+        j++;
+      }
+      current += text.slice(i, j + 1);
+      i = j;
+      continue;
+    }
+    if (ch === "(" || ch === "[" || ch === "{") depth++;
+    else if (ch === ")" || ch === "]" || ch === "}") depth--;
+    else if (ch === "<" && /[\w:>]$/.test(current) && /^['\w&]/.test(text.slice(i + 1))) angle++;
+    else if (ch === ">" && angle > 0 && text[i - 1] !== "-" && text[i - 1] !== "=") angle--;
+
+    if (ch === separator && depth === 0 && angle === 0) {
+      parts.push(current);
+      current = "";
+      continue;
+    }
+    current += ch;
+  }
+  if (current.trim().length > 0) parts.push(current);
+  return parts.map((part) => part.trim()).filter(Boolean);
+}
@@ -0,0 +1,97 @@
+/**
+ * Request handlers of Rust web services (axum, actix-web, Rocket, warp) and the values in
+ * their bodies that the client controls.
+ *
+ * A function is a handler when a route attribute sits on it, a router names it, or it takes
+ * one of the web frameworks' request extractors. Client control is followed through `let`
+ * bindings and `for` loops of the handler body; helper calls are not followed.
+ */
+
+import { extractFunctions, type RustFunction, type RustParam } from "./rust-items";
+import { escapeRegExp, stripComments } from "./rust-source";
+
+export interface RequestHandler {
+  fn: RustFunction;
+  /** Parameters whose value the client chooses */
+  inputs: RustParam[];
+}
+
+/** Extractors that only exist in request handlers. */
+const EXTRACTOR =
+  /^(?:&\s*)?(?:(?:axum|actix_web|extract|web)\s*::\s*)*(?:Path|Query|Json|Form|TypedHeader|RawQuery|RawForm)\s*<|^(?:&\s*)?(?:[\w:]*::)?(?:HttpRequest|Multipart)\b/;
+/** Handler parameters filled in by the server rather than the request. */
+const SERVER_STATE = /\b(?:State|Extension|ConnectInfo|web\s*::\s*Data)\b|Pool\b|Conn(?:ection)?\b/;
+const ROUTE_ATTRIBUTE = /#\[\s*(?:[\w:]*::)?(?:get|post|put|delete|patch|head|options|route)\s*\(/;
+/** `get(handler)`, `web::post().to(handler)`, `.service(handler)` and warp's `.and_then(handler)`. */
+const ROUTER_REFERENCE =
+  /\b(?:get|post|put|delete|patch|head|options|any)(?:_service)?\s*\(\s*([\w:]+)\s*\)|\.\s*(?:to|service|and_then|handler)\s*\(\s*([\w:]+)\s*\)/g;
+
+/** Function names a router in `content` dispatches requests to. */
+export function routedFunctions(content: string): Set<string> {
+  const names = new Set<string>();
+  for (const match of stripComments(content).matchAll(ROUTER_REFERENCE)) {
+    const name = (match[1] ?? match[2]).split("::").pop()!;
+    if (/^[a-z_]\w*$/.test(name)) names.add(name);
+  }
+  return names;
+}
+
+function hasRouteAttribute(lines: string[], fn: RustFunction): boolean {
+  // Attributes stacked directly above the `fn` line (or on it).
+  const attributes: string[] = [lines[fn.line - 1] ?? ""];
+  for (let index = fn.line - 2; index >= 0; index--) {
+    const line = lines[index].trim();
+    if (!line.startsWith("#[") && !line.startsWith("//") && !line.endsWith(")]")) break;
+    attributes.push(line);
+  }
+  return attributes.some((line) => ROUTE_ATTRIBUTE.test(line));
+}
+
+/** Request handlers of one file; `routed` holds the names any router of the crate dispatches to. */
+export function extractRequestHandlers(content: string, routed: Set<string>): RequestHandler[] {
+  const lines = content.split(/\r?\n/);
+  const handlers: RequestHandler[] = [];
+  for (const fn of extractFunctions(content)) {
+    const attributed = hasRouteAttribute(lines, fn);
+    if (!attributed && !routed.has(fn.name) && !fn.params.some((param) => EXTRACTOR.test(param.type))) continue;
+    handlers.push({ fn, inputs: fn.params.filter((param) => !SERVER_STATE.test(param.type)) });
+  }
+  return handlers;
+}
+
+/** Whether `expr` mentions any of `names` as a variable (not as a field or path segment). */
+export function mentionsBinding(expr: string, names: Iterable<string>): boolean {
+  for (const name of names) {
+    if (new RegExp(`(?<![\\w.])${escapeRegExp(name)}\\b`).test(expr)) return true;
+  }
+  return false;
+}
+
+/** Names a pattern binds, e.g. `Some(name)`, `(dir, file)` or `mut path: PathBuf`. */
+function patternNames(pattern: string): string[] {
+  const bare = pattern.split(/(?<!:):(?!:)/)[0];
+  return (bare.match(/\b[a-z_][a-z0-9_]*\b/g) ?? []).filter((name) => !["mut", "ref", "_"].includes(name));
+}
+
+/** `names` plus every local bound (transitively) from an expression that mentions one of them. */
+export function derivedBindings(body: string, names: string[]): Set<string> {
+  const tainted = new Set(names);
+  const bindings = [
+    ...[...body.matchAll(/\blet\s+([^=;]+?)\s*=(?![=>])([^;{]+)/g)].map((match) => [match[1], match[2]] as const),
+    ...[...body.matchAll(/\bfor\s+([^;{]+?)\s+in\s+([^{;]+)\{/g)].map((match) => [match[1], match[2]] as const)
+  ];
+  let grew = true;
+  while (grew) {
+    grew = false;
+    for (const [pattern, value] of bindings) {
+      if (!mentionsBinding(value, tainted)) continue;
+      for (const name of patternNames(pattern)) {
+        if (!tainted.has(name)) {
+          tainted.add(name);
+          grew = true;
+        }
+      }
+    }
+  }
+  return tainted;
+}
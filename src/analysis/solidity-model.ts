@@ -0,0 +1,504 @@
+/**
+ * Structured model of Solidity sources for the EVM scanners.
+ *
+ * Contracts keep their state in contract-level variables and expose `public`/`external`
+ * functions that anyone can call unless a modifier (`onlyOwner`, `onlyRole(..)`) or a
+ * `msg.sender` check says otherwise. Ether and control leave a contract through external
+ * calls: low-level `call`/`delegatecall`/`send` and calls on interface-typed values, any of
+ * which can run attacker code before the caller's function finishes.
+ *
+ * The parser works on a masked copy of each file (comments and string contents blanked)
+ * so offsets found there map straight back to the original lines.
+ */
+
+import { escapeRegExp, lineAt, lineStarts, matchingBracket, splitTopLevel } from "./rust-source";
+
+export type SolidityVisibility = "public" | "external" | "internal" | "private";
+
+export type SolidityFunctionKind = "function" | "constructor" | "fallback" | "receive" | "modifier";
+
+export interface SolidityParam {
+  name: string;
+  type: string;
+}
+
+export interface SolidityFunction {
+  name: string;
+  kind: SolidityFunctionKind;
+  visibility: SolidityVisibility;
+  mutability?: "view" | "pure" | "payable";
+  /** Modifiers applied in the header, without their arguments */
+  modifiers: string[];
+  params: SolidityParam[];
+  line: number;
+  /** Offset of the body's first character (after `{`) in the file */
+  bodyOffset: number;
+  /** Masked body text */
+  body: string;
+}
+
+export interface SolidityStateVariable {
+  name: string;
+  type: string;
+  line: number;
+  /** `constant` and `immutable` variables cannot be written after construction */
+  constant: boolean;
+}
+
+export interface SolidityContract {
+  name: string;
+  kind: "contract" | "abstract" | "library" | "interface";
+  bases: string[];
+  file: string;
+  line: number;
+  stateVariables: SolidityStateVariable[];
+  functions: SolidityFunction[];
+  modifiers: SolidityFunction[];
+}
+
+export interface SoliditySource {
+  file: string;
+  content: string;
+  masked: string;
+  starts: number[];
+  contracts: SolidityContract[];
+}
+
+export interface SolidityProject {
+  sources: SoliditySource[];
+  /** Every contract, library and interface by name */
+  contracts: Map<string, SolidityContract>;
+}
+
+export interface StateWrite {
+  offset: number;
+  variable: string;
+  /** Source of the assignment target, e.g. `balances[msg.sender]` */
+  target: string;
+}
+
+export type ExternalCallKind = "call" | "delegatecall" | "staticcall" | "send" | "transfer" | "interface";
+
+export interface ExternalCall {
+  kind: ExternalCallKind;
+  /** Offset of the receiver expression */
+  offset: number;
+  /** Offset just past the call's argument list */
+  end: number;
+  receiver: string;
+  /** Whether the call forwards ether (`{value: ..}`, `send`, `transfer`) */
+  value: boolean;
+}
+
+/** Build-tool configuration files that mark a directory as an EVM project root. */
+export const EVM_CONFIG_FILES = ["foundry.toml", "hardhat.config.js", "hardhat.config.ts", "hardhat.config.cjs", "truffle-config.js"];
+
+const KEYWORDS = new Set([
+  "public",
+  "external",
+  "internal",
+  "private",
+  "view",
+  "pure",
+  "payable",
+  "virtual",
+  "override",
+  "returns",
+  "constant",
+  "immutable",
+  "memory",
+  "calldata",
+  "storage"
+]);
+
+const REENTRANCY_GUARD = /^(?:nonReentrant\w*|noReentran\w*|reentrancyGuard|lock|mutex)$/i;
+
+/** Header modifiers that restrict who may call a function. */
+const ACCESS_MODIFIER = /^(?:only\w*|auth\w*|requiresAuth|restricted|initializer|reinitializer|whenAuthorized)$/i;
+
+const SENDER = String.raw`(?:msg\.sender|_msgSender\s*\(\s*\))`;
+
+/** Comparisons and lookups of the caller, and the access-control helpers OpenZeppelin and solmate expose. */
+const SENDER_CHECK = new RegExp(
+  [
+    String.raw`${SENDER}\s*[!=]=`,
+    String.raw`[!=]=\s*${SENDER}`,
+    String.raw`\b(?:require|assert)\s*\([^;]*${SENDER}`,
+    String.raw`\bif\s*\([^{;]*${SENDER}`,
+    String.raw`\b_?(?:checkOwner|checkRole|hasRole|onlyOwner|requireOwner|requireAuth|isAuthorized)\s*\(`
+  ].join("|")
+);
+
+/** Blank comment bodies and string-literal contents, keeping quotes, offsets and line breaks. */
+export function maskSolidity(content: string): string {
+  const out = content.split("");
+  const blank = (from: number, to: number): void => {
+    for (let i = from; i < to; i++) {
+      if (out[i] !== "\n") out[i] = " ";
+    }
+  };
+  let i = 0;
+  while (i < content.length) {
+    const ch = content[i];
+    if (ch === "/" && content[i + 1] === "/") {
+      const end = content.indexOf("\n", i);
+      const stop = end < 0 ? content.length : end;
+      blank(i, stop);
+      i = stop;
+    } else if (ch === "/" && content[i + 1] === "*") {
+      const end = content.indexOf("*/", i + 2);
+      const stop = end < 0 ? content.length : end + 2;
+      blank(i, stop);
+      i = stop;
+    } else if (ch === '"' || ch === "'") {
+      let j = i + 1;
+      while (j < content.length && content[j] !== ch && content[j] !== "\n") {
+        if (content[j] === "\\") j++;
+        j++;
+      }
+      blank(i + 1, j);
+      i = j + 1;
+    } else {
+      i++;
+    }
+  }
+  return out.join("");
+}
+
+export function isSolidityFile(file: string): boolean {
+  return file.endsWith(".sol");
+}
+
+/** Foundry tests and scripts, mocks, and vendored dependencies under `lib/` or `node_modules/`. */
+export function isSolidityTestFile(file: string): boolean {
+  return (
+    /\.[ts]\.sol$/.test(file) ||
+    /(?:^|[\\/])(?:test|tests|script|scripts|mocks?|lib|node_modules)[\\/]/.test(file) ||
+    /(?:^|[\\/])Mock\w*\.sol$/.test(file)
+  );
+}
+
+function parseParams(text: string): SolidityParam[] {
+  return splitTopLevel(text).map((param) => {
+    const tokens = param.split(/\s+/).filter(Boolean);
+    const last = tokens[tokens.length - 1] ?? "";
+    const named = tokens.length > 1 && !KEYWORDS.has(last);
+    return { name: named ? last : "", type: tokens[0] ?? "" };
+  });
+}
+
+/** Remove `returns (..)` and `override(..)` groups so only modifiers are left as identifiers. */
+function stripHeaderGroups(text: string): string {
+  let out = text;
+  for (const keyword of ["returns", "override"]) {
+    for (let index = out.search(new RegExp(`\\b${keyword}\\s*\\(`)); index >= 0; index = out.search(new RegExp(`\\b${keyword}\\s*\\(`))) {
+      const open = out.indexOf("(", index);
+      const close = matchingBracket(out, open);
+      out = out.slice(0, index) + out.slice(close < 0 ? out.length : close + 1);
+    }
+  }
+  return out;
+}
+
+function parseFunction(masked: string, starts: number[], headerStart: number, open: number): SolidityFunction | undefined {
+  const header = masked.slice(headerStart, open);
+  const head = header.match(/^\s*(?:function\s+(\w+)|modifier\s+(\w+)|(constructor|fallback|receive)\b)/);
+  if (!head) return undefined;
+
+  const kind: SolidityFunctionKind = head[1] ? "function" : head[2] ? "modifier" : (head[3] as SolidityFunctionKind);
+  const name = head[1] ?? head[2] ?? head[3];
+  const paramOpen = header.indexOf("(", head[0].length);
+  const paramClose = paramOpen < 0 ? -1 : matchingBracket(header, paramOpen);
+  const params = paramClose < 0 ? [] : parseParams(header.slice(paramOpen + 1, paramClose));
+  const rest = stripHeaderGroups(paramClose < 0 ? header.slice(head[0].length) : header.slice(paramClose + 1));
+
+  const visibility = (rest.match(/\b(public|external|internal|private)\b/)?.[1] ??
+    (kind === "fallback" || kind === "receive" ? "external" : kind === "modifier" ? "internal" : "public")) as SolidityVisibility;
+  const mutability = rest.match(/\b(view|pure|payable)\b/)?.[1] as SolidityFunction["mutability"];
+  const modifiers = [...rest.replace(/\([^()]*\)/g, "").matchAll(/\b([A-Za-z_]\w*)\b/g)]
+    .map((match) => match[1])
+    .filter((word) => !KEYWORDS.has(word));
+
+  const close = matchingBracket(masked, open);
+  const end = close < 0 ? masked.length : close;
+  const offset = headerStart + header.search(/\S/);
+  return {
+    name,
+    kind,
+    visibility,
+    mutability,
+    modifiers,
+    params,
+    line: lineAt(starts, offset),
+    bodyOffset: open + 1,
+    body: masked.slice(open + 1, end)
+  };
+}
+
+function parseStateVariable(declaration: string, line: number): SolidityStateVariable | undefined {
+  const text = declaration.trim();
+  if (!text || /^(?:using|event|error|function|type|pragma|import)\b/.test(text)) return undefined;
+  const assign = text.search(/=(?!>)/);
+  const left = (assign < 0 ? text : text.slice(0, assign)).trim();
+  const name = left.match(/(\w+)\s*$/)?.[1];
+  if (!name) return undefined;
+  const type = left
+    .slice(0, left.length - name.length)
+    .replace(/\b(?:public|private|internal|constant|immutable|override|transient)\b/g, "")
+    .trim();
+  if (!type) return undefined;
+  return { name, type, line, constant: /\b(?:constant|immutable)\b/.test(left) };
+}
+
+function parseContract(source: Omit<SoliditySource, "contracts">, match: RegExpMatchArray, open: number): SolidityContract {
+  const { masked, starts } = source;
+  const close = matchingBracket(masked, open);
+  const end = close < 0 ? masked.length : close;
+  const contract: SolidityContract = {
+    name: match[2],
+    kind: /abstract/.test(match[1]) ? "abstract" : (match[1] as SolidityContract["kind"]),
+    bases: (match[3].match(/\bis\s+([\s\S]*)$/)?.[1] ?? "")
+      .replace(/\([^()]*\)/g, "")
+      .split(",")
+      .map((base) => base.trim())
+      .filter(Boolean),
+    file: source.file,
+    line: lineAt(starts, match.index ?? 0),
+    stateVariables: [],
+    functions: [],
+    modifiers: []
+  };
+
+  // Walk top-level members: `{ .. }` blocks are functions, modifiers, structs and enums;
+  // `;`-terminated members are state variables, events, errors and bodiless declarations.
+  let segment = open + 1;
+  for (let i = open + 1; i < end; i++) {
+    const ch = masked[i];
+    if (ch === ";") {
+      const line = lineAt(starts, segment + masked.slice(segment, i).search(/\S|$/));
+      const variable = parseStateVariable(masked.slice(segment, i), line);
+      if (variable) contract.stateVariables.push(variable);
+      segment = i + 1;
+    } else if (ch === "{") {
+      const fn = parseFunction(masked, starts, segment, i);
+      if (fn?.kind === "modifier") contract.modifiers.push(fn);
+      else if (fn) contract.functions.push(fn);
+      const blockEnd = matchingBracket(masked, i);
+      i = blockEnd < 0 ? end : blockEnd;
+      segment = i + 1;
+    }
+  }
+  return contract;
+}
+
+export function parseSoliditySource(file: string, content: string): SoliditySource {
+  const masked = maskSolidity(content);
+  const source = { file, content, masked, starts: lineStarts(content) };
+  const contracts: SolidityContract[] = [];
+  let parsedUntil = 0;
+  for (const match of masked.matchAll(/\b(abstract\s+contract|contract|library|interface)\s+(\w+)([^{;]*)\{/g)) {
+    // Solidity has no nested contracts; a match inside a parsed body is an identifier, not a declaration.
+    if ((match.index ?? 0) < parsedUntil) continue;
+    const open = (match.index ?? 0) + match[0].length - 1;
+    contracts.push(parseContract(source, match, open));
+    const close = matchingBracket(masked, open);
+    parsedUntil = close < 0 ? masked.length : close;
+  }
+  return { ...source, contracts };
+}
+
+export function buildSolidityProject(contents: Map<string, string>): SolidityProject {
+  const sources = [...contents].map(([file, content]) => parseSoliditySource(file, content));
+  const contracts = new Map<string, SolidityContract>();
+  for (const source of sources) {
+    for (const contract of source.contracts) contracts.set(contract.name, contract);
+  }
+  return { sources, contracts };
+}
+
+/** `contract` followed by every base it inherits from that the project defines, nearest first. */
+export function linearize(contract: SolidityContract, project: SolidityProject): SolidityContract[] {
+  const out: SolidityContract[] = [];
+  const visit = (current: SolidityContract): void => {
+    if (out.includes(current)) return;
+    out.push(current);
+    for (const base of current.bases) {
+      const parent = project.contracts.get(base);
+      if (parent) visit(parent);
+    }
+  };
+  visit(contract);
+  return out;
+}
+
+/** Line of `bodyIndex` within `fn`'s body. */
+export function functionLine(source: SoliditySource, fn: SolidityFunction, bodyIndex: number): number {
+  return lineAt(source.starts, fn.bodyOffset + bodyIndex);
+}
+
+/** Whether the function can be called from outside the contract. */
+export function isExternallyCallable(fn: SolidityFunction): boolean {
+  return fn.kind !== "constructor" && fn.kind !== "modifier" && (fn.visibility === "public" || fn.visibility === "external");
+}
+
+export function mutatesState(fn: SolidityFunction): boolean {
+  return fn.mutability !== "view" && fn.mutability !== "pure";
+}
+
+export function hasReentrancyGuard(fn: SolidityFunction): boolean {
+  return fn.modifiers.some((modifier) => REENTRANCY_GUARD.test(modifier));
+}
+
+/**
+ * Whether only a restricted caller reaches `fn`'s body: an access modifier, a `msg.sender`
+ * check in the body, or one in a modifier or internal function it calls (one level deep).
+ */
+export function checksCaller(fn: SolidityFunction, contract: SolidityContract, project: SolidityProject): boolean {
+  if (SENDER_CHECK.test(fn.body)) return true;
+  const lineage = linearize(contract, project);
+  for (const name of fn.modifiers) {
+    if (ACCESS_MODIFIER.test(name)) return true;
+    const modifier = lineage.flatMap((candidate) => candidate.modifiers).find((candidate) => candidate.name === name);
+    if (modifier && SENDER_CHECK.test(modifier.body)) return true;
+  }
+  // Hand-rolled initializers guard themselves with an `initialized` flag.
+  if (/^_?init/i.test(fn.name) && /!\s*_?initialized\b|\b_?initialized\s*==\s*false\b/.test(fn.body)) return true;
+  const helpers = lineage.flatMap((candidate) => candidate.functions).filter((candidate) => candidate.visibility === "internal" || candidate.visibility === "private");
+  return helpers.some((helper) => new RegExp(`\\b${escapeRegExp(helper.name)}\\s*\\(`).test(fn.body) && SENDER_CHECK.test(helper.body));
+}
+
+/** State variables of `contract` and its bases that can change after construction. */
+export function mutableStateVariables(contract: SolidityContract, project: SolidityProject): SolidityStateVariable[] {
+  return linearize(contract, project)
+    .flatMap((candidate) => candidate.stateVariables)
+    .filter((variable) => !variable.constant);
+}
+
+/** Assignments, compound assignments, `++`/`--`, `delete` and `push`/`pop` on state variables in `body`. */
+export function stateWrites(body: string, variables: SolidityStateVariable[]): StateWrite[] {
+  if (variables.length === 0) return [];
+  const names = variables.map((variable) => escapeRegExp(variable.name)).join("|");
+  const access = String.raw`(?<![\w.])(${names})\b((?:\s*\[[^\]]*\]|\s*\.\s*\w+)*)`;
+  const patterns = [
+    new RegExp(`${access}\\s*(?:[-+*/%|&^]|<<|>>)?=(?![=>])`, "g"),
+    new RegExp(`${access}\\s*(?:\\+\\+|--)`, "g"),
+    new RegExp(`(?:\\+\\+|--|\\bdelete\\s+)\\s*${access}`, "g"),
+    new RegExp(`${access}\\s*\\.\\s*(?:push|pop)\\s*\\(`, "g")
+  ];
+  const writes = new Map<number, StateWrite>();
+  for (const pattern of patterns) {
+    for (const match of body.matchAll(pattern)) {
+      const offset = (match.index ?? 0) + match[0].indexOf(match[1]);
+      // `uint256 fee = ..` declares a local that shadows a state variable; it writes nothing.
+      if (/\b(?:memory|storage|calldata|uint\d*|int\d*|address|bool|bytes\d*|string)\s+$/.test(body.slice(Math.max(0, offset - 24), offset))) continue;
+      writes.set(offset, { offset, variable: match[1], target: `${match[1]}${match[2].replace(/\s+/g, "")}` });
+    }
+  }
+  return [...writes.values()].sort((a, b) => a.offset - b.offset);
+}
+
+/** Start offset of the receiver expression ending just before `dot` (`payable(msg.sender)`, `tokens[i]`). */
+function receiverStart(body: string, dot: number): number {
+  let i = dot - 1;
+  while (i >= 0 && /\s/.test(body[i])) i--;
+  while (i >= 0) {
+    const ch = body[i];
+    if (ch === ")" || ch === "]") {
+      const openCh = ch === ")" ? "(" : "[";
+      let depth = 0;
+      for (; i >= 0; i--) {
+        if (body[i] === ch) depth++;
+        else if (body[i] === openCh && --depth === 0) break;
+      }
+      i--;
+    } else if (/[\w.]/.test(ch)) {
+      i--;
+    } else {
+      break;
+    }
+  }
+  return i + 1;
+}
+
+/**
+ * External calls in `body`: low-level `call`/`delegatecall`/`staticcall`, ether `send`/`transfer`
+ * (single argument), and member calls on interface casts (`IERC20(token).transfer(..)`) or on
+ * state variables of a contract or interface type.
+ */
+export function externalCalls(body: string, contractTyped: Set<string> = new Set()): ExternalCall[] {
+  const calls: ExternalCall[] = [];
+  for (const match of body.matchAll(/\.\s*(call|delegatecall|staticcall|send|transfer)\s*(\{[^{}]*\})?\s*\(/g)) {
+    const dot = match.index ?? 0;
+    const argsOpen = dot + match[0].length - 1;
+    const argsClose = matchingBracket(body, argsOpen);
+    const kind = match[1] as ExternalCallKind;
+    const args = argsClose < 0 ? [] : splitTopLevel(body.slice(argsOpen + 1, argsClose));
+    const offset = receiverStart(body, dot);
+    const receiver = body.slice(offset, dot).trim();
+    // Two-argument `transfer`/`send` are token calls, handled below as interface calls.
+    if ((kind === "send" || kind === "transfer") && args.length !== 1) continue;
+    calls.push({
+      kind,
+      offset,
+      end: argsClose < 0 ? body.length : argsClose + 1,
+      receiver,
+      value: kind === "send" || kind === "transfer" || /\bvalue\s*:/.test(match[2] ?? "")
+    });
+  }
+
+  const typed = [...contractTyped].map(escapeRegExp).join("|");
+  const interfaceCall = new RegExp(
+    String.raw`(?<![\w.])(?:[A-Z]\w*\s*\([^()]*(?:\([^()]*\)[^()]*)*\)${typed ? `|(?:${typed})\\b(?:\\s*\\[[^\\]]*\\])?` : ""})\s*\.\s*(\w+)\s*(?:\{[^{}]*\})?\s*\(`,
+    "g"
+  );
+  for (const match of body.matchAll(interfaceCall)) {
+    const offset = match.index ?? 0;
+    if (calls.some((call) => call.offset === offset) || /^(?:call|delegatecall|staticcall)$/.test(match[1])) continue;
+    const argsOpen = offset + match[0].length - 1;
+    const argsClose = matchingBracket(body, argsOpen);
+    calls.push({
+      kind: "interface",
+      offset,
+      end: argsClose < 0 ? body.length : argsClose + 1,
+      receiver: body.slice(offset, offset + match[0].length).replace(/\s*\.\s*\w+\s*(?:\{[^{}]*\})?\s*\($/, "").trim(),
+      value: /\{[^{}]*\bvalue\s*:/.test(match[0])
+    });
+  }
+  return calls.sort((a, b) => a.offset - b.offset);
+}
+
+/**
+ * Mutable state variables declared with a contract or interface type (`IRewarder public rewarder`).
+ * `immutable` ones are fixed at deployment and treated as trusted.
+ */
+export function contractTypedVariables(contract: SolidityContract, project: SolidityProject): Set<string> {
+  return new Set(
+    mutableStateVariables(contract, project)
+      .filter((variable) => project.contracts.has(variable.type) || /^I[A-Z]\w*$/.test(variable.type))
+      .map((variable) => variable.name)
+  );
+}
+
+/** Start of the statement containing `offset`: just past the previous `;`, `{` or `}`. */
+export function statementStart(body: string, offset: number): number {
+  return Math.max(body.lastIndexOf(";", offset - 1), body.lastIndexOf("{", offset - 1), body.lastIndexOf("}", offset - 1)) + 1;
+}
+
+/**
+ * Whether the success flag of the call is inspected: the call sits in `require`/`if`/`assert`/
+ * `return`, or its `bool` result is bound and the binding is read later in the body.
+ */
+export function callResultChecked(body: string, call: ExternalCall): boolean {
+  const prefix = body.slice(statementStart(body, call.offset), call.offset).replace(/\s+/g, " ").trim();
+  if (prefix === "") return false;
+  if (/\b(?:require|assert|if|return|while)\b|[!=]=|&&|\|\||!\s*$/.test(prefix)) return true;
+  const binding = prefix.match(/^\(\s*(?:bool\s+)?(\w+)\s*,[^)]*\)\s*=$/)?.[1] ?? prefix.match(/^(?:bool\s+)?(\w+)\s*=$/)?.[1];
+  if (!binding) return !/^\(\s*,/.test(prefix);
+  return new RegExp(`\\b${escapeRegExp(binding)}\\b`).test(body.slice(call.end));
+}
+
+/** Root identifier of a receiver expression: `target` for `address(target)`, `payable(target)` or `target`. */
+export function receiverRoot(receiver: string): string | undefined {
+  const unwrapped = receiver.replace(/^(?:address|payable)\s*\(\s*([\s\S]*)\)$/, "$1").replace(/^(?:address|payable)\s*\(\s*([\s\S]*)\)$/, "$1");
+  return unwrapped.match(/^(\w+)/)?.[1];
+}
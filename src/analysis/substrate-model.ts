@@ -0,0 +1,263 @@
+/**
+ * Structured model of Substrate code: ink! smart contracts and FRAME pallets.
+ *
+ * ink! contracts keep their state in the `#[ink(storage)]` struct and expose `#[ink(message)]`
+ * methods, whose caller is `self.env().caller()`. Pallets keep state in `#[pallet::storage]`
+ * items and expose dispatchables in their `#[pallet::call]` impl; the first parameter is the
+ * origin that `ensure_signed`, `ensure_root` or an `EnsureOrigin` turns into an authority.
+ * Hooks in `#[pallet::hooks]` run every block with no caller at all, bounded only by the
+ * weight they report.
+ */
+
+import { crateIdent, type RustCrateGraph } from "./rust-crates";
+import { isRustTestFile, productionFunctions, type RustFunction } from "./rust-items";
+import { escapeRegExp, lineStarts, matchingBracket, splitTopLevel, stripComments } from "./rust-source";
+
+export interface InkStorageField {
+  name: string;
+  type: string;
+}
+
+export interface InkMessage {
+  fn: RustFunction;
+  /** Whether the message takes `&mut self` */
+  mutates: boolean;
+}
+
+export interface InkContract {
+  file: string;
+  storage: InkStorageField[];
+  messages: InkMessage[];
+  constructors: RustFunction[];
+  /** Non-test functions of the contract file */
+  functions: RustFunction[];
+}
+
+export type PalletStorageKind = "value" | "map";
+
+export interface PalletCall {
+  fn: RustFunction;
+  /** Name of the origin parameter */
+  origin: string;
+  /** Expression of `#[pallet::weight(..)]`, when present */
+  weight?: string;
+}
+
+export interface Pallet {
+  file: string;
+  calls: PalletCall[];
+  hooks: RustFunction[];
+  /** Non-test functions of the pallet file */
+  functions: RustFunction[];
+}
+
+export interface SubstrateProject {
+  graph: RustCrateGraph;
+  contents: Map<string, string>;
+  contracts: InkContract[];
+  pallets: Pallet[];
+  /** `#[pallet::storage]` items of every pallet, by name */
+  storage: Map<string, PalletStorageKind>;
+}
+
+/** Origin checks a dispatchable performs. */
+export interface OriginChecks {
+  signed: boolean;
+  root: boolean;
+  /** `T::AdminOrigin::ensure_origin(..)` and other configured `EnsureOrigin`s */
+  configured: boolean;
+  /** Binding of `let who = ensure_signed(origin)?`, when one is kept */
+  signer?: string;
+}
+
+export interface StorageAccess {
+  offset: number;
+  store: string;
+  operation: string;
+}
+
+const SUBSTRATE_CRATES = new Set(["ink", "ink_lang", "ink_storage", "ink_env", "frame_support", "frame_system"]);
+const INK_STORAGE = /#\[\s*ink\s*\(\s*storage\s*\)\s*\][\s\S]*?\bstruct\s+\w+\s*\{/;
+const PALLET_STORAGE =
+  /\btype\s+(\w+)\s*(?:<[^=;]*>)?\s*=\s*(?:[\w:]*::)?(StorageValue|StorageMap|StorageDoubleMap|StorageNMap|CountedStorageMap)\s*</g;
+/** `Fee::<T>::put(..)`, `<Fee<T>>::put(..)` and the other storage mutators. */
+const PALLET_WRITE =
+  /(?:<\s*(\w+)\s*(?:<[^<>]*>)?\s*>|\b(\w+)(?:\s*::\s*<[^<>]*>)?)\s*::\s*(put|set|insert|mutate|try_mutate|mutate_exists|try_mutate_exists|remove|kill|take|append|swap|remove_all|clear|clear_prefix)\s*\(/g;
+const PALLET_ITERATION =
+  /(?:<\s*(\w+)\s*(?:<[^<>]*>)?\s*>|\b(\w+)(?:\s*::\s*<[^<>]*>)?)\s*::\s*(iter|iter_keys|iter_values|iter_prefix|iter_prefix_values|iter_from|drain|drain_prefix)\s*\(/g;
+const HOOKS = new Set(["on_initialize", "on_finalize", "on_idle", "on_runtime_upgrade"]);
+/** Storage mutation through an ink! storage field: `self.owner = ..`, `self.balances.insert(..)`. */
+const INK_WRITE = /\bself\s*\.\s*(\w+)\s*(?:(?:\.\s*(insert|remove|set|push|pop|take|clear|truncate|retain)\s*\()|([-+*/]?=)(?!=))/g;
+const OPERAND = String.raw`[A-Za-z_]\w*(?:\s*\.\s*[A-Za-z_]\w*)*(?:\s*\(\s*\))?`;
+const ARITHMETIC = new RegExp(String.raw`(\*?\s*${OPERAND})\s*([+-])(=?)\s*(?![>=])(${OPERAND})`, "g");
+const BALANCE_NAME = /balance|supply|allowance|stake|deposit|reserve|total/i;
+
+/** Whether a `Cargo.toml` depends on ink! or FRAME. */
+export function isSubstrateManifest(text: string): boolean {
+  return /^\s*(?:ink(?:_lang|_storage|_env)?|frame[-_]support|frame[-_]system)\s*=|^\s*\[(?:\w+\.)?dependencies\.(?:ink|frame[-_]support|frame[-_]system)\]/m.test(
+    text
+  );
+}
+
+/** Attribute text stacked above a function, back to the previous item or block boundary. */
+function attributesOf(code: string, starts: number[], fn: RustFunction): string {
+  const lineStart = starts[fn.line - 1] ?? 0;
+  const before = code.slice(0, lineStart);
+  const boundary = Math.max(before.lastIndexOf("}"), before.lastIndexOf(";"), before.lastIndexOf("{"));
+  return code.slice(boundary + 1, fn.bodyOffset);
+}
+
+/** Line range of the impl block carrying `attribute` (`#[pallet::call]`, `#[pallet::hooks]`). */
+function implBlockLines(code: string, starts: number[], attribute: RegExp): [number, number] | undefined {
+  const match = attribute.exec(code);
+  if (!match) return undefined;
+  const open = code.indexOf("{", code.indexOf("impl", match.index));
+  const close = open < 0 ? -1 : matchingBracket(code, open);
+  if (close < 0) return undefined;
+  const lineOf = (offset: number): number => starts.filter((start) => start <= offset).length;
+  return [lineOf(open), lineOf(close)];
+}
+
+function inkStorage(code: string): InkStorageField[] {
+  const header = INK_STORAGE.exec(code);
+  if (!header) return [];
+  const open = header.index + header[0].length - 1;
+  const close = matchingBracket(code, open);
+  const fields: InkStorageField[] = [];
+  for (const part of splitTopLevel(code.slice(open + 1, close < 0 ? code.length : close))) {
+    const field = part.replace(/#\[[^\]]*\]/g, "").match(/^\s*(?:pub(?:\s*\([^)]*\))?\s+)?(\w+)\s*:\s*([\s\S]+)$/);
+    if (field) fields.push({ name: field[1], type: field[2].replace(/\s+/g, " ").trim() });
+  }
+  return fields;
+}
+
+function inkContract(file: string, content: string): InkContract | undefined {
+  const code = stripComments(content);
+  if (!/#\[\s*ink\s*::\s*contract\b|#\[\s*ink\s*\(\s*storage\s*\)/.test(code)) return undefined;
+  const starts = lineStarts(code);
+  const functions = productionFunctions(content);
+  const messages: InkMessage[] = [];
+  const constructors: RustFunction[] = [];
+  for (const fn of functions) {
+    const attributes = attributesOf(code, starts, fn);
+    if (/#\[\s*ink\s*\(\s*message\b/.test(attributes)) {
+      messages.push({ fn, mutates: /&\s*mut\s+self\b/.test(code.slice(starts[fn.line - 1] ?? 0, fn.bodyOffset)) });
+    } else if (/#\[\s*ink\s*\(\s*constructor\b/.test(attributes)) {
+      constructors.push(fn);
+    }
+  }
+  return { file, storage: inkStorage(code), messages, constructors, functions };
+}
+
+function pallet(file: string, content: string, storage: Map<string, PalletStorageKind>): Pallet | undefined {
+  const code = stripComments(content);
+  if (!/#\[\s*pallet\s*::\s*(?:call|hooks|storage)\b/.test(code)) return undefined;
+  for (const decl of code.matchAll(PALLET_STORAGE)) storage.set(decl[1], decl[2] === "StorageValue" ? "value" : "map");
+
+  const starts = lineStarts(code);
+  const functions = productionFunctions(content);
+  const callBlock = implBlockLines(code, starts, /#\[\s*pallet\s*::\s*call\b[^\]]*\]/);
+  const hookBlock = implBlockLines(code, starts, /#\[\s*pallet\s*::\s*hooks\s*\]/);
+  const within = (fn: RustFunction, block?: [number, number]): boolean => Boolean(block && fn.line > block[0] && fn.line < block[1]);
+
+  const calls: PalletCall[] = [];
+  for (const fn of functions) {
+    if (!within(fn, callBlock)) continue;
+    const origin = fn.params[0];
+    if (!origin || !/Origin/.test(origin.type)) continue;
+    const weight = attributesOf(code, starts, fn).match(/#\[\s*pallet\s*::\s*weight\s*\(([\s\S]*)\)\s*\]/)?.[1];
+    calls.push({ fn, origin: origin.name, weight: weight?.replace(/\s+/g, " ").trim() });
+  }
+  const hooks = functions.filter((fn) => within(fn, hookBlock) && HOOKS.has(fn.name));
+  return { file, calls, hooks, functions };
+}
+
+/** ink! contracts and pallets of the Substrate crates among `contents`. */
+export function buildSubstrateProject(graph: RustCrateGraph, contents: Map<string, string>): SubstrateProject {
+  const substrateCrates = new Set(
+    graph.crates.filter((crate) => crate.dependencies.some((dependency) => SUBSTRATE_CRATES.has(crateIdent(dependency)))).map((crate) => crate.name)
+  );
+  const contracts: InkContract[] = [];
+  const pallets: Pallet[] = [];
+  const storage = new Map<string, PalletStorageKind>();
+  for (const [file, content] of contents) {
+    if (isRustTestFile(file)) continue;
+    if (substrateCrates.size > 0 && !substrateCrates.has(graph.modules.get(file)?.crate ?? "")) continue;
+    const contract = inkContract(file, content);
+    if (contract) contracts.push(contract);
+    const candidate = pallet(file, content, storage);
+    if (candidate) pallets.push(candidate);
+  }
+  return { graph, contents, contracts, pallets, storage };
+}
+
+/** Storage fields a message body writes through `self`. */
+export function inkStorageWrites(body: string, storage: InkStorageField[]): StorageAccess[] {
+  const fields = new Set(storage.map((field) => field.name));
+  return [...body.matchAll(INK_WRITE)]
+    .filter((match) => fields.size === 0 || fields.has(match[1]))
+    .map((match) => ({ offset: match.index ?? 0, store: match[1], operation: match[2] ?? match[3] }));
+}
+
+/** Whether `body` reads the caller, directly or through a `self.helper()` it calls. */
+export function readsCaller(body: string, functions: RustFunction[]): boolean {
+  const caller = /\benv\s*\(\s*\)\s*\.\s*caller\s*\(/;
+  if (caller.test(body)) return true;
+  const helpers = new Set([...body.matchAll(/\b(?:self|Self)\s*(?:\.|::)\s*(\w+)\s*\(/g)].map((match) => match[1]));
+  return functions.some((fn) => helpers.has(fn.name) && caller.test(fn.body));
+}
+
+export function originChecks(call: PalletCall): OriginChecks {
+  const body = call.fn.body;
+  const origin = escapeRegExp(call.origin);
+  const signed = new RegExp(String.raw`\bensure_signed\s*\(\s*${origin}\s*\)`);
+  const binding = body.match(new RegExp(String.raw`\blet\s+(?:mut\s+)?(\w+)\s*=\s*ensure_signed\s*\(\s*${origin}\s*\)`));
+  return {
+    signed: signed.test(body),
+    root: new RegExp(String.raw`\bensure_root\s*\(\s*${origin}\s*\)`).test(body),
+    configured: /::\s*ensure_origin\s*\(|::\s*try_origin\s*\(/.test(body),
+    signer: binding && !binding[1].startsWith("_") ? binding[1] : undefined
+  };
+}
+
+/** Whether a dispatchable compares its signer with stored authority (`ensure!(who == admin)`, `Admins::contains_key(&who)`). */
+export function checksSigner(body: string, signer: string): boolean {
+  const who = escapeRegExp(signer);
+  return new RegExp(
+    String.raw`(?:==|!=)\s*(?:&\s*)?(?:Some\s*\(\s*)?${who}\b|\b${who}\s*(?:==|!=)|\bcontains_key\s*\([^;]*\b${who}\b|\b\w*(?:owner|admin|auth|only|permission|role|member|council)\w*\s*\([^;]*\b${who}\b`,
+    "i"
+  ).test(body);
+}
+
+function palletAccesses(body: string, pattern: RegExp, storage: Map<string, PalletStorageKind>): StorageAccess[] {
+  return [...body.matchAll(pattern)]
+    .map((match) => ({ offset: match.index ?? 0, store: match[1] ?? match[2], operation: match[3] }))
+    .filter((access) => storage.has(access.store));
+}
+
+export function palletWrites(body: string, storage: Map<string, PalletStorageKind>): StorageAccess[] {
+  return palletAccesses(body, PALLET_WRITE, storage);
+}
+
+export function palletIterations(body: string, storage: Map<string, PalletStorageKind>): StorageAccess[] {
+  return palletAccesses(body, PALLET_ITERATION, storage);
+}
+
+/**
+ * Plain `+`/`-` on balance-like values (`from_balance - value`, `*total_supply += amount`)
+ * that no earlier comparison of the same operands guards.
+ */
+export function uncheckedBalanceArithmetic(body: string): { offset: number; operator: "+" | "-"; expression: string }[] {
+  const ops: { offset: number; operator: "+" | "-"; expression: string }[] = [];
+  for (const match of body.matchAll(ARITHMETIC)) {
+    const left = match[1].replace(/^\*\s*/, "").replace(/\s+/g, "");
+    const right = match[4].replace(/\s+/g, "");
+    if (!BALANCE_NAME.test(left) && !BALANCE_NAME.test(right)) continue;
+    const before = body.slice(0, match.index ?? 0);
+    const [a, b] = [left, right].map((operand) => escapeRegExp(operand.split(".").pop()!.replace(/\(\)$/, "")));
+    const guard = new RegExp(String.raw`\b${a}\b[^;{]*[<>]=?[^;{]*\b${b}\b|\b${b}\b[^;{]*[<>]=?[^;{]*\b${a}\b`);
+    if (guard.test(before)) continue;
+    ops.push({ offset: match.index ?? 0, operator: match[2] as "+" | "-", expression: `${left} ${match[2]}${match[3]} ${right}` });
+  }
+  return ops;
+}
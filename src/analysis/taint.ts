@@ -0,0 +1,452 @@
+/**
+ * Intra-procedural taint tracking for Solana instruction handlers.
+ *
+ * Values the caller chooses (instruction arguments, and data read from accounts nothing vouches
+ * for) are followed through `let` bindings and assignments in source order until they reach a
+ * sink that decides which address, program, signature or amount an instruction acts on. Each
+ * flow keeps the bindings it passed through, so findings can show the path from source to sink.
+ */
+
+import { getConstraint, type AnchorAccountsStruct, type AnchorInstruction } from "./anchor-model";
+import { bodyLine, type RustFunction, type RustParam } from "./rust-items";
+import { escapeRegExp, matchingBracket, splitTopLevel } from "./rust-source";
+
+export type TaintSourceKind = "instruction_arg" | "account_data";
+
+export type TaintSinkKind = "pda_seed" | "cpi_program" | "signer_seed" | "lamports" | "token_amount" | "realloc_size";
+
+export interface TaintStep {
+  file: string;
+  line: number;
+  label: string;
+}
+
+export interface TaintSource {
+  kind: TaintSourceKind;
+  /** Argument name, or the account whose data is read */
+  name: string;
+  /** Declared type, for arguments */
+  type?: string;
+  /** Whether the caller picks the length as well as the bytes: byte vectors, strings and raw account data */
+  variableLength: boolean;
+}
+
+export interface TaintFlow {
+  source: TaintSource;
+  sink: TaintSinkKind;
+  file: string;
+  line: number;
+  /** Call or constraint the sink belongs to, e.g. `invoke_signed()` */
+  text: string;
+  /** The sink expression itself: the seeds array, program id or amount */
+  expr: string;
+  /** Source first, sink last */
+  path: TaintStep[];
+}
+
+/** A sink reached in a handler body, with the flow that taints it if there is one. */
+export interface TaintSinkSite {
+  sink: TaintSinkKind;
+  file: string;
+  line: number;
+  text: string;
+  flow?: TaintFlow;
+  /** For `cpi_program` sinks: the program id is a constant, or was compared to one before the call */
+  pinned: boolean;
+}
+
+export interface TaintOptions {
+  /** Parameters whose values the caller chooses */
+  args: RustParam[];
+  /** Accounts whose data nothing vouches for: `ctx.accounts` field names, or native account bindings */
+  uncheckedAccounts: string[];
+  /** The `Context<T>` parameter, so `ctx.accounts.<name>` paths resolve; absent for native processors */
+  contextParam?: string;
+}
+
+interface Tainted {
+  source: TaintSource;
+  path: TaintStep[];
+}
+
+interface Builder {
+  program?: string;
+  taint?: Tainted;
+  pinned: boolean;
+}
+
+const SINK_LABEL: Record<TaintSinkKind, string> = {
+  pda_seed: "PDA seeds",
+  cpi_program: "program id",
+  signer_seed: "signer seeds",
+  lamports: "lamport amount",
+  token_amount: "token amount",
+  realloc_size: "new size"
+};
+
+/** Calls whose `arg`-th argument is a sink. `invoke_signed` also carries a program id, handled with `invoke`. */
+const SINK_CALLS: { pattern: RegExp; sink: TaintSinkKind; arg: number }[] = [
+  { pattern: /\b(?:Pubkey\s*::\s*)?(?:try_)?find_program_address\s*\(/g, sink: "pda_seed", arg: 0 },
+  { pattern: /\b(?:Pubkey\s*::\s*)?create_program_address\s*\(/g, sink: "pda_seed", arg: 0 },
+  { pattern: /\binvoke_signed(?:_unchecked)?\s*\(/g, sink: "signer_seed", arg: 2 },
+  { pattern: /\bCpiContext\s*::\s*new_with_signer\s*\(/g, sink: "signer_seed", arg: 2 },
+  { pattern: /\.\s*with_signer\s*\(/g, sink: "signer_seed", arg: 0 },
+  { pattern: /\bCpiContext\s*::\s*new(?:_with_signer)?\s*\(/g, sink: "cpi_program", arg: 0 },
+  { pattern: /\bsystem_instruction\s*::\s*transfer\s*\(/g, sink: "lamports", arg: 2 },
+  { pattern: /\bsystem_program\s*::\s*transfer\s*\(/g, sink: "lamports", arg: 1 },
+  { pattern: /\b(?:token|token_interface|token_2022)\s*::\s*(?:transfer|transfer_checked|mint_to|burn)\s*\(/g, sink: "token_amount", arg: 1 },
+  { pattern: /\bspl_token(?:_2022)?\s*::\s*instruction\s*::\s*(?:transfer|mint_to|burn)\s*\(/g, sink: "token_amount", arg: 5 },
+  { pattern: /\bspl_token(?:_2022)?\s*::\s*instruction\s*::\s*transfer_checked\s*\(/g, sink: "token_amount", arg: 6 },
+  { pattern: /\.\s*realloc\s*\(/g, sink: "realloc_size", arg: 0 }
+];
+
+const INVOKE = /\binvoke(?:_signed)?(?:_unchecked)?\s*\(/g;
+const LAMPORT_WRITE =
+  /\*\*\s*[\w.]+?(?:\s*\.\s*to_account_info\s*\(\s*\))?\s*\.\s*(?:try_borrow_mut_lamports\s*\(\s*\)\s*\??|lamports\s*\.\s*borrow_mut\s*\(\s*\))\s*[+-]=/g;
+const BINDING = /\b(if\s+|while\s+)?let\s+([^=;]+?)\s*=(?![=>])/g;
+const ASSIGNMENT = /(?:^|[;{}])\s*\*?\s*(\w+)((?:\s*\.\s*\w+)*)\s*([+\-*/|&^]?)=(?![=>])/g;
+const CHECK = /\b(?:require_keys_eq|require_eq|assert_keys_eq|assert_eq)!\s*\(|\brequire!\s*\(|\bif\s+(?!let\b)/g;
+/** System program builders always target the system program. */
+const SYSTEM_BUILDER = /\bsystem_instruction\s*::/;
+const PINNED_PROGRAM = /^(?:\w+::)*(?:[A-Z][A-Z0-9_]*|id\s*\(\s*\))$|^(?:\w+\.)?program_id$/;
+
+/** An account key among seeds, usually the signer's, which confines a derivation to that account's PDAs. */
+export const KEY_SEED = /\.\s*key\s*(?:\(\s*\))?|\.\s*to_bytes\s*\(\s*\)/;
+
+function argStep(file: string, line: number, arg: RustParam): TaintStep {
+  return { file, line, label: `instruction argument \`${arg.name}: ${arg.type}\`` };
+}
+
+function argSource(arg: RustParam): TaintSource {
+  return { kind: "instruction_arg", name: arg.name, type: arg.type, variableLength: /\b(?:String|str|Vec)\b|\[\s*u8\s*\]/.test(arg.type) };
+}
+
+function mentions(expr: string, name: string): boolean {
+  return new RegExp(`(?<![\\w.])${escapeRegExp(name)}\\b`).test(expr);
+}
+
+function snippet(expr: string): string {
+  const flat = expr.replace(/\s+/g, " ").trim();
+  return flat.length > 60 ? `${flat.slice(0, 57)}...` : flat;
+}
+
+/** End of the statement starting at `from`: the next top-level `;`, or the brace closing the enclosing block. */
+function statementEnd(body: string, from: number, stopAtBlock = false): number {
+  let depth = 0;
+  for (let i = from; i < body.length; i++) {
+    const ch = body[i];
+    if (ch === '"') {
+      i++;
+      while (i < body.length && body[i] !== '"') {
+        if (body[i] === "\\") i++;
+        i++;
+      }
+      continue;
+    }
+    if (ch === "{" && depth === 0 && stopAtBlock) return i;
+    if (ch === "(" || ch === "[" || ch === "{") depth++;
+    else if (ch === ")" || ch === "]" || ch === "}") {
+      if (depth === 0) return i;
+      depth--;
+    } else if (ch === ";" && depth === 0) return i;
+  }
+  return body.length;
+}
+
+function callArgs(body: string, openParen: number): string[] {
+  const close = matchingBracket(body, openParen);
+  return splitTopLevel(body.slice(openParen + 1, close < 0 ? body.length : close));
+}
+
+/** Bound names of a `let` pattern, e.g. `(pda, bump)` or `mut ix: Instruction`. */
+function patternNames(pattern: string): string[] {
+  const bare = pattern.split(/(?<!:):(?!:)/)[0];
+  return [...bare.matchAll(/\b[a-z_][a-z0-9_]*\b/g)].map((match) => match[0]).filter((name) => !["mut", "ref", "_"].includes(name));
+}
+
+/** Program id expression of an instruction builder: `Instruction { program_id, .. }` or `Instruction::new_with_*(id, ..)`. */
+function builderProgram(body: string, expr: string, exprOffset: number): string | undefined {
+  const literal = expr.match(/\bInstruction\s*\{/);
+  if (literal) {
+    const open = exprOffset + (literal.index ?? 0) + literal[0].length - 1;
+    const close = matchingBracket(body, open);
+    for (const field of splitTopLevel(body.slice(open + 1, close < 0 ? body.length : close))) {
+      const [key, value] = field.split(/(?<!:):(?!:)/);
+      if (key.trim() === "program_id") return (value ?? key).trim();
+    }
+    return undefined;
+  }
+  const call = expr.match(/\b(?:Instruction\s*::\s*new_with_\w+|\w+\s*::\s*instruction\s*::\s*\w+)\s*\(/);
+  if (!call) return undefined;
+  return callArgs(body, exprOffset + (call.index ?? 0) + call[0].length - 1)[0];
+}
+
+/** Trace instruction arguments and unchecked account data through one handler body into its sinks. */
+export function traceTaint(content: string, file: string, fn: RustFunction, options: TaintOptions): TaintSinkSite[] {
+  const body = fn.body;
+  const lineOf = (offset: number): number => bodyLine(content, fn, offset);
+
+  const tainted = new Map<string, Tainted>();
+  for (const arg of options.args) {
+    tainted.set(arg.name, { source: argSource(arg), path: [argStep(file, fn.line, arg)] });
+  }
+  const pinned = new Set<string>();
+  const builders = new Map<string, Builder>();
+
+  // References to unchecked accounts: `ctx.accounts.<name>` paths, native bindings and `let` aliases of either.
+  const refs = new Map<string, string>();
+  for (const name of options.uncheckedAccounts) {
+    refs.set(options.contextParam ? `${options.contextParam}.accounts.${name}` : name, name);
+  }
+  if (options.contextParam) {
+    const alias = new RegExp(
+      `\\blet\\s+(?:mut\\s+)?(\\w+)\\s*(?::[^=]+)?=\\s*&?\\s*(?:mut\\s+)?${escapeRegExp(options.contextParam)}\\.accounts\\.(\\w+)(?:\\.to_account_info\\(\\))?\\s*;`,
+      "g"
+    );
+    for (const match of body.matchAll(alias)) {
+      if (options.uncheckedAccounts.includes(match[2])) refs.set(match[1], match[2]);
+    }
+  }
+  const dataReads = [...refs].map(([ref, account]) => ({
+    account,
+    pattern: new RegExp(
+      `(?<![\\w.])${escapeRegExp(ref)}\\s*(?:\\.\\s*to_account_info\\s*\\(\\s*\\))?\\s*\\.\\s*(?:data|try_borrow_data|try_borrow_mut_data)\\b`
+    )
+  }));
+
+  const taintOf = (expr: string, offset: number): Tainted | undefined => {
+    const read = dataReads.find((candidate) => candidate.pattern.test(expr));
+    if (read) {
+      return {
+        source: { kind: "account_data", name: read.account, variableLength: true },
+        path: [{ file, line: lineOf(offset), label: `data of unchecked account \`${read.account}\` read` }]
+      };
+    }
+    let best: Tainted | undefined;
+    for (const [name, taint] of tainted) {
+      if (!mentions(expr, name)) continue;
+      if (!best || (taint.source.variableLength && !best.source.variableLength)) best = taint;
+    }
+    return best;
+  };
+
+  // Sink expressions are reported with single-name bindings expanded, e.g. `&[signer_seeds]` shows its seeds.
+  const bound = new Map<string, string>();
+  const expand = (expr: string): string =>
+    expr.replace(/(?<![\w.:])[a-z_]\w*\b(?!\s*[(!:])/g, (name) => bound.get(name) ?? name).replace(/\s+/g, " ").trim();
+
+  const isPinned = (expr: string): boolean => {
+    const bare = expr.replace(/^[&*\s]+/, "").replace(/\s*\.\s*(?:key|clone)\s*\(\s*\)$/, "").trim();
+    return PINNED_PROGRAM.test(bare) || pinned.has(bare);
+  };
+
+  const sites: TaintSinkSite[] = [];
+  const reach = (
+    sink: TaintSinkKind,
+    offset: number,
+    text: string,
+    expr: string,
+    taint: Tainted | undefined,
+    isPinnedSink = false
+  ): void => {
+    const line = lineOf(offset);
+    const site: TaintSinkSite = { sink, file, line, text, pinned: isPinnedSink };
+    if (taint && !isPinnedSink) {
+      site.flow = {
+        source: taint.source,
+        sink,
+        file,
+        line,
+        text,
+        expr: expand(expr),
+        path: [...taint.path, { file, line, label: `reaches the ${SINK_LABEL[sink]} of ${text}` }]
+      };
+    }
+    sites.push(site);
+  };
+
+  const events: { offset: number; run: () => void }[] = [];
+
+  for (const match of body.matchAll(BINDING)) {
+    const start = (match.index ?? 0) + match[0].length;
+    const expr = body.slice(start, statementEnd(body, start, Boolean(match[1])));
+    const names = patternNames(match[2]);
+    events.push({
+      offset: match.index ?? 0,
+      run: () => {
+        const taint = taintOf(expr, start);
+        const line = lineOf(match.index ?? 0);
+        if (names.length === 1) bound.set(names[0], expand(expr));
+        for (const name of names) {
+          pinned.delete(name);
+          if (taint) {
+            tainted.set(name, { source: taint.source, path: [...taint.path, { file, line, label: `\`${name}\` = ${snippet(expr)}` }] });
+          } else {
+            tainted.delete(name);
+          }
+        }
+
+        const program = names.length === 1 ? builderProgram(body, expr, start) : undefined;
+        if (names.length === 1 && SYSTEM_BUILDER.test(expr)) {
+          builders.set(names[0], { pinned: true });
+        } else if (program !== undefined) {
+          const programTaint = taintOf(program, start);
+          builders.set(names[0], {
+            program,
+            pinned: isPinned(program),
+            taint: programTaint && {
+              source: programTaint.source,
+              path: [...programTaint.path, { file, line, label: `\`${names[0]}\` built with program id \`${snippet(program)}\`` }]
+            }
+          });
+        }
+      }
+    });
+  }
+
+  for (const match of body.matchAll(ASSIGNMENT)) {
+    const [name, fields, op] = [match[1], match[2].replace(/\s+/g, ""), match[3]];
+    if (["let", "return", "mut"].includes(name)) continue;
+    const start = (match.index ?? 0) + match[0].length;
+    const expr = body.slice(start, statementEnd(body, start));
+    const offset = (match.index ?? 0) + match[0].indexOf(name);
+    events.push({
+      offset,
+      run: () => {
+        const taint = taintOf(expr, start);
+        const line = lineOf(offset);
+        if (fields === ".program_id" && builders.has(name)) {
+          builders.set(name, {
+            program: expr.trim(),
+            pinned: isPinned(expr),
+            taint: taint && { source: taint.source, path: [...taint.path, { file, line, label: `\`${name}.program_id\` = ${snippet(expr)}` }] }
+          });
+        }
+        if (taint) {
+          tainted.set(name, { source: taint.source, path: [...taint.path, { file, line, label: `\`${name}${fields}\` ${op}= ${snippet(expr)}` }] });
+        } else if (!fields && !op) {
+          tainted.delete(name);
+        }
+      }
+    });
+  }
+
+  // Equality against an untainted value pins a name: `require_keys_eq!(program, ID)`, `if *program.key != id() { .. }`.
+  for (const match of body.matchAll(CHECK)) {
+    const start = (match.index ?? 0) + match[0].length;
+    const condition = match[0].startsWith("if")
+      ? body.slice(start, statementEnd(body, start, true))
+      : callArgs(body, start - 1).slice(0, match[0].startsWith("require!") ? 1 : 2).join(" == ");
+    events.push({
+      offset: match.index ?? 0,
+      run: () => {
+        for (const comparison of condition.split(/&&|\|\|/)) {
+          const sides = comparison.split(/==|!=/);
+          if (sides.length !== 2) continue;
+          const [left, right] = sides.map((side) => side.trim());
+          for (const [side, other] of [[left, right], [right, left]]) {
+            if (!side || taintOf(other, start)) continue;
+            const root = side.replace(/^[&*\s]+/, "").replace(/\s*\.\s*(?:key|clone)\s*\(\s*\)$/, "").trim();
+            if (!/^[\w.]+$/.test(root)) continue;
+            tainted.delete(root);
+            pinned.add(root);
+          }
+        }
+      }
+    });
+  }
+
+  for (const rule of SINK_CALLS) {
+    for (const match of body.matchAll(rule.pattern)) {
+      const open = (match.index ?? 0) + match[0].length - 1;
+      const expr = callArgs(body, open)[rule.arg];
+      if (expr === undefined) continue;
+      const text = match[0].replace(/^\.\s*/, "").replace(/\s+/g, "").replace(/\($/, "()");
+      events.push({
+        offset: match.index ?? 0,
+        run: () => reach(rule.sink, match.index ?? 0, text, expr, taintOf(expr, open), rule.sink === "cpi_program" && isPinned(expr))
+      });
+    }
+  }
+
+  for (const match of body.matchAll(INVOKE)) {
+    const open = (match.index ?? 0) + match[0].length - 1;
+    const instruction = callArgs(body, open)[0] ?? "";
+    const text = match[0].replace(/\s+/g, "").replace(/\($/, "()");
+    events.push({
+      offset: match.index ?? 0,
+      run: () => {
+        const name = instruction.replace(/^&\s*/, "").trim();
+        const builder = builders.get(name);
+        if (builder) {
+          reach("cpi_program", match.index ?? 0, text, builder.program ?? name, builder.taint, builder.pinned);
+          return;
+        }
+        // Instructions from unrecognised helpers hide their program id; only inline builders are traced.
+        const program = builderProgram(body, instruction, body.indexOf(instruction, open));
+        const pinnedProgram = SYSTEM_BUILDER.test(instruction) || (program !== undefined && isPinned(program));
+        reach("cpi_program", match.index ?? 0, text, program ?? instruction, program === undefined ? undefined : taintOf(program, open), pinnedProgram);
+      }
+    });
+  }
+
+  for (const match of body.matchAll(LAMPORT_WRITE)) {
+    const start = (match.index ?? 0) + match[0].length;
+    const expr = body.slice(start, statementEnd(body, start));
+    events.push({
+      offset: match.index ?? 0,
+      run: () => reach("lamports", match.index ?? 0, "lamport balance update", expr, taintOf(expr, start))
+    });
+  }
+
+  for (const event of events.sort((a, b) => a.offset - b.offset)) event.run();
+  return sites;
+}
+
+/**
+ * Flows from handler arguments into `seeds` and `realloc` constraints of its Accounts struct.
+ * `#[instruction(..)]` binds the handler's leading arguments by position, so names may differ.
+ */
+export function constraintTaint(struct: AnchorAccountsStruct, instruction: AnchorInstruction): TaintFlow[] {
+  const flows: TaintFlow[] = [];
+  const bound = struct.instructionArgs
+    .map((name, index) => ({ name, arg: instruction.args[index] }))
+    .filter((binding): binding is { name: string; arg: RustParam } => binding.arg !== undefined);
+
+  for (const field of struct.fields) {
+    for (const [key, sink] of [["seeds", "pda_seed"], ["realloc", "realloc_size"]] as const) {
+      const constraint = getConstraint(field, key);
+      if (!constraint?.value) continue;
+      const used = bound.filter((binding) => mentions(constraint.value!, binding.name));
+      const binding = used.find((candidate) => argSource(candidate.arg).variableLength) ?? used[0];
+      if (!binding) continue;
+
+      const text = `${struct.name}.${field.name} ${key}`;
+      flows.push({
+        source: argSource(binding.arg),
+        sink,
+        file: struct.file,
+        line: constraint.line,
+        text,
+        expr: constraint.value,
+        path: [
+          argStep(instruction.file, instruction.line, binding.arg),
+          { file: struct.file, line: struct.line, label: `passed to ${struct.name} as #[instruction] argument \`${binding.name}\`` },
+          { file: struct.file, line: constraint.line, label: `reaches the ${SINK_LABEL[sink]} of ${text}` }
+        ]
+      });
+    }
+  }
+
+  return flows;
+}
+
+/** The flows of sink sites that are tainted. */
+export function taintedFlows(sites: TaintSinkSite[]): TaintFlow[] {
+  return sites.flatMap((site) => (site.flow ? [site.flow] : []));
+}
+
+/** One-line rendering of a flow for finding evidence. */
+export function formatTaintPath(flow: TaintFlow): string {
+  return flow.path.map((step) => `${step.label} (line ${step.line})`).join(" -> ");
+}
@@ -4,6 +4,9 @@ import { runDiffScan, runFullScan } from "../orchestrator/run-scan";
 import { startOrchestratorDaemon } from "../orchestrator/daemon";
 import { toMarkdownReport } from "../output/report";
 import { toSarif } from "../output/sarif";
+import { toDot, toMermaid } from "../output/graph";
+import { loadPrivilegeModels } from "../agents/scanner/solana-privilege";
+import { buildAuthorityGraph } from "../analysis/authority-graph";
 import { startGitHubApp } from "../integrations/github-app";
 import type { ScanResult } from "../types";
 
@@ -32,6 +35,7 @@ function usage(): string {
     "  hydra-audit scan [targetPath] [--mode full|diff] [--base-ref ref] [--head-ref ref] [--json] [--sarif path]",
     "  hydra-audit diff [targetPath] [--base-ref ref] [--head-ref ref] [--json] [--sarif path]",
     "  hydra-audit report <scan-result.json> [--format markdown|json|sarif] [--output path]",
+    "  hydra-audit graph [targetPath] [--format mermaid|dot|json] [--output path]",
     "  hydra-audit config [--show] [--set key=value] [--init]",
     "  hydra-audit daemon [--host 127.0.0.1] [--port 8787] [--allow-insecure-defaults]",
     "  hydra-audit github-app [--port 3000]",
@@ -41,6 +45,7 @@ function usage(): string {
     "  scan       Run a full or differential security scan",
     "  diff       Shorthand for 'scan --mode diff'",
     "  report     Generate a report from a saved scan result JSON",
+    "  graph      Export the signer / PDA / CPI authority graph of a Solana program",
     "  config     Show or modify scanner configuration",
     "  daemon     Start the orchestrator HTTP daemon",
     "  github-app Start the GitHub App webhook listener",
@@ -51,6 +56,7 @@ function usage(): string {
     "  hydra-audit scan . --sarif out.sarif.json",
     "  hydra-audit diff . --base-ref HEAD~3 --json",
     "  hydra-audit report scan-result.json --format sarif --output report.sarif.json",
+    "  hydra-audit graph . --format dot --output authority.dot",
     "  hydra-audit config --show",
     "  hydra-audit config --init",
     "  hydra-audit config --set min_confidence=60",
@@ -162,6 +168,44 @@ async function handleReport(args: string[]): Promise<void> {
   }
 }
 
+async function handleGraph(args: string[]): Promise<void> {
+  const targetPath = path.resolve(args.find((arg) => !arg.startsWith("--")) ?? ".");
+  const format = getOptionValue(args, "--format") ?? "mermaid";
+  const outputPath = getOptionValue(args, "--output");
+
+  const { models } = await loadPrivilegeModels(targetPath);
+  if (models.length === 0) {
+    console.error(`No Anchor instructions with an Accounts struct found under ${targetPath}.`);
+    process.exitCode = 1;
+    return;
+  }
+  const graph = buildAuthorityGraph(models);
+
+  let output: string;
+  switch (format) {
+    case "mermaid":
+      output = toMermaid(graph);
+      break;
+    case "dot":
+      output = toDot(graph);
+      break;
+    case "json":
+      output = JSON.stringify(graph, null, 2);
+      break;
+    default:
+      console.error(`Invalid --format value: ${format}. Expected mermaid, dot, or json.`);
+      process.exitCode = 1;
+      return;
+  }
+
+  if (outputPath) {
+    await fs.writeFile(path.resolve(outputPath), output + "\n", "utf8");
+    console.log(`Graph written to: ${path.resolve(outputPath)}`);
+  } else {
+    console.log(output);
+  }
+}
+
 async function loadConfig(dir?: string): Promise<{ config: HydraConfig; configPath: string }> {
   const searchDir = dir ?? process.cwd();
   const configPath = path.join(searchDir, CONFIG_FILE_NAME);
@@ -309,6 +353,9 @@ async function main(): Promise<void> {
     case "report":
       await handleReport(args);
       return;
+    case "graph":
+      await handleGraph(args);
+      return;
     case "config":
       await handleConfig(args);
       return;
@@ -1,25 +1,9 @@
-import type { Finding, Severity, VulnClass } from "../types";
+import type { Finding, Severity } from "../types";
 import { makeFinding } from "../agents/scanner/base";
+import { isVulnClass, vulnClassInfo } from "../vulns/registry";
 
 const VALID_SEVERITIES = new Set<Severity>(["CRITICAL", "HIGH", "MEDIUM", "LOW"]);
 
-const VALID_VULN_CLASSES = new Set<VulnClass>([
-  "hardcoded_secret",
-  "command_injection",
-  "sql_injection",
-  "xss",
-  "insecure_deserialization",
-  "missing_signer_check",
-  "missing_has_one",
-  "account_type_confusion",
-  "arbitrary_cpi",
-  "cpi_signer_seed_bypass",
-  "cpi_reentrancy",
-  "non_canonical_bump",
-  "seed_collision",
-  "attacker_controlled_seed"
-]);
-
 export interface ParseResult {
   findings: Finding[];
   errors: string[];
@@ -45,10 +29,6 @@ function isValidSeverity(value: unknown): value is Severity {
   return typeof value === "string" && VALID_SEVERITIES.has(value as Severity);
 }
 
-function isValidVulnClass(value: unknown): value is VulnClass {
-  return typeof value === "string" && VALID_VULN_CLASSES.has(value as VulnClass);
-}
-
 interface RawFinding {
   vuln_class?: unknown;
   severity?: unknown;
@@ -58,6 +38,13 @@ interface RawFinding {
   description?: unknown;
   evidence?: unknown;
   confidence?: unknown;
+  program_module?: unknown;
+  instruction?: unknown;
+  account_field?: unknown;
+}
+
+function optionalString(value: unknown): string | undefined {
+  return typeof value === "string" && value.length > 0 ? value : undefined;
 }
 
 function validateAndConvert(
@@ -65,10 +52,12 @@ function validateAndConvert(
   index: number,
   scannerId: string
 ): { finding?: Finding; error?: string } {
-  if (!isValidVulnClass(raw.vuln_class)) {
+  if (!isVulnClass(raw.vuln_class)) {
     return { error: `finding[${index}]: invalid vuln_class "${String(raw.vuln_class)}"` };
   }
-  if (!isValidSeverity(raw.severity)) {
+  // A finding that leaves out its severity gets the class default; one that names an unknown severity is rejected.
+  const severity = raw.severity === undefined ? vulnClassInfo(raw.vuln_class).defaultSeverity : raw.severity;
+  if (!isValidSeverity(severity)) {
     return { error: `finding[${index}]: invalid severity "${String(raw.severity)}"` };
   }
   if (typeof raw.file !== "string" || raw.file.length === 0) {
@@ -87,13 +76,16 @@ function validateAndConvert(
   const finding = makeFinding({
     scannerId,
     vulnClass: raw.vuln_class,
-    severity: raw.severity,
+    severity,
     confidence,
     file: raw.file,
     line: raw.line,
     title: raw.title,
     description: typeof raw.description === "string" ? raw.description : raw.title,
-    evidence: typeof raw.evidence === "string" ? raw.evidence : "LLM-generated finding"
+    evidence: typeof raw.evidence === "string" ? raw.evidence : "LLM-generated finding",
+    programModule: optionalString(raw.program_module),
+    instruction: optionalString(raw.instruction),
+    accountField: optionalString(raw.account_field)
   });
 
   return { finding };
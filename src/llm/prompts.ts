@@ -1,4 +1,5 @@
 import type { AgentTask } from "./router";
+import { listVulnClasses } from "../vulns/registry";
 
 export interface PromptTemplate {
   system: string;
@@ -14,8 +15,9 @@ export interface RenderedPrompt {
 const COMMON_RULES = [
   "You are a security auditor for software repositories (backend, frontend, and infrastructure code).",
   "Return findings as a JSON array. Each finding must have: vuln_class, severity, file, line, title, description, evidence, confidence (0-100).",
+  "For Solana programs, also include program_module, instruction and account_field when the finding is reachable through a specific instruction.",
   "Valid severities: CRITICAL, HIGH, MEDIUM, LOW.",
-  "Valid vuln_class values: hardcoded_secret, command_injection, sql_injection, xss, insecure_deserialization, missing_signer_check, missing_has_one, account_type_confusion, arbitrary_cpi, cpi_signer_seed_bypass, cpi_reentrancy, non_canonical_bump, seed_collision, attacker_controlled_seed.",
+  "Valid vuln_class values: {{vuln_classes}}.",
   "If no vulnerabilities are found, return an empty array: []",
   "Do NOT wrap the JSON in markdown code fences. Return raw JSON only."
 ].join("\n");
@@ -37,10 +39,11 @@ const templates: Record<AgentTask, PromptTemplate> = {
       "```text",
       "{{code}}",
       "```",
+      "{{related_context}}",
       "",
       "Return a JSON array of findings."
     ].join("\n"),
-    variables: ["vuln_focus", "file_path", "code"]
+    variables: ["vuln_focus", "file_path", "code", "related_context"]
   },
 
   "threat-model": {
@@ -97,9 +100,11 @@ const templates: Record<AgentTask, PromptTemplate> = {
       "",
       "Target system ID (if known): {{program_id}}",
       "",
+      "exploit_code format: {{exploit_format}}",
+      "",
       "Return a JSON exploit assessment."
     ].join("\n"),
-    variables: ["vuln_class", "severity", "file_path", "line", "title", "description", "code", "program_id"]
+    variables: ["vuln_class", "severity", "file_path", "line", "title", "description", "code", "program_id", "exploit_format"]
   },
 
   "blue-team": {
@@ -193,6 +198,12 @@ const templates: Record<AgentTask, PromptTemplate> = {
       "- File: {{file_path}}:{{line}}",
       "- Title: {{title}}",
       "- Root cause: {{root_cause}}",
+      "- Remediation guidance: {{remediation}}",
+      "",
+      "Example fix for this class:",
+      "```text",
+      "{{example_fix}}",
+      "```",
       "",
       "Source code:",
       "```text",
@@ -201,7 +212,7 @@ const templates: Record<AgentTask, PromptTemplate> = {
       "",
       "Return a JSON patch object."
     ].join("\n"),
-    variables: ["vuln_class", "severity", "file_path", "line", "title", "root_cause", "code"]
+    variables: ["vuln_class", "severity", "file_path", "line", "title", "root_cause", "remediation", "example_fix", "code"]
   },
 
   review: {
@@ -255,8 +266,10 @@ export function getPromptTemplate(task: AgentTask): PromptTemplate {
 
 export function renderPrompt(task: AgentTask, variables: Record<string, string>): RenderedPrompt {
   const template = templates[task];
+  // Read at render time so classes registered by third-party profiles are accepted too
+  const vulnClasses = listVulnClasses().map((definition) => definition.id).join(", ");
   return {
-    system: template.system,
+    system: substituteVariables(template.system, { vuln_classes: vulnClasses }),
     user: substituteVariables(template.userTemplate, variables)
   };
 }
@@ -8,6 +8,7 @@ import os from "node:os";
 import { runFullScan, runDiffScan } from "../orchestrator/run-scan.js";
 import { toMarkdownReport } from "../output/report.js";
 import { toSarif } from "../output/sarif.js";
+import { expandProfile, loadConfiguredProfiles } from "../profiles/registry.js";
 import type { ScanResult } from "../types.js";
 
 const PROJECT_ROOT = path.resolve(import.meta.dirname, "../..");
@@ -258,6 +259,12 @@ function buildScanSummary(result: ScanResult): string {
   // Findings count
   parts.push(`Found ${result.findings.length} finding(s) across ${agentRuns.length} scanner(s) in ${duration}ms.`);
 
+  // Domain profiles and the subtrees they covered
+  const scopes = [...new Set(agentRuns.filter((r) => r.domain).map((r) => `${r.domain} (${r.scope ?? "."})`))];
+  if (scopes.length > 0) {
+    parts.push(`Domains: ${scopes.join(", ")}`);
+  }
+
   // Pipeline stages that ran
   const stages: string[] = [];
   stages.push("pattern-scanners");
@@ -537,9 +544,9 @@ server.registerTool(
       "Run the Hydra evaluation suite against benchmark datasets. Compares Hydra vs baselines and reports precision/recall metrics.",
     inputSchema: {
       dataset: z
-        .enum(["d1", "d2", "d3", "d4", "core", "all"])
+        .enum(["d1", "d2", "d3", "d4", "d5", "d6", "d7", "d8", "d9", "d10", "d11", "d12", "d13", "d14", "d15", "d16", "d17", "d18", "d19", "d20", "d21", "d22", "core", "all"])
         .describe(
-          "Dataset to evaluate: d1/d2 (seeded), d3 (clean controls), d4 (holdout), core (d1+d2), all (d1-d4)"
+          "Dataset to evaluate: d1/d2 (seeded), d3 (clean controls), d4 (holdout), d5 (math & precision), d6 (state management), d7 (economic), d8 (native programs), d9 (owner checks), d10 (SPL Token / Token-2022), d11 (Anchor IDL), d12 (Cargo workspaces), d13 (PDA seed schemas), d14 (taint flows), d15 (remaining_accounts & sysvar spoofing), d16 (duplicate mutable accounts), d17 (least-privilege audit), d18 (Rust service appsec), d19 (CosmWasm access control, submessages, iteration and migration), d20 (ink! contracts and FRAME pallets), d21 (Solidity/EVM reentrancy, access control, low-level calls and signatures), d22 (polyglot monorepos: Anchor program, TypeScript client, Node API and Rust service crate), core (d1+d2), all (d1-d22)"
         ),
     },
   },
@@ -549,8 +556,26 @@ server.registerTool(
       d2: "eval:d2",
       d3: "eval:d3",
       d4: "eval:d4",
+      d5: "eval:d5",
+      d6: "eval:d6",
+      d7: "eval:d7",
+      d8: "eval:d8",
+      d9: "eval:d9",
+      d10: "eval:d10",
+      d11: "eval:d11",
+      d12: "eval:d12",
+      d13: "eval:d13",
+      d14: "eval:d14",
+      d15: "eval:d15",
+      d16: "eval:d16",
+      d17: "eval:d17",
+      d18: "eval:d18",
+      d19: "eval:d19",
+      d20: "eval:d20",
+      d21: "eval:d21",
+      d22: "eval:d22",
       core: "eval:core",
-      all: "eval:phase0",
+      all: "eval:all",
     };
 
     const script = scriptMap[dataset];
@@ -574,93 +599,59 @@ server.registerTool(
 );
 
 // --- Tool 5: hydra_list_scanners ---
+interface ListedScanner {
+  id: string;
+  type: "pattern" | "deterministic" | "llm";
+  active: boolean;
+  vuln_classes: string[];
+  description: string;
+  /** Domain profiles that run the scanner */
+  domains: string[];
+}
+
 server.registerTool(
   "hydra_list_scanners",
   {
     description:
-      "List all available Hydra security scanners and the vulnerability classes they detect.",
+      "List all available Hydra security scanners, the vulnerability classes they detect and the domain profiles that run them.",
   },
   async () => {
     const hasApiKey = !!process.env.ANTHROPIC_API_KEY;
-    const scanners = [
-      {
-        id: "scanner.generic.appsec",
-        type: "pattern",
-        active: true,
-        vuln_classes: ["hardcoded_secret", "command_injection", "sql_injection", "xss", "insecure_deserialization"],
-        description: "Generic application security scanner for common issues in web/backend codebases.",
-      },
-      {
-        id: "scanner.solana.account-validation",
-        type: "pattern",
-        active: true,
-        vuln_classes: ["missing_signer_check", "missing_has_one", "account_type_confusion"],
-        description: "Detects missing signer checks, relationship constraints, and account type confusion via regex patterns with context-aware mitigation.",
-      },
-      {
-        id: "scanner.solana.cpi",
-        type: "pattern",
-        active: true,
-        vuln_classes: ["arbitrary_cpi", "cpi_signer_seed_bypass", "cpi_reentrancy"],
-        description: "Detects unsafe cross-program invocation patterns including arbitrary CPI targets, reentrancy, and signer seed bypass.",
-      },
-      {
-        id: "scanner.solana.pda",
-        type: "pattern",
-        active: true,
-        vuln_classes: ["non_canonical_bump", "seed_collision", "attacker_controlled_seed"],
-        description: "Detects PDA derivation issues including non-canonical bumps, missing seed domain separation, and attacker-controlled seeds.",
-      },
-      {
-        id: "signal.deterministic.adapters",
-        type: "deterministic",
-        active: true,
-        vuln_classes: ["missing_signer_check", "arbitrary_cpi", "non_canonical_bump"],
-        description: "Rule-based deterministic signal detection (regex lint-level checks).",
-      },
-      {
-        id: "llm.scanner.solana.account-validation",
-        type: "llm",
-        active: hasApiKey,
-        vuln_classes: ["missing_signer_check", "missing_has_one", "account_type_confusion"],
-        description: "LLM-powered deep analysis of account validation patterns. Requires ANTHROPIC_API_KEY.",
-      },
-      {
-        id: "llm.scanner.generic.secrets",
-        type: "llm",
-        active: hasApiKey,
-        vuln_classes: ["hardcoded_secret"],
-        description: "LLM-powered deep analysis of credential leakage patterns. Requires ANTHROPIC_API_KEY.",
-      },
-      {
-        id: "llm.scanner.generic.command-injection",
-        type: "llm",
-        active: hasApiKey,
-        vuln_classes: ["command_injection"],
-        description: "LLM-powered deep analysis of command execution risks. Requires ANTHROPIC_API_KEY.",
-      },
-      {
-        id: "llm.scanner.generic.injection-and-deserialization",
-        type: "llm",
-        active: hasApiKey,
-        vuln_classes: ["sql_injection", "xss", "insecure_deserialization"],
-        description: "LLM-powered deep analysis of injection and deserialization risks. Requires ANTHROPIC_API_KEY.",
-      },
-      {
-        id: "llm.scanner.solana.cpi",
-        type: "llm",
-        active: hasApiKey,
-        vuln_classes: ["arbitrary_cpi", "cpi_signer_seed_bypass", "cpi_reentrancy"],
-        description: "LLM-powered deep analysis of CPI patterns. Requires ANTHROPIC_API_KEY.",
-      },
-      {
-        id: "llm.scanner.solana.pda",
-        type: "llm",
-        active: hasApiKey,
-        vuln_classes: ["non_canonical_bump", "seed_collision", "attacker_controlled_seed"],
-        description: "LLM-powered deep analysis of PDA derivation patterns. Requires ANTHROPIC_API_KEY.",
-      },
-    ];
+    const profiles = await loadConfiguredProfiles();
+    const byId = new Map<string, ListedScanner>();
+    const list = (entry: Omit<ListedScanner, "domains">, domain: string): void => {
+      const listed = byId.get(entry.id);
+      if (listed) {
+        listed.domains.push(domain);
+      } else {
+        byId.set(entry.id, { ...entry, domains: [domain] });
+      }
+    };
+    // Included profiles (the generic checks under `rust`) run their scanners for the including one too.
+    for (const profile of profiles) {
+      for (const member of expandProfile(profile)) {
+        for (const { scanner, kind, vulnClasses, description } of member.scanners) {
+          list({ id: scanner.id, type: kind, active: true, vuln_classes: vulnClasses, description }, profile.id);
+        }
+      }
+    }
+    for (const profile of profiles) {
+      for (const member of expandProfile(profile)) {
+        for (const config of member.llmScanners) {
+          list(
+            {
+              id: config.scannerId,
+              type: "llm",
+              active: hasApiKey,
+              vuln_classes: config.vulnClasses,
+              description: `${config.description} Requires ANTHROPIC_API_KEY.`,
+            },
+            profile.id,
+          );
+        }
+      }
+    }
+    const scanners = [...byId.values()];
 
     return {
       content: [
@@ -44,37 +44,73 @@ function shouldEmitFinding(finding: Finding): boolean {
   return isCorroborated(finding.scanner_id) || finding.confidence >= MIN_UNCORROBORATED_CONFIDENCE;
 }
 
-export function aggregateFindings(findings: Finding[]): Finding[] {
-  const byKey = new Map<string, Finding>();
+function mergeFindings(existing: Finding, finding: Finding): Finding {
+  const corroborated = existing.scanner_id !== finding.scanner_id;
+  const winner =
+    severityRank[finding.severity] > severityRank[existing.severity] ? finding : existing;
+  const other = winner === existing ? finding : existing;
 
-  for (const finding of findings) {
-    const key = `${finding.vuln_class}|${finding.file}|${finding.line}`;
-    const existing = byKey.get(key);
-    if (!existing) {
-      byKey.set(key, finding);
+  // Scope the winner lacks (crate, instruction, account) is taken from the other finding.
+  const merged: Finding = {
+    ...other,
+    ...winner,
+    scanner_id: mergeScannerIds(existing.scanner_id, finding.scanner_id),
+    confidence: mergedConfidence(existing.confidence, finding.confidence, corroborated),
+    evidence: mergeEvidence(existing.evidence, finding.evidence),
+    description: mergeDescription(existing.description, finding.description)
+  };
+
+  if (merged.scanner_id.includes(" + ") && !merged.title.endsWith("(corroborated)")) {
+    merged.title = `${merged.title} (corroborated)`;
+  }
+
+  return merged;
+}
+
+/**
+ * Findings at one location stay apart per instruction when scanners scoped them to one. Findings
+ * without an instruction (deterministic signals, file-level rules) corroborate every scoped finding
+ * there, and only stand alone when no scanner scoped the location.
+ */
+function mergeLocation(group: Finding[]): Finding[] {
+  const scoped = new Map<string, Finding>();
+  let unscoped: Finding | undefined;
+
+  for (const finding of group) {
+    if (!finding.instruction) {
+      unscoped = unscoped ? mergeFindings(unscoped, finding) : finding;
       continue;
     }
+    const existing = scoped.get(finding.instruction);
+    scoped.set(finding.instruction, existing ? mergeFindings(existing, finding) : finding);
+  }
 
-    const corroborated = existing.scanner_id !== finding.scanner_id;
-    const winner =
-      severityRank[finding.severity] > severityRank[existing.severity] ? finding : existing;
+  const corroborating = unscoped;
+  if (!corroborating) return [...scoped.values()];
+  if (scoped.size === 0) return [corroborating];
+  return [...scoped.values()].map((finding) => ({
+    ...mergeFindings(finding, corroborating),
+    id: finding.id,
+    instruction: finding.instruction
+  }));
+}
 
-    const merged: Finding = {
-      ...winner,
-      scanner_id: mergeScannerIds(existing.scanner_id, finding.scanner_id),
-      confidence: mergedConfidence(existing.confidence, finding.confidence, corroborated),
-      evidence: mergeEvidence(existing.evidence, finding.evidence),
-      description: mergeDescription(existing.description, finding.description)
-    };
+export function aggregateFindings(findings: Finding[]): Finding[] {
+  const byLocation = new Map<string, Finding[]>();
 
-    if (merged.scanner_id.includes(" + ") && !merged.title.endsWith("(corroborated)")) {
-      merged.title = `${merged.title} (corroborated)`;
+  for (const finding of findings) {
+    const key = `${finding.vuln_class}|${finding.file}|${finding.line}`;
+    const group = byLocation.get(key);
+    if (group) {
+      group.push(finding);
+    } else {
+      byLocation.set(key, [finding]);
     }
-
-    byKey.set(key, merged);
   }
 
-  return [...byKey.values()].filter(shouldEmitFinding).sort((a, b) => {
+  const merged = [...byLocation.values()].flatMap(mergeLocation);
+
+  return merged.filter(shouldEmitFinding).sort((a, b) => {
     const bySeverity = severityRank[b.severity] - severityRank[a.severity];
     if (bySeverity !== 0) {
       return bySeverity;
@@ -1,27 +1,23 @@
 import { randomUUID } from "node:crypto";
-import { existsSync } from "node:fs";
 import path from "node:path";
-import type { AgentRunRecord, Finding, ScanTarget } from "../types";
-import { solanaAccountValidationScanner } from "../agents/scanner/solana-account-validation";
-import { solanaCpiScanner } from "../agents/scanner/solana-cpi";
-import { solanaPdaScanner } from "../agents/scanner/solana-pda";
-import { genericAppSecScanner } from "../agents/scanner/generic-appsec";
-import { runDeterministicSignalAdapters } from "../agents/scanner/deterministic-signals";
-import {
-  runLlmScanner,
-  GENERIC_LLM_SCANNER_CONFIGS,
-  SOLANA_LLM_SCANNER_CONFIGS
-} from "../agents/scanner/llm-scanner";
-
-const solanaScanners = [solanaAccountValidationScanner, solanaCpiScanner, solanaPdaScanner];
-const genericScanners = [genericAppSecScanner];
+import type { AgentRunRecord, AuthorityGraph, Finding, InstructionPrivileges, ScanTarget } from "../types";
+import { runLlmScanner } from "../agents/scanner/llm-scanner";
+import { detectProfileScopes, expandProfile, loadConfiguredProfiles, scopeReads } from "../profiles/registry";
+import type { ProfileScope, ProfileSummary } from "../profiles/types";
+
 const DEFAULT_MAX_CONCURRENT_AGENTS = 3;
 const DEFAULT_AGENT_TIMEOUT_MS = 90_000;
 const LLM_AGENT_TIMEOUT_MS = 300_000;
 
 export interface DispatchResult {
+  /** Ids of the domain profiles detected in the target, one per subtree */
+  domains: string[];
   findings: Finding[];
   agent_runs: AgentRunRecord[];
+  /** Per-instruction privilege summary, for Solana targets */
+  privileges?: InstructionPrivileges[];
+  /** Signer, PDA, constraint and CPI graph, for Solana targets */
+  authority_graph?: AuthorityGraph;
 }
 
 class AgentTimeoutError extends Error {
@@ -65,70 +61,104 @@ async function withTimeout<T>(fn: () => Promise<T>, timeoutMs: number, agentId:
 
 interface AgentTask {
   agent_id: string;
+  domain: string;
+  scope: string;
   execute: () => Promise<Finding[]>;
   timeoutMs?: number;
 }
 
-type ScannerDomain = "generic" | "solana";
-
-function detectScannerDomain(rootPath: string): ScannerDomain {
-  const forced = process.env.HYDRA_SCAN_DOMAIN?.toLowerCase();
-  if (forced === "generic" || forced === "solana") {
-    return forced;
-  }
-
-  if (existsSync(path.join(rootPath, "Anchor.toml")) || existsSync(path.join(rootPath, "programs"))) {
-    return "solana";
-  }
-
-  return "generic";
+/**
+ * The files a scope's scanners may report on: those no higher-priority scope at its root, and no
+ * scope nested below it, reads. This keeps the generic fallback off a Solana program's Rust sources
+ * and the Solana scanners of a workspace off a service crate with a subtree of its own.
+ */
+function scopeFilter(scope: ProfileScope, scopes: ProfileScope[]): ((filePath: string) => boolean) | undefined {
+  const others = scopes.filter((other) => {
+    const relPath = path.relative(scope.root, other.root);
+    if (relPath.startsWith("..") || path.isAbsolute(relPath)) return false;
+    return relPath !== "" || other.profile.priority > scope.profile.priority;
+  });
+  if (others.length === 0) return undefined;
+  return (filePath) => !others.some((other) => scopeReads(other, filePath));
 }
 
-function buildTasks(target: ScanTarget): AgentTask[] {
-  const domain = detectScannerDomain(target.root_path);
-  const selectedScanners = domain === "solana" ? solanaScanners : genericScanners;
-  const scannerTasks: AgentTask[] = selectedScanners.map((scanner) => ({
-    agent_id: scanner.id,
-    execute: () => scanner.scan(target.root_path)
-  }));
-
-  const tasks: AgentTask[] = [...scannerTasks];
-
-  if (domain === "solana") {
-    tasks.push({
-      agent_id: "signal.deterministic.adapters",
-      execute: () => runDeterministicSignalAdapters(target.root_path)
-    });
-  }
+function buildTasks(target: ScanTarget, scopes: ProfileScope[]): AgentTask[] {
+  const tasks: AgentTask[] = [];
+  for (const scope of scopes) {
+    const include = scopeFilter(scope, scopes);
+    const domain = scope.profile.id;
+    const scopePath = path.relative(target.root_path, scope.root) || ".";
+    const members = expandProfile(scope.profile);
 
-  // Wire LLM-powered scanners when ANTHROPIC_API_KEY is available
-  if (process.env.ANTHROPIC_API_KEY) {
-    const llmConfigs = domain === "solana" ? SOLANA_LLM_SCANNER_CONFIGS : GENERIC_LLM_SCANNER_CONFIGS;
-    for (const config of llmConfigs) {
+    for (const { scanner } of members.flatMap((member) => member.scanners)) {
       tasks.push({
-        agent_id: config.scannerId,
-        execute: () => runLlmScanner(target.root_path, {
-          vulnFocus: config.vulnFocus,
-          scannerId: config.scannerId
-        }),
-        timeoutMs: LLM_AGENT_TIMEOUT_MS
+        agent_id: scanner.id,
+        domain,
+        scope: scopePath,
+        execute: async () => {
+          const findings = await scanner.scan(scope.root);
+          return include ? findings.filter((finding) => include(path.resolve(scope.root, finding.file))) : findings;
+        }
       });
     }
+
+    // Wire LLM-powered scanners when ANTHROPIC_API_KEY is available
+    if (process.env.ANTHROPIC_API_KEY) {
+      for (const member of members) {
+        for (const config of member.llmScanners) {
+          tasks.push({
+            agent_id: config.scannerId,
+            domain,
+            scope: scopePath,
+            execute: () => runLlmScanner(scope.root, {
+              vulnFocus: config.vulnFocus,
+              scannerId: config.scannerId,
+              files: member.files,
+              include
+            }),
+            timeoutMs: LLM_AGENT_TIMEOUT_MS
+          });
+        }
+      }
+    }
   }
 
   return tasks;
 }
 
+/** Privilege summaries and authority graphs of several subtrees, as one. */
+function mergeSummaries(summaries: ProfileSummary[]): ProfileSummary {
+  const merged: ProfileSummary = {};
+  for (const summary of summaries) {
+    if (summary.privileges) {
+      merged.privileges = [...(merged.privileges ?? []), ...summary.privileges];
+    }
+    if (summary.authority_graph) {
+      const nodes = new Map((merged.authority_graph?.nodes ?? []).map((node) => [node.id, node]));
+      for (const node of summary.authority_graph.nodes) nodes.set(node.id, node);
+      merged.authority_graph = {
+        nodes: [...nodes.values()],
+        edges: [...(merged.authority_graph?.edges ?? []), ...summary.authority_graph.edges]
+      };
+    }
+  }
+  return merged;
+}
+
 export async function dispatchScanners(target: ScanTarget): Promise<DispatchResult> {
   const maxConcurrentAgents = readPositiveIntFromEnv(
     "HYDRA_MAX_CONCURRENT_AGENTS",
     DEFAULT_MAX_CONCURRENT_AGENTS
   );
   const agentTimeoutMs = readPositiveIntFromEnv("HYDRA_AGENT_TIMEOUT_MS", DEFAULT_AGENT_TIMEOUT_MS);
-  const tasks = buildTasks(target);
+  await loadConfiguredProfiles();
+  const scopes = detectProfileScopes(target.root_path);
+  const tasks = buildTasks(target, scopes);
   const records: AgentRunRecord[] = tasks.map((task) => ({
     id: randomUUID(),
     agent_id: task.agent_id,
+    domain: task.domain,
+    scope: task.scope,
     status: "queued",
     queued_at: nowIso()
   }));
@@ -176,5 +206,14 @@ export async function dispatchScanners(target: ScanTarget): Promise<DispatchResu
     }
   }
 
-  return { findings, agent_runs: records };
+  // Profile summaries are informational; a failure to build them must not fail the scan.
+  const summaries = await Promise.all(
+    scopes.map((scope) => scope.profile.summarize?.(scope.root).catch(() => ({})) ?? Promise.resolve({}))
+  );
+  return {
+    domains: [...new Set(scopes.map((scope) => scope.profile.id))],
+    findings,
+    agent_runs: records,
+    ...mergeSummaries(summaries)
+  };
 }
@@ -70,8 +70,11 @@ export async function runFullScan(rootPath: string, options: ScanOptions = {}):
     started_at: started,
     completed_at: completed,
     threat_model: threatModel,
+    domains: dispatched.domains,
     agent_runs: dispatched.agent_runs,
     findings,
+    privileges: dispatched.privileges,
+    authority_graph: dispatched.authority_graph,
     adversarial_results: adversarialResults,
     patch_results: patchResults
   };
@@ -123,6 +126,7 @@ export async function runDiffScan(
   const findings = aggregateFindings(dispatched.findings).filter((finding) =>
     changedSet.has(path.resolve(finding.file))
   );
+  const privileges = dispatched.privileges?.filter((entry) => changedSet.has(path.resolve(entry.file)));
 
   let adversarialResults: AdversarialResult[] | undefined;
   let patchResults: PatchResult[] | undefined;
@@ -144,8 +148,11 @@ export async function runDiffScan(
     started_at: started,
     completed_at: completed,
     threat_model: threatModel,
+    domains: dispatched.domains,
     agent_runs: dispatched.agent_runs,
     findings,
+    privileges,
+    authority_graph: dispatched.authority_graph,
     adversarial_results: adversarialResults,
     patch_results: patchResults
   };
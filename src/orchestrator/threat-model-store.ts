@@ -5,6 +5,9 @@ import type { Dirent } from "node:fs";
 import path from "node:path";
 import { fileURLToPath } from "node:url";
 import { promisify } from "node:util";
+import { loadCrateGraph, type RustCrateGraph } from "../analysis/rust-crates";
+import { detectSubtreeProfiles, listProfiles, loadConfiguredProfiles } from "../profiles/registry";
+import type { DomainProfile, ProfileSources, ThreatModelContribution } from "../profiles/types";
 import type {
   ScanTarget,
   ThreatModelFingerprint,
@@ -179,56 +182,97 @@ function detectLanguageBreakdown(sourceFiles: string[]): Record<string, number>
   return breakdown;
 }
 
-async function detectFrameworks(rootPath: string, sourceFiles: string[]): Promise<string[]> {
-  const frameworks = new Set<string>();
-  if (await fileExists(path.join(rootPath, "Anchor.toml"))) {
-    frameworks.add("solana-anchor");
-  }
-  if (await fileExists(path.join(rootPath, "Cargo.toml"))) {
-    frameworks.add("rust-cargo");
-  }
-  if (await fileExists(path.join(rootPath, "package.json"))) {
-    frameworks.add("nodejs");
-  }
-  if (await fileExists(path.join(rootPath, "tsconfig.json"))) {
-    frameworks.add("typescript");
-  }
+/** Reads of source files, shared by every profile hook of one threat model. */
+function sourceReader(): (filePath: string) => Promise<string | undefined> {
+  const reads = new Map<string, Promise<string | undefined>>();
+  return (filePath) => {
+    let content = reads.get(filePath);
+    if (!content) {
+      content = fs.readFile(filePath, "utf8").catch(() => undefined);
+      reads.set(filePath, content);
+    }
+    return content;
+  };
+}
 
-  const hasRust = sourceFiles.some((filePath) => filePath.endsWith(".rs"));
-  const hasTsOrJs = sourceFiles.some(
-    (filePath) =>
-      filePath.endsWith(".ts") ||
-      filePath.endsWith(".tsx") ||
-      filePath.endsWith(".js") ||
-      filePath.endsWith(".jsx")
-  );
+/** What a profile hook sees of `dir`: the source files under it, read through `read`. */
+function profileSources(
+  rootPath: string,
+  dir: string,
+  sourceFiles: string[],
+  read: (filePath: string) => Promise<string | undefined>
+): ProfileSources {
+  const files = sourceFiles.filter((filePath) => !path.relative(dir, filePath).startsWith(".."));
+  let graph: Promise<RustCrateGraph> | undefined;
+  return {
+    rootPath: dir,
+    files,
+    read,
+    crateGraph: () => {
+      if (!graph) graph = loadCrateGraph(dir, files.filter((filePath) => filePath.endsWith(".rs")));
+      return graph;
+    },
+    relPath: (filePath) => normalizeRelPath(rootPath, filePath)
+  };
+}
 
-  if (hasRust && !frameworks.has("rust-cargo")) {
-    frameworks.add("rust");
-  }
-  if (hasTsOrJs && !frameworks.has("nodejs")) {
-    frameworks.add("javascript-runtime");
-  }
+/**
+ * The scan root and every detected subtree, such as an Anchor workspace under `onchain/`, each with
+ * the profiles active there: those whose `detect` matches the directory.
+ */
+function activeProfiles(rootPath: string): { dir: string; profiles: DomainProfile[] }[] {
+  const dirs = [...new Set([rootPath, ...detectSubtreeProfiles(rootPath).map((scope) => scope.root)])];
+  return dirs.map((dir) => ({ dir, profiles: listProfiles().filter((profile) => profile.detect(dir)) }));
+}
 
-  return uniqueSorted([...frameworks]);
+/** Frameworks the active profiles report for the directories they are active in. */
+async function detectFrameworks(
+  rootPath: string,
+  sourceFiles: string[],
+  active: { dir: string; profiles: DomainProfile[] }[],
+  read: (filePath: string) => Promise<string | undefined>
+): Promise<string[]> {
+  const frameworks: string[] = [];
+  for (const { dir, profiles } of active) {
+    const sources = profileSources(rootPath, dir, sourceFiles, read);
+    for (const profile of profiles) {
+      frameworks.push(...((await profile.detectFrameworks?.(sources)) ?? []));
+    }
+  }
+  return uniqueSorted(frameworks);
 }
 
-async function detectEntryPoints(rootPath: string, sourceFiles: string[]): Promise<string[]> {
+/**
+ * Entry points the active profiles find across the target, such as Anchor instructions, routed
+ * request handlers or external contract functions, beside conventional entry files. Without any,
+ * the public functions of the first Rust files stand in.
+ */
+async function detectEntryPoints(
+  rootPath: string,
+  sourceFiles: string[],
+  active: { dir: string; profiles: DomainProfile[] }[],
+  read: (filePath: string) => Promise<string | undefined>
+): Promise<string[]> {
   const relFiles = sourceFiles.map((filePath) => normalizeRelPath(rootPath, filePath));
   const nameHeuristic = relFiles.filter((filePath) => {
     const base = path.basename(filePath);
     return base === "main.rs" || base === "lib.rs" || base === "main.ts" || base === "index.ts";
   });
 
+  const sources = profileSources(rootPath, rootPath, sourceFiles, read);
+  const profiles = [...new Set(active.flatMap((entry) => entry.profiles))];
+  const instructionEntryPoints: string[] = [];
+  for (const profile of profiles) {
+    instructionEntryPoints.push(...((await profile.entryPoints?.(sources)) ?? []));
+  }
+  if (instructionEntryPoints.length > 0) {
+    return uniqueSorted([...nameHeuristic, ...instructionEntryPoints]).slice(0, MAX_ENTRY_POINTS);
+  }
+
   const functionHeuristic: string[] = [];
-  const rustFiles = sourceFiles.filter((filePath) => filePath.endsWith(".rs")).slice(0, 30);
-  for (const filePath of rustFiles) {
-    let content: string;
-    try {
-      content = await fs.readFile(filePath, "utf8");
-    } catch {
-      continue;
-    }
+  for (const filePath of sourceFiles.filter((candidate) => candidate.endsWith(".rs")).slice(0, 30)) {
+    const content = await read(filePath);
+    if (content === undefined) continue;
 
     const relPath = normalizeRelPath(rootPath, filePath);
     for (const match of content.matchAll(/\bpub\s+fn\s+([a-zA-Z0-9_]+)\s*\(/g)) {
@@ -245,55 +289,59 @@ async function detectEntryPoints(rootPath: string, sourceFiles: string[]): Promi
   return uniqueSorted([...nameHeuristic, ...functionHeuristic]).slice(0, MAX_ENTRY_POINTS);
 }
 
-function buildAssets(frameworks: string[]): string[] {
-  const assets = new Set<string>(["Source code integrity", "Deployment artifacts", "Build secrets"]);
-
-  if (frameworks.includes("solana-anchor")) {
-    assets.add("Program-owned state accounts");
-    assets.add("PDA authority relationships");
-    assets.add("CPI permission boundaries");
-    assets.add("Token custody and transfer invariants");
+/**
+ * Threat-model entries the registered profiles contribute for the detected frameworks. A
+ * contribution may also name a profile id, which matches when that profile detects the target.
+ */
+function profileContributions(rootPath: string, frameworks: string[]): ThreatModelContribution[] {
+  const active = new Set(frameworks);
+  const profiles = listProfiles();
+  for (const profile of profiles) {
+    if (profile.detect(rootPath)) active.add(profile.id);
   }
-
-  if (frameworks.includes("nodejs") || frameworks.includes("javascript-runtime")) {
-    assets.add("API authentication state");
-    assets.add("Runtime configuration and environment variables");
+  for (const scope of detectSubtreeProfiles(rootPath)) {
+    active.add(scope.profile.id);
   }
-
-  return uniqueSorted([...assets]);
+  return profiles
+    .flatMap((profile) => profile.threatModel ?? [])
+    .filter(
+      (contribution) =>
+        contribution.frameworks.some((framework) => active.has(framework)) &&
+        !(contribution.unless ?? []).some((framework) => active.has(framework))
+    );
 }
 
-function buildTrustBoundaries(frameworks: string[]): string[] {
-  const boundaries = new Set<string>([
-    "External caller input -> application logic",
-    "Application logic -> persistent state mutations"
+function buildAssets(contributions: ThreatModelContribution[]): string[] {
+  return uniqueSorted([
+    "Source code integrity",
+    "Deployment artifacts",
+    "Build secrets",
+    ...contributions.flatMap((contribution) => contribution.assets ?? [])
   ]);
-
-  if (frameworks.includes("solana-anchor")) {
-    boundaries.add("Transaction accounts -> instruction handlers");
-    boundaries.add("Program -> CPI target programs");
-    boundaries.add("Signer authorities -> PDA-derived authorities");
-  }
-
-  return uniqueSorted([...boundaries]);
 }
 
-function buildAttackSurface(target: ScanTarget, frameworks: string[]): string[] {
-  const attackSurface = new Set<string>();
-
-  if (frameworks.includes("solana-anchor")) {
-    attackSurface.add("Account validation constraints");
-    attackSurface.add("Cross-program invocation callsites");
-    attackSurface.add("PDA seed derivation and bump handling");
-  }
+/** Boundaries profiles derive from the code itself, such as the Solana authority graph. */
+async function deriveTrustBoundaries(rootPath: string, frameworks: string[]): Promise<string[]> {
+  const derived = await Promise.all(
+    listProfiles().map((profile) => profile.deriveTrustBoundaries?.(rootPath, frameworks) ?? Promise.resolve([]))
+  );
+  return derived.flat();
+}
 
-  if (target.mode === "diff") {
-    attackSurface.add("Changed-file regression surface");
-  } else {
-    attackSurface.add("Full repository scan surface");
-  }
+function buildTrustBoundaries(contributions: ThreatModelContribution[], derived: string[]): string[] {
+  return uniqueSorted([
+    "External caller input -> application logic",
+    "Application logic -> persistent state mutations",
+    ...contributions.flatMap((contribution) => contribution.trustBoundaries ?? []),
+    ...derived
+  ]);
+}
 
-  return uniqueSorted([...attackSurface]);
+function buildAttackSurface(target: ScanTarget, contributions: ThreatModelContribution[]): string[] {
+  return uniqueSorted([
+    ...contributions.flatMap((contribution) => contribution.attackSurface ?? []),
+    target.mode === "diff" ? "Changed-file regression surface" : "Full repository scan surface"
+  ]);
 }
 
 function buildScopeFiles(target: ScanTarget, rootPath: string, sourceFiles: string[]): string[] {
@@ -321,17 +369,22 @@ async function generateThreatModelSummary(
 ): Promise<ThreatModelSummary> {
   const sourceFiles = await listSourceFiles(rootPath);
   const languageBreakdown = detectLanguageBreakdown(sourceFiles);
-  const frameworks = await detectFrameworks(rootPath, sourceFiles);
-  const entryPoints = await detectEntryPoints(rootPath, sourceFiles);
+  await loadConfiguredProfiles();
+  const active = activeProfiles(rootPath);
+  const read = sourceReader();
+  const frameworks = await detectFrameworks(rootPath, sourceFiles, active, read);
+  const entryPoints = await detectEntryPoints(rootPath, sourceFiles, active, read);
+  const contributions = profileContributions(rootPath, frameworks);
+  const derived = await deriveTrustBoundaries(rootPath, frameworks);
 
   return {
     primary_language: pickPrimaryLanguage(languageBreakdown),
     language_breakdown: languageBreakdown,
     detected_frameworks: frameworks,
-    assets: buildAssets(frameworks),
-    trust_boundaries: buildTrustBoundaries(frameworks),
+    assets: buildAssets(contributions),
+    trust_boundaries: buildTrustBoundaries(contributions, derived),
     entry_points: entryPoints,
-    attack_surface: buildAttackSurface(target, frameworks),
+    attack_surface: buildAttackSurface(target, contributions),
     scan_scope_files: buildScopeFiles(target, rootPath, sourceFiles)
   };
 }
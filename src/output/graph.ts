@@ -0,0 +1,82 @@
+import type { AuthorityEdgeKind, AuthorityGraph, AuthorityNodeKind } from "../types";
+
+const MERMAID_SHAPE: Record<AuthorityNodeKind, [string, string]> = {
+  instruction: ["[", "]"],
+  signer: ["([", "])"],
+  pda: ["{{", "}}"],
+  account_type: ["[(", ")]"],
+  program: ["[[", "]]"]
+};
+
+const MERMAID_ARROW: Record<AuthorityEdgeKind, string> = {
+  authorizes: "-->",
+  constraint: "-.->",
+  seeds: "-.->",
+  cpi: "-->",
+  signs: "==>",
+  mutates: "==>"
+};
+
+const DOT_SHAPE: Record<AuthorityNodeKind, string> = {
+  instruction: "box",
+  signer: "ellipse",
+  pda: "hexagon",
+  account_type: "cylinder",
+  program: "component"
+};
+
+const DOT_STYLE: Record<AuthorityEdgeKind, string> = {
+  authorizes: "solid",
+  constraint: "dashed",
+  seeds: "dotted",
+  cpi: "solid",
+  signs: "bold",
+  mutates: "bold"
+};
+
+function nodeLabel(label: string, crate?: string): string {
+  // Instructions of a program module named after its crate already say which crate they belong to.
+  return crate && !label.startsWith(`${crate}::`) ? `${crate}/${label}` : label;
+}
+
+function mermaidText(text: string): string {
+  const escaped = text.replace(/"/g, "#quot;");
+  return `"${escaped}"`;
+}
+
+function dotText(text: string): string {
+  const escaped = text.replace(/[\\"]/g, (ch) => `\\${ch}`);
+  return `"${escaped}"`;
+}
+
+/** Whether nodes span several crates, in which case labels name their crate. */
+function multiCrate(graph: AuthorityGraph): boolean {
+  return new Set(graph.nodes.map((node) => node.crate).filter(Boolean)).size > 1;
+}
+
+export function toMermaid(graph: AuthorityGraph): string {
+  const qualify = multiCrate(graph);
+  const lines = ["flowchart LR"];
+  for (const node of graph.nodes) {
+    const [open, close] = MERMAID_SHAPE[node.kind];
+    lines.push(`  ${node.id}${open}${mermaidText(nodeLabel(node.label, qualify ? node.crate : undefined))}${close}`);
+  }
+  for (const edge of graph.edges) {
+    lines.push(`  ${edge.from} ${MERMAID_ARROW[edge.kind]}|${mermaidText(edge.label)}| ${edge.to}`);
+  }
+  return lines.join("\n");
+}
+
+export function toDot(graph: AuthorityGraph): string {
+  const qualify = multiCrate(graph);
+  const lines = ["digraph authority {", "  rankdir=LR;", '  node [fontname="Helvetica"];', '  edge [fontname="Helvetica", fontsize=10];'];
+  for (const node of graph.nodes) {
+    const label = dotText(nodeLabel(node.label, qualify ? node.crate : undefined));
+    lines.push(`  ${node.id} [label=${label}, shape=${DOT_SHAPE[node.kind]}];`);
+  }
+  for (const edge of graph.edges) {
+    lines.push(`  ${edge.from} -> ${edge.to} [label=${dotText(edge.label)}, style=${DOT_STYLE[edge.kind]}];`);
+  }
+  lines.push("}");
+  return lines.join("\n");
+}
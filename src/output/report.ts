@@ -1,5 +1,8 @@
 import path from "node:path";
-import type { ScanResult, Finding, AdversarialResult, PatchResult, Severity } from "../types";
+import { toMermaid } from "./graph";
+import { expandProfile, getProfile } from "../profiles/registry";
+import { getVulnClass } from "../vulns/registry";
+import type { ScanResult, Finding, AdversarialResult, PatchResult, Severity, InstructionPrivileges } from "../types";
 
 const SEVERITY_ORDER: Record<Severity, number> = {
   CRITICAL: 0,
@@ -39,6 +42,27 @@ function severityDistribution(findings: Finding[]): Record<Severity, number> {
   return dist;
 }
 
+/** What the registry says about a class: references, remediation and an example fix. */
+function vulnClassGuidance(vulnClass: string): string[] {
+  const info = getVulnClass(vulnClass);
+  if (!info) return [];
+  const lines: string[] = [];
+  const references = info.references.map((reference) =>
+    reference.url ? `[${reference.id}](${reference.url})` : reference.id
+  );
+  lines.push(`> **${info.title}**${references.length > 0 ? ` (${references.join(", ")})` : ""}`);
+  lines.push(">");
+  lines.push(`> Remediation: ${info.remediation}`);
+  lines.push("");
+  if (info.exampleFix) {
+    lines.push("```text");
+    lines.push(info.exampleFix);
+    lines.push("```");
+    lines.push("");
+  }
+  return lines;
+}
+
 function pipelineStages(result: ScanResult): string[] {
   const lines: string[] = [];
   const hasApiKey = (result.agent_runs ?? []).some((r) => r.agent_id.startsWith("llm.scanner"));
@@ -76,8 +100,15 @@ function pipelineStages(result: ScanResult): string[] {
       `| LLM Scanners | ${llmCompleted > 0 ? "RAN" : "FAILED"} | ${llmCompleted}/${llmAgents.length} completed${llmFailed > 0 ? `, ${llmFailed} failed` : ""} | Deep semantic analysis via Claude |`
     );
   } else {
+    const members = new Set(
+      (result.domains ?? []).flatMap((id) => {
+        const profile = getProfile(id);
+        return profile ? expandProfile(profile) : [];
+      })
+    );
+    const llmTotal = [...members].reduce((total, member) => total + member.llmScanners.length, 0);
     lines.push(
-      `| LLM Scanners | SKIPPED | 0/3 | Requires ANTHROPIC_API_KEY environment variable |`
+      `| LLM Scanners | SKIPPED | 0/${llmTotal} | Requires ANTHROPIC_API_KEY environment variable |`
     );
   }
 
@@ -113,6 +144,30 @@ function pipelineStages(result: ScanResult): string[] {
   return lines;
 }
 
+function instructionLabel(f: Pick<Finding, "crate" | "program_module" | "instruction">): string | undefined {
+  if (!f.instruction) return undefined;
+  const label = f.program_module ? `${f.program_module}::${f.instruction}` : f.instruction;
+  // Workspace members usually name their #[program] module after the crate; only qualify when they differ.
+  return f.crate && f.crate !== f.program_module ? `${f.crate}/${label}` : label;
+}
+
+/** One row per instruction: the privileges its Accounts struct asks for, with unused ones struck through. */
+function privilegeRows(privileges: InstructionPrivileges[]): string[] {
+  const rows: string[] = [];
+  for (const entry of privileges) {
+    const unjudged = entry.opaque ? " (context passed on)" : "";
+    const mutable = entry.mutable_accounts
+      .map((account) => (account.written || entry.opaque ? `\`${account.account}\`` : `~~\`${account.account}\`~~`))
+      .join(", ");
+    const signers = entry.signers
+      .map((signer) => (signer.authorizes || entry.opaque ? `\`${signer.account}\`` : `~~\`${signer.account}\`~~`))
+      .join(", ");
+    const pdas = entry.pda_signers.map((pda) => `\`${pda.seeds}\` -> \`${pda.operation}\``).join("<br>");
+    rows.push(`| \`${instructionLabel(entry)}\`${unjudged} | ${mutable || "-"} | ${signers || "-"} | ${pdas || "-"} |`);
+  }
+  return rows;
+}
+
 function formatFindingRow(f: Finding, idx: number): string {
   const relFile = f.file.includes("/") ? f.file.split("/").slice(-2).join("/") : f.file;
   return `| ${idx} | ${SEVERITY_ICON[f.severity]} | \`${f.vuln_class}\` | \`${relFile}:${f.line}\` | ${f.confidence}% | ${f.title} |`;
@@ -138,6 +193,10 @@ export function toMarkdownReport(result: ScanResult): string {
   lines.push(`| Mode | ${result.target.mode === "diff" ? `Differential (${result.target.diff?.changed_files?.length ?? 0} files)` : "Full Scan"} |`);
   lines.push(`| Started | ${result.started_at} |`);
   lines.push(`| Duration | ${duration} |`);
+  if (result.domains && result.domains.length > 0) {
+    const names = result.domains.map((id) => `${getProfile(id)?.name ?? id} (\`${id}\`)`);
+    lines.push(`| Domain Profile${names.length > 1 ? "s" : ""} | ${names.join(", ")} |`);
+  }
   if (result.threat_model) {
     const status = result.threat_model.loaded_from_cache ? "cached" : "generated";
     lines.push(`| Threat Model | \`${result.threat_model.version.id}\` (rev ${result.threat_model.version.revision}, ${status}) |`);
@@ -163,8 +222,8 @@ export function toMarkdownReport(result: ScanResult): string {
   if (result.agent_runs && result.agent_runs.length > 0) {
     lines.push("## Scanner Performance");
     lines.push("");
-    lines.push("| Scanner | Status | Duration | Findings |");
-    lines.push("|---------|--------|----------|----------|");
+    lines.push("| Scanner | Domain | Status | Duration | Findings |");
+    lines.push("|---------|--------|--------|----------|----------|");
     for (const run of result.agent_runs) {
       const statusLabel = run.status === "completed" ? "OK"
         : run.status === "timed_out" ? "TIMEOUT"
@@ -172,11 +231,36 @@ export function toMarkdownReport(result: ScanResult): string {
         : run.status;
       const dur = run.duration_ms != null ? `${run.duration_ms}ms` : "-";
       const count = run.finding_count != null ? String(run.finding_count) : "-";
-      lines.push(`| \`${run.agent_id}\` | ${statusLabel} | ${dur} | ${count} |`);
+      const domain = run.domain ? `${run.domain}${run.scope && run.scope !== "." ? ` (\`${run.scope}/\`)` : ""}` : "-";
+      lines.push(`| \`${run.agent_id}\` | ${domain} | ${statusLabel} | ${dur} | ${count} |`);
     }
     lines.push("");
   }
 
+  // ── Instruction Privileges ──
+  if (result.privileges && result.privileges.length > 0) {
+    lines.push("## Instruction Privileges");
+    lines.push("");
+    lines.push("Accounts each instruction takes as writable or signer, and the PDA authorities it signs with. Struck-through entries are never written or never relied on by the handler.");
+    lines.push("");
+    lines.push("| Instruction | Mutable Accounts | Signers | PDA Signatures |");
+    lines.push("|-------------|------------------|---------|----------------|");
+    lines.push(...privilegeRows(result.privileges));
+    lines.push("");
+  }
+
+  // ── Authority Graph ──
+  if (result.authority_graph && result.authority_graph.edges.length > 0) {
+    lines.push("## Authority Graph");
+    lines.push("");
+    lines.push("Signers authorizing instructions, PDAs signing CPIs, accounts bound by constraints and seeds, and the accounts each instruction writes.");
+    lines.push("");
+    lines.push("```mermaid");
+    lines.push(toMermaid(result.authority_graph));
+    lines.push("```");
+    lines.push("");
+  }
+
   // ── No Findings ──
   if (result.findings.length === 0) {
     lines.push("## Results");
@@ -208,17 +292,44 @@ export function toMarkdownReport(result: ScanResult): string {
   for (const [vulnClass, findings] of byClass) {
     lines.push(`### ${vulnClass} (${findings.length} finding${findings.length > 1 ? "s" : ""})`);
     lines.push("");
+    lines.push(...vulnClassGuidance(vulnClass));
     for (const f of findings) {
       const relFile = f.file.includes("/") ? f.file.split("/").slice(-2).join("/") : f.file;
       lines.push(`**${f.title}**`);
       lines.push(`- Location: \`${relFile}:${f.line}\``);
+      const instruction = instructionLabel(f);
+      if (instruction) {
+        lines.push(`- Instruction: \`${instruction}\`${f.account_field ? ` | Account: \`${f.account_field}\`` : ""}`);
+      }
       lines.push(`- Severity: ${f.severity} | Confidence: ${f.confidence}% | Scanner: \`${f.scanner_id}\``);
       lines.push(`- ${f.description}`);
       lines.push(`- Evidence: \`${f.evidence}\``);
+      if (f.trace) {
+        const steps = f.trace.map((step) => {
+          const stepFile = step.file.includes("/") ? step.file.split("/").slice(-2).join("/") : step.file;
+          return `\`${stepFile}:${step.line}\` ${step.label}`;
+        });
+        lines.push(`- Trace: ${steps.join(" -> ")}`);
+      }
       lines.push("");
     }
   }
 
+  // ── Findings Grouped by Instruction ──
+  const scoped = sorted.filter((f) => f.instruction);
+  if (scoped.length > 0) {
+    lines.push("## Findings by Instruction");
+    lines.push("");
+    lines.push("| Instruction | Findings | Highest Severity | Classes |");
+    lines.push("|-------------|:--------:|------------------|---------|");
+    const byInstruction = groupBy(scoped, (f) => instructionLabel(f)!);
+    for (const [instruction, findings] of byInstruction) {
+      const classes = [...new Set(findings.map((f) => `\`${f.vuln_class}\``))].join(", ");
+      lines.push(`| \`${instruction}\` | ${findings.length} | ${findings[0].severity} | ${classes} |`);
+    }
+    lines.push("");
+  }
+
   // ── Adversarial Results ──
   if (result.adversarial_results && result.adversarial_results.length > 0) {
     lines.push("## Adversarial Validation Results");
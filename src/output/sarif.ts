@@ -1,6 +1,51 @@
-import type { ScanResult } from "../types";
+import type { ScanResult, Severity, VulnClass } from "../types";
+import { getVulnClass } from "../vulns/registry";
+
+/** GitHub code scanning ranks alerts by this 0-10 score. */
+const SECURITY_SEVERITY: Record<Severity, string> = {
+  CRITICAL: "9.5",
+  HIGH: "8.0",
+  MEDIUM: "5.5",
+  LOW: "3.0"
+};
+
+function sarifLevel(severity: Severity): "error" | "warning" {
+  return severity === "CRITICAL" || severity === "HIGH" ? "error" : "warning";
+}
+
+/** Rule metadata for a vulnerability class, from the vulnerability-class registry. */
+function sarifRule(id: VulnClass): object {
+  const info = getVulnClass(id);
+  if (!info) return { id };
+
+  const help = [info.remediation];
+  if (info.exampleFix) help.push("", "```", info.exampleFix, "```");
+  const links = info.references.filter((reference) => reference.url);
+  if (links.length > 0) help.push("", ...links.map((reference) => `- [${reference.id}](${reference.url})`));
+
+  return {
+    id,
+    name: id.replace(/(?:^|_)([a-z0-9])/g, (_, letter: string) => letter.toUpperCase()),
+    shortDescription: { text: info.title },
+    fullDescription: { text: info.description },
+    help: { text: info.remediation, markdown: help.join("\n") },
+    ...(links.length > 0 ? { helpUri: links[0].url } : {}),
+    defaultConfiguration: { level: sarifLevel(info.defaultSeverity) },
+    properties: {
+      tags: [
+        "security",
+        info.domain,
+        ...info.references
+          .filter((reference) => reference.source === "CWE")
+          .map((reference) => `external/cwe/${reference.id.toLowerCase()}`)
+      ],
+      "security-severity": SECURITY_SEVERITY[info.defaultSeverity]
+    }
+  };
+}
 
 export function toSarif(result: ScanResult): object {
+  const ruleIds = [...new Set(result.findings.map((finding) => finding.vuln_class))];
   return {
     version: "2.1.0",
     $schema:
@@ -11,21 +56,66 @@ export function toSarif(result: ScanResult): object {
           driver: {
             name: "hydra-security",
             informationUri: "https://github.com/hydra-security/hydra-security",
-            rules: []
+            rules: ruleIds.map(sarifRule)
           }
         },
         results: result.findings.map((finding) => ({
-          level: finding.severity === "CRITICAL" || finding.severity === "HIGH" ? "error" : "warning",
+          level: sarifLevel(finding.severity),
           ruleId: finding.vuln_class,
+          ruleIndex: ruleIds.indexOf(finding.vuln_class),
           message: { text: finding.title },
           locations: [
             {
               physicalLocation: {
                 artifactLocation: { uri: finding.file },
                 region: { startLine: finding.line }
-              }
+              },
+              ...(finding.instruction
+                ? {
+                    logicalLocations: [
+                      {
+                        name: finding.instruction,
+                        fullyQualifiedName: finding.program_module
+                          ? `${finding.program_module}::${finding.instruction}`
+                          : finding.instruction,
+                        kind: "function"
+                      }
+                    ]
+                  }
+                : {})
             }
-          ]
+          ],
+          ...(finding.trace
+            ? {
+                codeFlows: [
+                  {
+                    threadFlows: [
+                      {
+                        locations: finding.trace.map((step) => ({
+                          location: {
+                            physicalLocation: {
+                              artifactLocation: { uri: step.file },
+                              region: { startLine: step.line }
+                            },
+                            message: { text: step.label }
+                          }
+                        }))
+                      }
+                    ]
+                  }
+                ]
+              }
+            : {}),
+          ...(finding.instruction
+            ? {
+                properties: {
+                  crate: finding.crate,
+                  program_module: finding.program_module,
+                  instruction: finding.instruction,
+                  account_field: finding.account_field
+                }
+              }
+            : {})
         }))
       }
     ]
@@ -0,0 +1,98 @@
+import { cosmwasmAccessScanner } from "../agents/scanner/cosmwasm-access";
+import { cosmwasmSubmsgScanner } from "../agents/scanner/cosmwasm-submsg";
+import { cosmwasmStorageScanner } from "../agents/scanner/cosmwasm-storage";
+import { buildCosmwasmContract, isCosmwasmManifest } from "../analysis/cosmwasm-model";
+import { anyManifest, crateDependsOn, hasRootFile, rustSources } from "./detect";
+import type { DomainProfile } from "./types";
+
+/** CosmWasm contracts: the root crate or a `contracts/<name>` member depends on `cosmwasm-std`. */
+export const cosmwasmProfile: DomainProfile = {
+  id: "cosmwasm",
+  name: "CosmWasm",
+  priority: 80,
+  detect: (rootPath) => anyManifest(rootPath, isCosmwasmManifest),
+  files: { extensions: [".rs"], rustCrates: true },
+  scanners: [
+    {
+      scanner: cosmwasmAccessScanner,
+      kind: "pattern",
+      vulnClasses: ["missing_sender_auth", "unchecked_addr", "unprotected_migration"],
+      description:
+        "CosmWasm access control: ExecuteMsg routes that save Items or send funds and admin messages without checking info.sender, Addr::unchecked on non-constant addresses, and migrate entry points that rewrite state from MigrateMsg without a cw2 version or admin check."
+    },
+    {
+      scanner: cosmwasmSubmsgScanner,
+      kind: "pattern",
+      vulnClasses: ["submsg_reentrancy"],
+      description:
+        "CosmWasm reply ordering: execute routes that dispatch reply-bearing submessages before saving any state while the reply entry point does the bookkeeping."
+    },
+    {
+      scanner: cosmwasmStorageScanner,
+      kind: "pattern",
+      vulnClasses: ["unbounded_iteration"],
+      description: "CosmWasm storage iteration: cw-storage-plus range/keys/prefix_range walks with no take(limit), rated higher in state-changing handlers."
+    }
+  ],
+  llmScanners: [
+    {
+      scannerId: "llm.scanner.cosmwasm.access",
+      vulnFocus:
+        "CosmWasm execute handlers that save Items, send BankMsg or WasmMsg admin messages without comparing info.sender to a stored owner or admin, Addr::unchecked on message-supplied addresses instead of deps.api.addr_validate, and migrate entry points that overwrite owner or config without cw2 version or admin checks",
+      vulnClasses: ["missing_sender_auth", "unchecked_addr", "unprotected_migration"],
+      description: "LLM-powered deep analysis of CosmWasm sender authorization, address validation and migrations."
+    },
+    {
+      scannerId: "llm.scanner.cosmwasm.submsg",
+      vulnFocus:
+        "CosmWasm submessage and reply flows: SubMsg::reply_on_success / reply_always dispatched before pending state is saved, reply handlers that trust the reply id or result without matching in-flight state, and callee contracts able to re-enter before bookkeeping completes",
+      vulnClasses: ["submsg_reentrancy"],
+      description: "LLM-powered deep analysis of CosmWasm submessage and reply ordering."
+    },
+    {
+      scannerId: "llm.scanner.cosmwasm.storage",
+      vulnFocus:
+        "CosmWasm storage iteration without bounds: cw-storage-plus Map range, keys and prefix iteration with no take(limit) or start_after pagination in execute, sudo or query handlers, where anyone can grow the map until the handler runs out of gas",
+      vulnClasses: ["unbounded_iteration"],
+      description: "LLM-powered deep analysis of unbounded CosmWasm storage iteration."
+    }
+  ],
+  threatModel: [
+    {
+      frameworks: ["cosmwasm"],
+      assets: [
+        "Contract-held native and CW20 funds",
+        "Contract owner, admin and configuration Items",
+        "Contract storage maps (balances, claims, positions)"
+      ],
+      trustBoundaries: [
+        "MessageInfo.sender -> execute handlers",
+        "Contract -> submessage callee contracts (reply path)",
+        "Chain code admin -> migrate entry point"
+      ],
+      attackSurface: [
+        "ExecuteMsg variants and their sender checks",
+        "Submessages and reply handlers",
+        "Storage iteration in execute and query handlers",
+        "Migrate entry point and cw2 version checks"
+      ]
+    }
+  ],
+  async detectFrameworks({ rootPath, crateGraph }) {
+    return hasRootFile(rootPath, "Cargo.toml") && crateDependsOn(await crateGraph(), "cosmwasm_std") ? ["cosmwasm"] : [];
+  },
+  /** The exported entry points and each ExecuteMsg variant `execute` dispatches. */
+  async entryPoints(sources) {
+    const contents = await rustSources(sources);
+    if (![...contents.values()].some((content) => /\bcosmwasm_std\b/.test(content))) {
+      return [];
+    }
+    const contract = buildCosmwasmContract(await sources.crateGraph(), contents);
+    return [
+      ...contract.entryPoints.map((entry) => `${sources.relPath(entry.file)}::${entry.name}`),
+      ...contract.routes.map((route) => `${sources.relPath(route.file)}::execute::${route.variant}`)
+    ];
+  },
+  sandboxProfile: "generic",
+  vulnClasses: ["missing_sender_auth", "unchecked_addr", "submsg_reentrancy", "unbounded_iteration", "unprotected_migration"]
+};
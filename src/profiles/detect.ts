@@ -0,0 +1,56 @@
+import { existsSync, readdirSync, readFileSync } from "node:fs";
+import path from "node:path";
+import { crateIdent, type RustCrateGraph } from "../analysis/rust-crates";
+import type { ProfileSources } from "./types";
+
+export function hasRootFile(rootPath: string, ...names: string[]): boolean {
+  return names.some((name) => existsSync(path.join(rootPath, name)));
+}
+
+/** The root `Cargo.toml` and those of `contracts/<name>` and `pallets/<name>` member crates. */
+export function contractManifests(rootPath: string): string[] {
+  const manifests = [path.join(rootPath, "Cargo.toml")];
+  for (const group of ["contracts", "pallets"]) {
+    try {
+      for (const entry of readdirSync(path.join(rootPath, group), { withFileTypes: true })) {
+        if (entry.isDirectory()) {
+          manifests.push(path.join(rootPath, group, entry.name, "Cargo.toml"));
+        }
+      }
+    } catch {
+      // No such directory.
+    }
+  }
+  return manifests.filter((manifest) => existsSync(manifest));
+}
+
+export function anyManifest(rootPath: string, matches: (text: string) => boolean): boolean {
+  return contractManifests(rootPath).some((manifest) => matches(readFileSync(manifest, "utf8")));
+}
+
+/** Whether any of `dirs` (relative to the root) directly holds a file with `extension`. */
+export function hasSourcesIn(rootPath: string, dirs: string[], extension: string): boolean {
+  return dirs.some((dir) => {
+    try {
+      return readdirSync(path.join(rootPath, dir)).some((name) => name.endsWith(extension));
+    } catch {
+      return false;
+    }
+  });
+}
+
+/** Whether any crate of the graph depends on one of `names` (`-` and `_` spellings alike). */
+export function crateDependsOn(graph: RustCrateGraph, ...names: string[]): boolean {
+  const wanted = new Set(names.map(crateIdent));
+  return graph.crates.some((crate) => crate.dependencies.some((dependency) => wanted.has(crateIdent(dependency))));
+}
+
+/** Contents of the `.rs` files among `sources`, keyed by path. */
+export async function rustSources(sources: ProfileSources): Promise<Map<string, string>> {
+  const contents = new Map<string, string>();
+  for (const filePath of sources.files.filter((candidate) => candidate.endsWith(".rs"))) {
+    const content = await sources.read(filePath);
+    if (content !== undefined) contents.set(filePath, content);
+  }
+  return contents;
+}
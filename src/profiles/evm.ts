@@ -0,0 +1,118 @@
+import { evmAccessScanner } from "../agents/scanner/evm-access";
+import { evmCallsScanner } from "../agents/scanner/evm-calls";
+import { evmSignatureScanner } from "../agents/scanner/evm-signature";
+import path from "node:path";
+import { buildSolidityProject, EVM_CONFIG_FILES, isExternallyCallable, isSolidityTestFile } from "../analysis/solidity-model";
+import { hasRootFile, hasSourcesIn } from "./detect";
+import type { DomainProfile, ProfileSources } from "./types";
+
+/** The project's own Solidity sources under the directory, leaving out tests, scripts and libraries. */
+function contractSources({ rootPath, files }: ProfileSources): string[] {
+  return files.filter((filePath) => filePath.endsWith(".sol") && !isSolidityTestFile(path.relative(rootPath, filePath)));
+}
+
+/** Foundry, Hardhat and Truffle projects, or Solidity sources directly under `contracts/` or `src/`. */
+export const evmProfile: DomainProfile = {
+  id: "evm",
+  name: "EVM",
+  priority: 90,
+  detect: (rootPath) => hasRootFile(rootPath, ...EVM_CONFIG_FILES) || hasSourcesIn(rootPath, ["contracts", "src"], ".sol"),
+  // The project's own contracts, not tests, scripts or vendored libraries.
+  files: { extensions: [".sol"], exclude: isSolidityTestFile },
+  scanners: [
+    {
+      scanner: evmCallsScanner,
+      kind: "pattern",
+      vulnClasses: ["reentrancy", "unchecked_call_return", "arbitrary_delegatecall"],
+      description:
+        "Solidity external calls: call{value:} or interface calls before state writes without a nonReentrant guard, ignored bool results of call/send/delegatecall, and delegatecall to argument or unguarded storage addresses."
+    },
+    {
+      scanner: evmAccessScanner,
+      kind: "pattern",
+      vulnClasses: ["missing_access_modifier", "tx_origin_auth"],
+      description:
+        "Solidity access control: public/external functions that write owner, fee, oracle or implementation state, mint, upgrade or selfdestruct with no access modifier or msg.sender check, and tx.origin authorization."
+    },
+    {
+      scanner: evmSignatureScanner,
+      kind: "pattern",
+      vulnClasses: ["unsafe_ecrecover"],
+      description: "Solidity signatures: raw ecrecover without an address(0) check, over digests with no nonce, chain id or contract binding."
+    }
+  ],
+  llmScanners: [
+    {
+      scannerId: "llm.scanner.evm.reentrancy",
+      vulnFocus:
+        "Solidity reentrancy: external calls (call{value:}, interface calls on caller-supplied or mutable contracts, token hooks) made before the function updates balances or other state, in functions without a nonReentrant guard, including cross-function reentrancy through shared state",
+      vulnClasses: ["reentrancy"],
+      description: "LLM-powered deep analysis of Solidity reentrancy, including cross-function paths."
+    },
+    {
+      scannerId: "llm.scanner.evm.access-control",
+      vulnFocus:
+        "Solidity access control: public or external functions that change owners, admins, fees, oracles, implementations or pause flags, mint, upgrade or selfdestruct without onlyOwner/onlyRole modifiers or msg.sender checks, unprotected initializers, and authorization through tx.origin",
+      vulnClasses: ["missing_access_modifier", "tx_origin_auth"],
+      description: "LLM-powered deep analysis of Solidity access control and initializers."
+    },
+    {
+      scannerId: "llm.scanner.evm.calls",
+      vulnFocus:
+        "Solidity low-level calls: unchecked bool results of call, send and delegatecall, delegatecall to addresses taken from arguments or from storage anyone can set, and untrusted call targets with arbitrary calldata",
+      vulnClasses: ["unchecked_call_return", "arbitrary_delegatecall"],
+      description: "LLM-powered deep analysis of Solidity low-level calls and delegatecall targets."
+    },
+    {
+      scannerId: "llm.scanner.evm.signature",
+      vulnFocus:
+        "Solidity signature verification: raw ecrecover without an address(0) check or s-value malleability bound, signed digests missing a nonce, chain id, deadline or verifying contract, and signatures that are never marked used",
+      vulnClasses: ["unsafe_ecrecover"],
+      description: "LLM-powered deep analysis of Solidity signature verification and replay protection."
+    }
+  ],
+  threatModel: [
+    {
+      frameworks: ["solidity-evm"],
+      assets: [
+        "Contract-held ether and ERC-20 balances",
+        "Owner, role and implementation storage slots",
+        "Signed off-chain authorizations (nonces, permits)"
+      ],
+      trustBoundaries: [
+        "msg.sender -> public and external functions",
+        "Contract -> external callees (call, interface calls, delegatecall)",
+        "Off-chain signers -> ecrecover-verified actions"
+      ],
+      attackSurface: [
+        "Public and external functions and their access modifiers",
+        "External calls ordered before state updates",
+        "Low-level call, send and delegatecall sites",
+        "Signature verification and replay protection"
+      ]
+    }
+  ],
+  async detectFrameworks(sources) {
+    return contractSources(sources).length > 0 || hasRootFile(sources.rootPath, ...EVM_CONFIG_FILES) ? ["solidity-evm"] : [];
+  },
+  /** Every public or external function of a deployable contract. */
+  async entryPoints(sources) {
+    const contents = new Map<string, string>();
+    for (const filePath of contractSources(sources)) {
+      const content = await sources.read(filePath);
+      if (content !== undefined) contents.set(filePath, content);
+    }
+    const entryPoints: string[] = [];
+    for (const source of buildSolidityProject(contents).sources) {
+      const relPath = sources.relPath(source.file);
+      for (const contract of source.contracts.filter((candidate) => candidate.kind === "contract" || candidate.kind === "abstract")) {
+        for (const fn of contract.functions.filter(isExternallyCallable)) {
+          entryPoints.push(`${relPath}::${contract.name}::${fn.name}`);
+        }
+      }
+    }
+    return entryPoints;
+  },
+  sandboxProfile: "evm",
+  vulnClasses: ["reentrancy", "tx_origin_auth", "unchecked_call_return", "arbitrary_delegatecall", "missing_access_modifier", "unsafe_ecrecover"]
+};
@@ -0,0 +1,64 @@
+import { genericAppSecScanner } from "../agents/scanner/generic-appsec";
+import { hasRootFile } from "./detect";
+import type { DomainProfile } from "./types";
+
+/** The fallback for any repository no other profile claims: web and backend application code. */
+export const genericProfile: DomainProfile = {
+  id: "generic",
+  name: "Generic AppSec",
+  priority: 0,
+  detect: () => true,
+  files: {
+    extensions: [".js", ".jsx", ".ts", ".tsx", ".mjs", ".cjs", ".py", ".go", ".java", ".rb", ".php", ".cs", ".rs"]
+  },
+  scanners: [
+    {
+      scanner: genericAppSecScanner,
+      kind: "pattern",
+      vulnClasses: ["hardcoded_secret", "command_injection", "sql_injection", "xss", "insecure_deserialization"],
+      description: "Generic application security scanner for common issues in web/backend codebases."
+    }
+  ],
+  llmScanners: [
+    {
+      scannerId: "llm.scanner.generic.secrets",
+      vulnFocus: "hardcoded secrets and credential leakage",
+      vulnClasses: ["hardcoded_secret"],
+      description: "LLM-powered deep analysis of credential leakage patterns."
+    },
+    {
+      scannerId: "llm.scanner.generic.command-injection",
+      vulnFocus: "command injection and unsafe shell/process execution",
+      vulnClasses: ["command_injection"],
+      description: "LLM-powered deep analysis of command execution risks."
+    },
+    {
+      scannerId: "llm.scanner.generic.injection-and-deserialization",
+      vulnFocus: "sql injection, xss sinks, and insecure deserialization",
+      vulnClasses: ["sql_injection", "xss", "insecure_deserialization"],
+      description: "LLM-powered deep analysis of injection and deserialization risks."
+    }
+  ],
+  threatModel: [
+    {
+      frameworks: ["nodejs", "javascript-runtime"],
+      assets: ["API authentication state", "Runtime configuration and environment variables"]
+    }
+  ],
+  /** Language runtimes: Node and TypeScript projects, and loose Rust or JavaScript without a manifest. */
+  async detectFrameworks({ rootPath, files }) {
+    const frameworks: string[] = [];
+    const hasPackageJson = hasRootFile(rootPath, "package.json");
+    if (hasPackageJson) frameworks.push("nodejs");
+    if (hasRootFile(rootPath, "tsconfig.json")) frameworks.push("typescript");
+    if (files.some((filePath) => filePath.endsWith(".rs")) && !hasRootFile(rootPath, "Cargo.toml")) {
+      frameworks.push("rust");
+    }
+    if (files.some((filePath) => /\.(?:tsx?|jsx?)$/.test(filePath)) && !hasPackageJson) {
+      frameworks.push("javascript-runtime");
+    }
+    return frameworks;
+  },
+  sandboxProfile: "generic",
+  vulnClasses: ["hardcoded_secret", "command_injection", "sql_injection", "xss", "insecure_deserialization"]
+};
@@ -0,0 +1,244 @@
+import { promises as fs, readdirSync, type Dirent } from "node:fs";
+import path from "node:path";
+import { pathToFileURL } from "node:url";
+import { DEFAULT_SANDBOX_CONFIG } from "../sandbox/types";
+import { assertVulnClassDefinition, getVulnClass, registerVulnClass } from "../vulns/registry";
+import { cosmwasmProfile } from "./cosmwasm";
+import { hasRootFile } from "./detect";
+import { evmProfile } from "./evm";
+import { genericProfile } from "./generic";
+import { rustProfile } from "./rust";
+import { solanaProfile } from "./solana";
+import { substrateProfile } from "./substrate";
+import type { DomainProfile, ProfileScope } from "./types";
+
+const PROFILE_MODULE = /\.(?:m?js|ts)$/;
+/** How deep below the root to look for nested domains such as `contracts/<name>` or `services/api`. */
+const MAX_SCOPE_DEPTH = 3;
+/** Build output, dependencies and Foundry's vendored libraries never hold a domain of their own. */
+const SKIPPED_SCOPE_DIRS = new Set(["node_modules", "target", "dist", "build", "coverage", "out", "lib"]);
+/** Inside a detected subtree, only directories holding one of these can start another domain. */
+const NESTED_MANIFESTS = ["Cargo.toml", "package.json", "foundry.toml", "Anchor.toml"];
+
+const profiles = new Map<string, DomainProfile>();
+const directoryLoads = new Map<string, Promise<DomainProfile[]>>();
+
+export function registerProfile(profile: DomainProfile): void {
+  if (profiles.has(profile.id)) {
+    throw new Error(`Domain profile already registered: ${profile.id}`);
+  }
+  const definitions = profile.vulnClassDefinitions ?? [];
+  const taken = definitions.filter((definition) => getVulnClass(definition.id));
+  if (taken.length > 0) {
+    const ids = taken.map((definition) => definition.id).join(", ");
+    throw new Error(`Domain profile ${profile.id} redefines vulnerability classes: ${ids}`);
+  }
+  const defined = new Set(definitions.map((definition) => definition.id));
+  const named = [
+    ...profile.vulnClasses,
+    ...profile.scanners.flatMap((entry) => entry.vulnClasses),
+    ...profile.llmScanners.flatMap((config) => config.vulnClasses)
+  ];
+  const unknown = [...new Set(named.filter((id) => !defined.has(id) && !getVulnClass(id)))];
+  if (unknown.length > 0) {
+    throw new Error(`Domain profile ${profile.id} names unknown vulnerability classes: ${unknown.join(", ")}`);
+  }
+  for (const definition of definitions) {
+    registerVulnClass(definition);
+  }
+  profiles.set(profile.id, profile);
+}
+
+for (const profile of [solanaProfile, evmProfile, cosmwasmProfile, substrateProfile, rustProfile, genericProfile]) {
+  registerProfile(profile);
+}
+
+/** Registered profiles, highest priority first. */
+export function listProfiles(): DomainProfile[] {
+  return [...profiles.values()].sort((a, b) => b.priority - a.priority);
+}
+
+export function getProfile(id: string): DomainProfile | undefined {
+  return profiles.get(id);
+}
+
+/**
+ * Every domain in the target. Walking down from the root, each profile that detects a directory
+ * gets that directory as its subtree, unless a higher-priority profile detected there already reads
+ * all of its files. Below a detected directory, only one with its own manifest can start another
+ * subtree, such as an axum service beside the programs of an Anchor workspace. The generic profile
+ * then covers the files no subtree profile reads, unless a profile at the root already includes it.
+ * `HYDRA_SCAN_DOMAIN` forces one profile over the whole tree.
+ */
+export function detectProfileScopes(rootPath: string): ProfileScope[] {
+  const forced = process.env.HYDRA_SCAN_DOMAIN?.toLowerCase();
+  const forcedProfile = forced ? profiles.get(forced) : undefined;
+  if (forcedProfile) {
+    return [{ profile: forcedProfile, root: rootPath }];
+  }
+  return detectSubtreeProfiles(rootPath);
+}
+
+/** The scopes `detectProfileScopes` finds by walking the tree, whatever `HYDRA_SCAN_DOMAIN` says. */
+export function detectSubtreeProfiles(rootPath: string): ProfileScope[] {
+  const candidates = listProfiles().filter((profile) => profile !== genericProfile);
+  const scopes: ProfileScope[] = [];
+  const visit = (dir: string, depth: number, enclosing: DomainProfile[]): void => {
+    // A member crate an enclosing profile also detects, like `programs/vault`, stays in that subtree.
+    const open =
+      enclosing.length === 0 ||
+      (hasRootFile(dir, ...NESTED_MANIFESTS) && !enclosing.some((profile) => profile.detect(dir)));
+    const detected = open ? unshadowed(candidates.filter((candidate) => candidate.detect(dir))) : [];
+    if (detected.length > 0) {
+      scopes.push(...detected.map((profile) => ({ profile, root: dir })));
+    }
+    if (depth >= MAX_SCOPE_DEPTH) return;
+    let entries: Dirent[];
+    try {
+      entries = readdirSync(dir, { withFileTypes: true });
+    } catch {
+      return;
+    }
+    for (const entry of entries.sort((a, b) => a.name.localeCompare(b.name))) {
+      if (entry.isDirectory() && !entry.name.startsWith(".") && !SKIPPED_SCOPE_DIRS.has(entry.name)) {
+        visit(path.join(dir, entry.name), depth + 1, detected.length > 0 ? detected : enclosing);
+      }
+    }
+  };
+  visit(rootPath, 0, []);
+
+  const fallback: ProfileScope = { profile: genericProfile, root: rootPath };
+  if (scopes.length === 0 || (!coversGeneric(scopes, rootPath) && hasUnclaimedFile(rootPath, scopes, fallback))) {
+    scopes.push(fallback);
+  }
+  return scopes;
+}
+
+/**
+ * Profiles detected at one directory, highest priority first, without those whose file types a
+ * higher-priority one already reads (Rust AppSec at an Anchor or CosmWasm workspace root).
+ */
+function unshadowed(detected: DomainProfile[]): DomainProfile[] {
+  return detected.filter(
+    (profile, index) =>
+      !profile.files.extensions.every((extension) =>
+        detected.slice(0, index).some((other) => other.files.extensions.includes(extension))
+      )
+  );
+}
+
+function coversGeneric(scopes: ProfileScope[], rootPath: string): boolean {
+  return scopes.some((scope) => scope.root === rootPath && expandProfile(scope.profile).includes(genericProfile));
+}
+
+/** Whether `fallback` would read a file none of `scopes` reads, such as the TypeScript client of an Anchor workspace. */
+function hasUnclaimedFile(dir: string, scopes: ProfileScope[], fallback: ProfileScope): boolean {
+  let entries: Dirent[];
+  try {
+    entries = readdirSync(dir, { withFileTypes: true });
+  } catch {
+    return false;
+  }
+  return entries.some((entry) => {
+    const entryPath = path.join(dir, entry.name);
+    if (entry.isDirectory()) {
+      return !entry.name.startsWith(".") && !SKIPPED_SCOPE_DIRS.has(entry.name) && hasUnclaimedFile(entryPath, scopes, fallback);
+    }
+    return entry.isFile() && scopeReads(fallback, entryPath) && !scopes.some((scope) => scopeReads(scope, entryPath));
+  });
+}
+
+/** Whether `filePath` lies under the scope's subtree and one of its profiles' file selections takes it. */
+export function scopeReads(scope: ProfileScope, filePath: string): boolean {
+  const relPath = path.relative(scope.root, filePath);
+  if (relPath.startsWith("..") || path.isAbsolute(relPath)) return false;
+  return expandProfile(scope.profile).some(
+    ({ files }) => files.extensions.some((extension) => filePath.endsWith(extension)) && !files.exclude?.(relPath)
+  );
+}
+
+/** A profile and the profiles it includes, included ones first and each once. */
+export function expandProfile(profile: DomainProfile, seen = new Set<string>()): DomainProfile[] {
+  if (seen.has(profile.id)) return [];
+  seen.add(profile.id);
+  const included = (profile.includes ?? []).flatMap((id) => {
+    const dependency = profiles.get(id);
+    if (!dependency) {
+      throw new Error(`Domain profile ${profile.id} includes unknown profile: ${id}`);
+    }
+    return expandProfile(dependency, seen);
+  });
+  return [...included, profile];
+}
+
+/** The highest-priority profile whose files include `filePath`; decides where exploits for a finding run. */
+export function profileForFile(filePath: string): DomainProfile {
+  return (
+    listProfiles().find((profile) => profile.files.extensions.some((extension) => filePath.endsWith(extension))) ??
+    genericProfile
+  );
+}
+
+function assertProfile(value: unknown, source: string): DomainProfile {
+  const profile = (value ?? {}) as Partial<DomainProfile>;
+  const problems: string[] = [];
+  if (typeof profile.id !== "string" || !/^[a-z0-9][a-z0-9-]*$/.test(profile.id)) problems.push("id must be a lowercase slug");
+  if (typeof profile.name !== "string") problems.push("name must be a string");
+  if (typeof profile.priority !== "number") problems.push("priority must be a number");
+  if (typeof profile.detect !== "function") problems.push("detect must be a function");
+  if (!Array.isArray(profile.files?.extensions)) problems.push("files.extensions must be an array");
+  if (
+    !Array.isArray(profile.scanners) ||
+    profile.scanners.some((entry) => typeof entry?.scanner?.id !== "string" || typeof entry.scanner.scan !== "function")
+  ) {
+    problems.push("scanners must be an array of { scanner: { id, scan } } entries");
+  }
+  if (!Array.isArray(profile.llmScanners)) problems.push("llmScanners must be an array");
+  if (!Array.isArray(profile.vulnClasses)) problems.push("vulnClasses must be an array");
+  if (profile.vulnClassDefinitions !== undefined && !Array.isArray(profile.vulnClassDefinitions)) {
+    problems.push("vulnClassDefinitions must be an array");
+  }
+  if (typeof profile.sandboxProfile !== "string" || !(profile.sandboxProfile in DEFAULT_SANDBOX_CONFIG)) {
+    problems.push(`sandboxProfile must be one of ${Object.keys(DEFAULT_SANDBOX_CONFIG).join(", ")}`);
+  }
+  if (problems.length > 0) {
+    throw new Error(`Invalid domain profile in ${source}: ${problems.join("; ")}`);
+  }
+  for (const definition of profile.vulnClassDefinitions ?? []) {
+    assertVulnClassDefinition(definition, source);
+  }
+  return profile as DomainProfile;
+}
+
+/**
+ * Register the profiles of every module in `dir`. A module exports one profile as `default` or
+ * `profile`, or several as `profiles`.
+ */
+export async function loadProfilesFromDirectory(dir: string): Promise<DomainProfile[]> {
+  const modules = (await fs.readdir(dir)).filter((name) => PROFILE_MODULE.test(name) && !name.endsWith(".d.ts")).sort();
+  const loaded: DomainProfile[] = [];
+  for (const name of modules) {
+    const file = path.join(dir, name);
+    const exports = (await import(pathToFileURL(file).href)) as Record<string, unknown>;
+    const exported = exports.profiles ?? exports.profile ?? exports.default;
+    for (const candidate of Array.isArray(exported) ? exported : [exported]) {
+      const profile = assertProfile(candidate, file);
+      registerProfile(profile);
+      loaded.push(profile);
+    }
+  }
+  return loaded;
+}
+
+/** The registered profiles, after loading those in `HYDRA_PROFILE_DIR` (once per process). */
+export async function loadConfiguredProfiles(): Promise<DomainProfile[]> {
+  const dir = process.env.HYDRA_PROFILE_DIR;
+  if (dir) {
+    const resolved = path.resolve(dir);
+    if (!directoryLoads.has(resolved)) {
+      directoryLoads.set(resolved, loadProfilesFromDirectory(resolved));
+    }
+    await directoryLoads.get(resolved);
+  }
+  return listProfiles();
+}
@@ -0,0 +1,113 @@
+import { rustAppSecScanner } from "../agents/scanner/rust-appsec";
+import { isNativeProgramSource } from "../analysis/native-model";
+import { extractRequestHandlers, routedFunctions } from "../analysis/rust-web";
+import { crateDependsOn, hasRootFile, rustSources } from "./detect";
+import type { DomainProfile } from "./types";
+
+/** Crates that make a Cargo package an HTTP service. */
+const RUST_WEB_CRATES = ["axum", "actix_web", "rocket", "warp", "poem", "tide", "hyper"];
+
+/** Any other Cargo package or workspace: a Rust service, CLI or library. The generic checks run too. */
+export const rustProfile: DomainProfile = {
+  id: "rust",
+  name: "Rust AppSec",
+  priority: 10,
+  detect: (rootPath) => hasRootFile(rootPath, "Cargo.toml"),
+  includes: ["generic"],
+  files: { extensions: [".rs"], rustCrates: true },
+  scanners: [
+    {
+      scanner: rustAppSecScanner,
+      kind: "pattern",
+      vulnClasses: ["rust_command_injection", "rust_sql_injection", "path_traversal", "unsafe_code", "handler_panic", "unbounded_deserialization"],
+      description:
+        "Rust service checks: format!-built Command programs, arguments and sh -c scripts, format!-built SQL, request input joined onto file paths, undocumented unsafe and FFI, panics reachable from axum/actix/Rocket handlers, and size-unbounded deserialization."
+    }
+  ],
+  llmScanners: [
+    {
+      scannerId: "llm.scanner.rust.command-injection",
+      vulnFocus:
+        "std::process::Command and tokio::process::Command spawned with format!-built programs or arguments, and sh -c / cmd /C invocations whose script interpolates request data",
+      vulnClasses: ["rust_command_injection"],
+      description: "LLM-powered deep analysis of process spawning in Rust crates."
+    },
+    {
+      scannerId: "llm.scanner.rust.sql-injection",
+      vulnFocus:
+        "SQL text built with format! or string concatenation and passed to sqlx::query, query_as, diesel::sql_query, rusqlite or postgres execute/query/prepare instead of bound parameters",
+      vulnClasses: ["rust_sql_injection"],
+      description: "LLM-powered deep analysis of SQL construction with sqlx, diesel, rusqlite and postgres."
+    },
+    {
+      scannerId: "llm.scanner.rust.path-traversal",
+      vulnFocus:
+        "path traversal: request extractor values (Path, Query, Json, Form, multipart file names) joined onto a base directory with Path::join or PathBuf::push and opened without rejecting .. components or checking the canonicalized path stays under the base",
+      vulnClasses: ["path_traversal"],
+      description: "LLM-powered deep analysis of request input reaching file-system paths."
+    },
+    {
+      scannerId: "llm.scanner.rust.unsafe-code",
+      vulnFocus:
+        "unsafe blocks, unsafe impl Send/Sync and FFI (extern \"C\" imports, #[no_mangle] exports) whose soundness depends on undocumented invariants or on lengths and pointers derived from untrusted input",
+      vulnClasses: ["unsafe_code"],
+      description: "LLM-powered review of unsafe blocks and FFI boundaries."
+    },
+    {
+      scannerId: "llm.scanner.rust.handler-panic",
+      vulnFocus:
+        "panics reachable from HTTP request handlers: unwrap, expect, panic!, indexing and assertions on client-controlled values, directly or through the helpers a handler calls",
+      vulnClasses: ["handler_panic"],
+      description: "LLM-powered deep analysis of panics reachable from request handlers."
+    },
+    {
+      scannerId: "llm.scanner.rust.deserialization",
+      vulnFocus:
+        "deserialization of untrusted input without size limits: bincode without with_limit, serde_json/ciborium/rmp_serde from_reader on unbounded readers, request bodies buffered with no limit, and disabled body limits",
+      vulnClasses: ["unbounded_deserialization"],
+      description: "LLM-powered deep analysis of size-unbounded deserialization of untrusted input."
+    }
+  ],
+  threatModel: [
+    {
+      frameworks: ["rust-web"],
+      assets: [
+        "Server file system reachable from request paths",
+        "Database contents behind query construction",
+        "Service availability under hostile requests"
+      ],
+      trustBoundaries: ["HTTP request extractors -> handler logic", "Handler logic -> OS processes, SQL and file system"],
+      attackSurface: [
+        "Request extractors (Path, Query, Json, Form, multipart)",
+        "Process spawning, SQL and file-system calls in handlers",
+        "Request body size limits and deserialization"
+      ]
+    },
+    {
+      frameworks: ["rust-cargo"],
+      unless: ["solana-anchor", "solana-native", "cosmwasm", "ink", "substrate-pallet"],
+      trustBoundaries: ["Safe Rust -> unsafe blocks and FFI"]
+    }
+  ],
+  async detectFrameworks({ rootPath, crateGraph }) {
+    if (!hasRootFile(rootPath, "Cargo.toml")) {
+      return [];
+    }
+    return crateDependsOn(await crateGraph(), ...RUST_WEB_CRATES) ? ["rust-cargo", "rust-web"] : ["rust-cargo"];
+  },
+  /** Request handlers the routers of a web service dispatch to, outside Solana program sources. */
+  async entryPoints(sources) {
+    const contents = await rustSources(sources);
+    const routed = new Set([...contents.values()].flatMap((content) => [...routedFunctions(content)]));
+    const entryPoints: string[] = [];
+    for (const [filePath, content] of contents) {
+      if (content.includes("#[program]") || isNativeProgramSource(content)) continue;
+      for (const handler of extractRequestHandlers(content, routed)) {
+        entryPoints.push(`${sources.relPath(filePath)}::${handler.fn.name}`);
+      }
+    }
+    return entryPoints;
+  },
+  sandboxProfile: "generic",
+  vulnClasses: ["rust_command_injection", "rust_sql_injection", "path_traversal", "unsafe_code", "handler_panic", "unbounded_deserialization"]
+};
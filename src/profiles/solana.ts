@@ -0,0 +1,327 @@
+import { readFileSync } from "node:fs";
+import path from "node:path";
+import { solanaAccountValidationScanner } from "../agents/scanner/solana-account-validation";
+import { solanaCpiScanner } from "../agents/scanner/solana-cpi";
+import { solanaPdaScanner } from "../agents/scanner/solana-pda";
+import { solanaMathScanner } from "../agents/scanner/solana-math";
+import { solanaStateScanner } from "../agents/scanner/solana-state";
+import { solanaEconomicScanner } from "../agents/scanner/solana-economic";
+import { solanaNativeScanner } from "../agents/scanner/solana-native";
+import { solanaTokenScanner } from "../agents/scanner/solana-token";
+import { solanaIdlScanner } from "../agents/scanner/solana-idl";
+import { solanaAccountSpoofingScanner } from "../agents/scanner/solana-account-spoofing";
+import { loadPrivilegeModels, solanaPrivilegeScanner, summarizePrivileges } from "../agents/scanner/solana-privilege";
+import { deterministicSignalScanner } from "../agents/scanner/deterministic-signals";
+import { loadAnchorIdls } from "../analysis/anchor-idl";
+import { extractProgramInstructions } from "../analysis/anchor-model";
+import { authorityBoundaries, buildAuthorityGraph } from "../analysis/authority-graph";
+import { extractNativeProcessors, isNativeProgramSource } from "../analysis/native-model";
+import { crateDependsOn, hasRootFile, rustSources } from "./detect";
+import type { DomainProfile } from "./types";
+
+/**
+ * Crates without an Anchor.toml: native programs depend on `solana-program` and declare an
+ * `entrypoint!`, and the member crates of an Anchor workspace depend on `anchor-lang`.
+ */
+function isSolanaCrate(rootPath: string): boolean {
+  try {
+    const manifest = readFileSync(path.join(rootPath, "Cargo.toml"), "utf8");
+    if (/^\s*(?:solana[-_]program|anchor[-_]lang)\s*=/m.test(manifest)) {
+      return true;
+    }
+  } catch {
+    // No Cargo.toml at the root.
+  }
+  return hasRootFile(rootPath, path.join("src", "entrypoint.rs"));
+}
+
+const SOLANA_FRAMEWORKS = ["solana-anchor", "solana-native"];
+
+export const solanaProfile: DomainProfile = {
+  id: "solana",
+  name: "Solana",
+  priority: 100,
+  detect: (rootPath) => hasRootFile(rootPath, "Anchor.toml", "programs") || isSolanaCrate(rootPath),
+  files: { extensions: [".rs"], rustCrates: true },
+  scanners: [
+    {
+      scanner: solanaAccountValidationScanner,
+      kind: "pattern",
+      vulnClasses: ["missing_signer_check", "missing_has_one", "account_type_confusion", "missing_owner_check", "duplicate_mutable_accounts"],
+      description:
+        "Detects missing signer checks, relationship constraints, missing owner checks on deserialized raw accounts, account type confusion, and same-type mutable accounts that can be passed twice, by querying a structured model of #[derive(Accounts)] structs."
+    },
+    {
+      scanner: solanaCpiScanner,
+      kind: "pattern",
+      vulnClasses: ["arbitrary_cpi", "cpi_signer_seed_bypass", "cpi_reentrancy"],
+      description:
+        "Detects unsafe cross-program invocation patterns including arbitrary CPI targets, reentrancy, and signer seed bypass, tracing caller-chosen program ids and signer seeds from instruction arguments and unchecked account data."
+    },
+    {
+      scanner: solanaPdaScanner,
+      kind: "pattern",
+      vulnClasses: ["non_canonical_bump", "seed_collision", "attacker_controlled_seed"],
+      description:
+        "Detects PDA derivation issues including non-canonical bumps, missing seed domain separation, attacker-controlled seeds traced from instruction arguments, and program-wide seed schemas of different account kinds that can derive the same address."
+    },
+    {
+      scanner: solanaMathScanner,
+      kind: "pattern",
+      vulnClasses: ["integer_overflow", "precision_loss", "unsafe_cast", "division_by_zero"],
+      description: "Detects unchecked token arithmetic, lossy casts, division before multiplication, share/price rounding and unguarded divisors."
+    },
+    {
+      scanner: solanaStateScanner,
+      kind: "pattern",
+      vulnClasses: ["reinitialization", "unsafe_init_if_needed", "unsafe_account_close", "realloc_without_zero"],
+      description: "Detects account lifecycle bugs: re-initialization, unguarded init_if_needed, close-and-revive and realloc without zeroing."
+    },
+    {
+      scanner: solanaEconomicScanner,
+      kind: "pattern",
+      vulnClasses: ["unchecked_oracle_price", "missing_slippage_check", "spot_price_manipulation", "fee_rounding"],
+      description:
+        "Detects DeFi economic risks: unvalidated oracle prices, swaps without minimum output, spot-balance share pricing and fee truncation."
+    },
+    {
+      scanner: solanaNativeScanner,
+      kind: "pattern",
+      vulnClasses: ["missing_signer_check", "missing_owner_check", "account_type_confusion", "arbitrary_cpi", "non_canonical_bump"],
+      description:
+        "Checks native (non-Anchor) processors: is_signer, owner and program-id checks per next_account_info account, and try_from_slice discriminators."
+    },
+    {
+      scanner: solanaTokenScanner,
+      kind: "pattern",
+      vulnClasses: [
+        "missing_token_mint_check",
+        "missing_token_authority_check",
+        "associated_token_mismatch",
+        "unchecked_token_extension",
+        "substitutable_transfer_authority"
+      ],
+      description:
+        "Checks SPL Token / Token-2022 accounts: mint and authority bindings on transferred token accounts, ATA derivation, unvetted Token-2022 extensions and PDA signer seeds built from unsigned accounts."
+    },
+    {
+      scanner: solanaIdlScanner,
+      kind: "pattern",
+      vulnClasses: ["missing_signer_check", "attacker_controlled_seed"],
+      description:
+        "Cross-checks Anchor IDLs (target/idl/*.json) against the source: authority accounts the IDL marks as non-signers, writable PDAs seeded only by instruction args, and instructions without any signer."
+    },
+    {
+      scanner: solanaAccountSpoofingScanner,
+      kind: "pattern",
+      vulnClasses: ["unchecked_remaining_accounts", "sysvar_spoofing"],
+      description:
+        "Flags remaining_accounts entries used without owner, key or typed-load validation, and sysvar accounts (notably the instructions sysvar) taken as raw accounts and read without pinning their address."
+    },
+    {
+      scanner: solanaPrivilegeScanner,
+      kind: "pattern",
+      vulnClasses: ["unnecessary_mut_account", "unnecessary_signer", "overprivileged_pda_signer"],
+      description:
+        "Least-privilege audit joining each handler body with its Accounts struct: mut accounts the handler never writes, Signer accounts whose signature authorizes nothing, and program-wide PDA authorities signing several kinds of CPI."
+    },
+    {
+      scanner: deterministicSignalScanner,
+      kind: "deterministic",
+      vulnClasses: ["missing_signer_check", "arbitrary_cpi", "non_canonical_bump"],
+      description: "Rule-based deterministic signal detection (regex lint-level checks)."
+    }
+  ],
+  llmScanners: [
+    {
+      scannerId: "llm.scanner.solana.account-validation",
+      vulnFocus:
+        "missing signer check, missing has_one constraint, missing owner check, account type confusion, and duplicate mutable accounts of the same type that can alias without a key inequality check",
+      vulnClasses: ["missing_signer_check", "missing_has_one", "account_type_confusion", "missing_owner_check", "duplicate_mutable_accounts"],
+      description: "LLM-powered deep analysis of account validation patterns."
+    },
+    {
+      scannerId: "llm.scanner.solana.cpi",
+      vulnFocus: "arbitrary CPI, CPI signer seed bypass, and CPI reentrancy",
+      vulnClasses: ["arbitrary_cpi", "cpi_signer_seed_bypass", "cpi_reentrancy"],
+      description: "LLM-powered deep analysis of CPI patterns."
+    },
+    {
+      scannerId: "llm.scanner.solana.pda",
+      vulnFocus: "non-canonical bump, seed collision, and attacker-controlled seed",
+      vulnClasses: ["non_canonical_bump", "seed_collision", "attacker_controlled_seed"],
+      description: "LLM-powered deep analysis of PDA derivation patterns."
+    },
+    {
+      scannerId: "llm.scanner.solana.math",
+      vulnFocus: "integer overflow, precision loss and rounding direction, unsafe casts, and division by zero in token math",
+      vulnClasses: ["integer_overflow", "precision_loss", "unsafe_cast", "division_by_zero"],
+      description: "LLM-powered deep analysis of token math and precision."
+    },
+    {
+      scannerId: "llm.scanner.solana.state",
+      vulnFocus: "account re-initialization, unsafe init_if_needed, close-and-revive, and realloc without zeroing",
+      vulnClasses: ["reinitialization", "unsafe_init_if_needed", "unsafe_account_close", "realloc_without_zero"],
+      description: "LLM-powered deep analysis of account lifecycle and state management."
+    },
+    {
+      scannerId: "llm.scanner.solana.economic",
+      vulnFocus: "oracle staleness and confidence, missing slippage bounds, spot-balance share pricing, and fee rounding",
+      vulnClasses: ["unchecked_oracle_price", "missing_slippage_check", "spot_price_manipulation", "fee_rounding"],
+      description: "LLM-powered deep analysis of oracle, slippage, flash-loan and fee economics."
+    },
+    {
+      scannerId: "llm.scanner.solana.native",
+      vulnFocus: "native (non-Anchor) processors: missing is_signer, owner and program id checks, and try_from_slice discriminators",
+      vulnClasses: ["missing_signer_check", "missing_owner_check", "account_type_confusion", "arbitrary_cpi", "non_canonical_bump"],
+      description: "LLM-powered deep analysis of native solana_program processors."
+    },
+    {
+      scannerId: "llm.scanner.solana.token",
+      vulnFocus:
+        "token account mint/authority bindings, associated token mismatches, Token-2022 hooks and permanent delegates, and substitutable transfer authorities",
+      vulnClasses: [
+        "missing_token_mint_check",
+        "missing_token_authority_check",
+        "associated_token_mismatch",
+        "unchecked_token_extension",
+        "substitutable_transfer_authority"
+      ],
+      description: "LLM-powered deep analysis of SPL Token and Token-2022 account handling."
+    },
+    {
+      scannerId: "llm.scanner.solana.account-spoofing",
+      vulnFocus:
+        "ctx.remaining_accounts iterated or indexed and trusted without owner, key or typed Account::try_from validation, and sysvar accounts (especially the instructions sysvar used for introspection) taken as AccountInfo without an address = sysvar::instructions::ID check",
+      vulnClasses: ["unchecked_remaining_accounts", "sysvar_spoofing"],
+      description: "LLM-powered deep analysis of remaining_accounts validation and sysvar spoofing."
+    },
+    {
+      scannerId: "llm.scanner.solana.privilege",
+      vulnFocus:
+        "excess privilege: accounts marked #[account(mut)] that the handler never writes, co-signer accounts whose signature no constraint or check relies on, and one global PDA signing invoke_signed for unrelated operations where per-purpose seeds would limit the blast radius",
+      vulnClasses: ["unnecessary_mut_account", "unnecessary_signer", "overprivileged_pda_signer"],
+      description: "LLM-powered least-privilege review of account mutability, signer requirements and PDA signing authority."
+    }
+  ],
+  threatModel: [
+    {
+      frameworks: SOLANA_FRAMEWORKS,
+      assets: ["Program-owned state accounts", "PDA authority relationships", "CPI permission boundaries", "Token custody and transfer invariants"],
+      trustBoundaries: [
+        "Transaction accounts -> instruction handlers",
+        "Program -> CPI target programs",
+        "Signer authorities -> PDA-derived authorities"
+      ]
+    },
+    {
+      frameworks: ["solana-anchor"],
+      attackSurface: ["Account validation constraints", "Cross-program invocation callsites", "PDA seed derivation and bump handling"]
+    },
+    {
+      frameworks: ["solana-native"],
+      attackSurface: [
+        "Manual is_signer, owner and program id checks",
+        "Account data deserialization (try_from_slice / unpack)",
+        "Cross-program invocation callsites",
+        "PDA seed derivation and bump handling"
+      ]
+    }
+  ],
+  async detectFrameworks({ rootPath, crateGraph }) {
+    if (hasRootFile(rootPath, "Anchor.toml")) {
+      return ["solana-anchor"];
+    }
+    if (!hasRootFile(rootPath, "Cargo.toml")) {
+      return [];
+    }
+    const manifest = readFileSync(path.join(rootPath, "Cargo.toml"), "utf8");
+    // A workspace root lists no dependencies of its own; its member crates do.
+    return /^\s*solana[-_]program\s*=/m.test(manifest) || crateDependsOn(await crateGraph(), "solana_program")
+      ? ["solana-native"]
+      : [];
+  },
+  /**
+   * Instructions of `#[program]` modules, the processors of native programs that read accounts from
+   * the `&[AccountInfo]` slice, and IDL instructions the source parser missed.
+   */
+  async entryPoints(sources) {
+    const entryPoints: string[] = [];
+    for (const [filePath, content] of await rustSources(sources)) {
+      const relPath = sources.relPath(filePath);
+      if (content.includes("#[program]")) {
+        for (const instruction of extractProgramInstructions(content, filePath)) {
+          entryPoints.push(`${relPath}::${instruction.programModule}::${instruction.name}`);
+        }
+      } else if (isNativeProgramSource(content)) {
+        for (const processor of extractNativeProcessors(content, filePath)) {
+          entryPoints.push(`${relPath}::${processor.name}`);
+        }
+      }
+    }
+    for (const idl of await loadAnchorIdls(sources.rootPath)) {
+      const idlPath = sources.relPath(idl.file);
+      for (const instruction of idl.instructions) {
+        const suffix = `::${idl.programName}::${instruction.name}`;
+        if (!entryPoints.some((entryPoint) => entryPoint.endsWith(suffix))) {
+          entryPoints.push(`${idlPath}${suffix}`);
+        }
+      }
+    }
+    return entryPoints;
+  },
+  /** Concrete boundaries from the program's authority graph: which signers and PDAs authorize what. */
+  async deriveTrustBoundaries(rootPath, frameworks) {
+    if (!SOLANA_FRAMEWORKS.some((framework) => frameworks.includes(framework))) {
+      return [];
+    }
+    try {
+      const { models } = await loadPrivilegeModels(rootPath);
+      return authorityBoundaries(buildAuthorityGraph(models));
+    } catch {
+      return [];
+    }
+  },
+  // Exploits for programs are TypeScript clients run with bun, which only the generic image ships.
+  sandboxProfile: "generic",
+  vulnClasses: [
+    "missing_signer_check",
+    "missing_has_one",
+    "account_type_confusion",
+    "missing_owner_check",
+    "duplicate_mutable_accounts",
+    "arbitrary_cpi",
+    "cpi_signer_seed_bypass",
+    "cpi_reentrancy",
+    "non_canonical_bump",
+    "seed_collision",
+    "attacker_controlled_seed",
+    "integer_overflow",
+    "precision_loss",
+    "unsafe_cast",
+    "division_by_zero",
+    "reinitialization",
+    "unsafe_init_if_needed",
+    "unsafe_account_close",
+    "realloc_without_zero",
+    "unchecked_oracle_price",
+    "missing_slippage_check",
+    "spot_price_manipulation",
+    "fee_rounding",
+    "missing_token_mint_check",
+    "missing_token_authority_check",
+    "associated_token_mismatch",
+    "unchecked_token_extension",
+    "substitutable_transfer_authority",
+    "unchecked_remaining_accounts",
+    "sysvar_spoofing",
+    "unnecessary_mut_account",
+    "unnecessary_signer",
+    "overprivileged_pda_signer"
+  ],
+  // The summary and graph are informational; the dispatcher drops them if they fail to build.
+  async summarize(rootPath) {
+    const { models } = await loadPrivilegeModels(rootPath);
+    return { privileges: summarizePrivileges(models), authority_graph: buildAuthorityGraph(models) };
+  }
+};
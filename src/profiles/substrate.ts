@@ -0,0 +1,119 @@
+import { inkContractScanner } from "../agents/scanner/ink-contract";
+import { substratePalletScanner } from "../agents/scanner/substrate-pallet";
+import { buildSubstrateProject, isSubstrateManifest } from "../analysis/substrate-model";
+import { anyManifest, crateDependsOn, hasRootFile, rustSources } from "./detect";
+import type { DomainProfile } from "./types";
+
+/** ink! contracts and FRAME pallets: the root crate or a `contracts/` or `pallets/` member depends on `ink` or `frame-support`. */
+export const substrateProfile: DomainProfile = {
+  id: "substrate",
+  name: "Substrate",
+  priority: 70,
+  detect: (rootPath) => anyManifest(rootPath, isSubstrateManifest),
+  files: { extensions: [".rs"], rustCrates: true },
+  scanners: [
+    {
+      scanner: inkContractScanner,
+      kind: "pattern",
+      vulnClasses: ["missing_caller_check", "integer_overflow"],
+      description:
+        "ink! contracts: &mut self messages that write storage without reading self.env().caller(), and unguarded + / - on balances in transfer, mint and burn paths."
+    },
+    {
+      scanner: substratePalletScanner,
+      kind: "pattern",
+      vulnClasses: ["wrong_origin_check", "unbounded_weight", "integer_overflow"],
+      description:
+        "FRAME pallets: signed-only dispatchables that change StorageValue configuration or drop the signer, hooks and calls iterating storage maps without take(n), Vec arguments looped over with a fixed weight, and unguarded balance arithmetic."
+    }
+  ],
+  llmScanners: [
+    {
+      scannerId: "llm.scanner.ink.access-control",
+      vulnFocus:
+        "ink! #[ink(message)] methods taking &mut self that change storage (owner, fees, paused flags, balances of other accounts) without comparing self.env().caller() to a stored owner or keying the write by the caller",
+      vulnClasses: ["missing_caller_check"],
+      description: "LLM-powered deep analysis of caller checks in ink! messages."
+    },
+    {
+      scannerId: "llm.scanner.substrate.origin",
+      vulnFocus:
+        "FRAME pallet dispatchables that call ensure_signed where ensure_root or a configured EnsureOrigin was meant, discard the signer, or change StorageValue configuration without checking the signer against a stored admin",
+      vulnClasses: ["wrong_origin_check"],
+      description: "LLM-powered deep analysis of origin checks in FRAME dispatchables."
+    },
+    {
+      scannerId: "llm.scanner.substrate.arithmetic",
+      vulnFocus:
+        "balance arithmetic in ink! transfer/mint/burn messages and pallet dispatchables using plain + and - instead of checked_add/checked_sub or the Currency traits, where an amount larger than the balance wraps",
+      vulnClasses: ["integer_overflow"],
+      description: "LLM-powered deep analysis of balance arithmetic in ink! contracts and pallets."
+    },
+    {
+      scannerId: "llm.scanner.substrate.weight",
+      vulnFocus:
+        "weight bounds in FRAME pallets: on_initialize/on_idle hooks and dispatchables iterating StorageMap::iter, iter_keys or drain without take, and loops over Vec arguments whose #[pallet::weight] does not scale with their length",
+      vulnClasses: ["unbounded_weight"],
+      description: "LLM-powered deep analysis of weight bounds on pallet loops and hooks."
+    }
+  ],
+  threatModel: [
+    {
+      frameworks: ["ink"],
+      assets: ["Contract balance and token ledgers (Mapping storage)", "Contract owner and privileged storage fields"],
+      trustBoundaries: ["self.env().caller() -> #[ink(message)] handlers", "Contract -> cross-contract calls and value transfers"],
+      attackSurface: ["#[ink(message)] methods taking &mut self", "Balance arithmetic in transfer, mint and burn paths"]
+    },
+    {
+      frameworks: ["substrate-pallet"],
+      assets: [
+        "Runtime storage and account balances",
+        "Pallet configuration (StorageValue items)",
+        "Chain liveness within the block weight limit"
+      ],
+      trustBoundaries: [
+        "Signed origins -> dispatchables",
+        "Root and governance origins -> privileged dispatchables",
+        "Block hooks -> block weight budget"
+      ],
+      attackSurface: [
+        "Dispatchable origin checks (ensure_signed / ensure_root / EnsureOrigin)",
+        "Storage iteration in hooks and dispatchables",
+        "Declared #[pallet::weight] versus work performed"
+      ]
+    }
+  ],
+  async detectFrameworks({ rootPath, crateGraph }) {
+    if (!hasRootFile(rootPath, "Cargo.toml")) {
+      return [];
+    }
+    const graph = await crateGraph();
+    return [
+      ...(crateDependsOn(graph, "ink", "ink_lang") ? ["ink"] : []),
+      ...(crateDependsOn(graph, "frame_support", "frame_system") ? ["substrate-pallet"] : [])
+    ];
+  },
+  /** ink! constructors and messages; pallet dispatchables and the hooks run every block. */
+  async entryPoints(sources) {
+    const contents = await rustSources(sources);
+    if (![...contents.values()].some((content) => /#\[\s*(?:ink\b|pallet\s*::)/.test(content))) {
+      return [];
+    }
+    const project = buildSubstrateProject(await sources.crateGraph(), contents);
+    const entryPoints: string[] = [];
+    for (const contract of project.contracts) {
+      const relPath = sources.relPath(contract.file);
+      for (const fn of [...contract.constructors, ...contract.messages.map((message) => message.fn)]) {
+        entryPoints.push(`${relPath}::${fn.name}`);
+      }
+    }
+    for (const pallet of project.pallets) {
+      const relPath = sources.relPath(pallet.file);
+      for (const call of pallet.calls) entryPoints.push(`${relPath}::call::${call.fn.name}`);
+      for (const hook of pallet.hooks) entryPoints.push(`${relPath}::hooks::${hook.name}`);
+    }
+    return entryPoints;
+  },
+  sandboxProfile: "generic",
+  vulnClasses: ["missing_caller_check", "wrong_origin_check", "unbounded_weight", "integer_overflow"]
+};
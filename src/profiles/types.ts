@@ -0,0 +1,102 @@
+import type { Scanner } from "../agents/scanner/base";
+import type { RustCrateGraph } from "../analysis/rust-crates";
+import type { SandboxProfile } from "../sandbox/types";
+import type { AuthorityGraph, InstructionPrivileges, VulnClass } from "../types";
+import type { VulnClassDefinition } from "../vulns/types";
+
+/** Which source files a profile's LLM scanners read. */
+export interface ProfileFileSelection {
+  /** File extensions, with the leading dot */
+  extensions: string[];
+  /** Paths relative to the scan root to leave out, such as tests, scripts and vendored libraries */
+  exclude?: (relPath: string) => boolean;
+  /** Resolve Cargo crates so each file is judged with the modules it imports as context */
+  rustCrates?: boolean;
+}
+
+/** A deterministic scanner a profile runs, with the metadata `hydra_list_scanners` reports. */
+export interface ProfileScanner {
+  scanner: Scanner;
+  /** `pattern` scanners query a source model; `deterministic` ones are lint-level signal adapters */
+  kind: "pattern" | "deterministic";
+  vulnClasses: VulnClass[];
+  description: string;
+}
+
+/** One LLM scanner: the focus handed to the scanner prompt and the classes it reports. */
+export interface LlmFocusConfig {
+  scannerId: string;
+  vulnFocus: string;
+  vulnClasses: VulnClass[];
+  description: string;
+}
+
+/**
+ * Threat-model entries a profile contributes when any of `frameworks` is detected (a framework
+ * name from the threat model, or the id of a profile whose `detect` matches), unless one of
+ * `unless` is detected too.
+ */
+export interface ThreatModelContribution {
+  frameworks: string[];
+  unless?: string[];
+  assets?: string[];
+  trustBoundaries?: string[];
+  attackSurface?: string[];
+}
+
+/** Source files the threat model hands to a profile's hooks, read and parsed once per directory. */
+export interface ProfileSources {
+  /** Directory the hook looks at: the scan root, or the root of a detected subtree */
+  rootPath: string;
+  /** Absolute paths of the source files under `rootPath`, tests included */
+  files: string[];
+  read(filePath: string): Promise<string | undefined>;
+  /** The Cargo crates of the `.rs` files in `files` */
+  crateGraph(): Promise<RustCrateGraph>;
+  /** `filePath` relative to the scan root with `/` separators, the form entry points are listed in */
+  relPath(filePath: string): string;
+}
+
+/** Summaries attached to the scan result once the profile's scanners have finished. */
+export interface ProfileSummary {
+  privileges?: InstructionPrivileges[];
+  authority_graph?: AuthorityGraph;
+}
+
+/**
+ * A scanning domain: how to recognise a target, which files and scanners apply, what the threat
+ * model should say about it and where its exploits run. Built-in profiles live next to this file;
+ * third-party ones are loaded from `HYDRA_PROFILE_DIR`.
+ */
+export interface DomainProfile {
+  id: string;
+  name: string;
+  /** Profiles are tried in descending priority; the first whose `detect` matches a directory claims its subtree */
+  priority: number;
+  detect(rootPath: string): boolean;
+  /** Profiles whose scanners also run for this one, each on its own file selection */
+  includes?: string[];
+  files: ProfileFileSelection;
+  scanners: ProfileScanner[];
+  llmScanners: LlmFocusConfig[];
+  threatModel?: ThreatModelContribution[];
+  /** Framework names the threat model records for a directory, such as `solana-anchor` or `rust-web` */
+  detectFrameworks?(sources: ProfileSources): Promise<string[]>;
+  /** Entry points of the profile's programs or services, as `<relPath>::<path to the function>` */
+  entryPoints?(sources: ProfileSources): Promise<string[]>;
+  /** Trust boundaries derived from the code itself, given the detected frameworks */
+  deriveTrustBoundaries?(rootPath: string, frameworks: string[]): Promise<string[]>;
+  /** Sandbox image Red Team exploits and patch retests for this profile's files run in */
+  sandboxProfile: SandboxProfile;
+  vulnClasses: VulnClass[];
+  /** Classes this profile introduces, registered alongside it; every other class it names must already exist */
+  vulnClassDefinitions?: VulnClassDefinition[];
+  summarize?(rootPath: string): Promise<ProfileSummary>;
+}
+
+/** A profile detected for a subtree of the scan target. */
+export interface ProfileScope {
+  profile: DomainProfile;
+  /** Absolute path of the subtree */
+  root: string;
+}
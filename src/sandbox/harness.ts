@@ -0,0 +1,82 @@
+import path from "node:path";
+import { profileForFile } from "../profiles/registry";
+import type { SandboxProfile, SandboxSession } from "./types";
+
+/**
+ * How a Red Team exploit for a finding runs inside the sandbox: the profile, where the exploit
+ * and the target source are written, and the command whose exit status says whether the
+ * exploit succeeded (zero) or failed.
+ */
+export interface ExploitHarness {
+  profile: SandboxProfile;
+  exploitPath: string;
+  targetPath: string;
+  command: string[];
+  /** Budget for `command`, compilation included */
+  timeoutMs: number;
+  /** What `exploit_code` must look like, for the Red Team prompt */
+  format: string;
+  /** Directories to create and toolchain files to write before the exploit */
+  directories: string[];
+  files: Record<string, string>;
+}
+
+/** Foundry project for exploit tests: `forge-std` is baked into the EVM image and builds run offline. */
+const FOUNDRY_TOML = [
+  "[profile.default]",
+  'src = "src"',
+  'test = "test"',
+  'out = "out"',
+  "offline = true",
+  'remappings = ["forge-std/=/opt/forge-std/src/"]',
+  ""
+].join("\n");
+
+/**
+ * The sandbox comes from the domain profile owning the finding's file: EVM findings run as a
+ * Foundry test against the contract, everything else as a bun script.
+ */
+export function exploitHarnessFor(findingFile: string): ExploitHarness {
+  const base = path.basename(findingFile);
+  if (profileForFile(findingFile).sandboxProfile === "evm") {
+    return {
+      profile: "evm",
+      exploitPath: "/workspace/test/Exploit.t.sol",
+      targetPath: `/workspace/src/${base}`,
+      command: ["forge", "test", "--root", "/workspace", "--match-path", "test/Exploit.t.sol"],
+      timeoutMs: 90_000,
+      format:
+        `a Foundry test file written to test/Exploit.t.sol that imports "forge-std/Test.sol" and the target from "../src/${base}", ` +
+        "deploys it, and has a test that passes only if the exploit succeeds. No network or forking is available.",
+      directories: ["/workspace/src", "/workspace/test"],
+      files: { "/workspace/foundry.toml": FOUNDRY_TOML }
+    };
+  }
+  return {
+    profile: "generic",
+    exploitPath: "/workspace/exploit.ts",
+    targetPath: `/workspace/${base}`,
+    command: ["bun", "run", "/workspace/exploit.ts"],
+    timeoutMs: 25_000,
+    format: "a standalone TypeScript script run with `bun run` that exits 0 only if the exploit succeeds.",
+    directories: [],
+    files: {}
+  };
+}
+
+/** Write the toolchain files, the target source and the exploit into a fresh session. */
+export async function stageExploit(
+  session: SandboxSession,
+  harness: ExploitHarness,
+  exploitCode: string,
+  targetSource: string
+): Promise<void> {
+  if (harness.directories.length > 0) {
+    await session.exec(["mkdir", "-p", ...harness.directories]);
+  }
+  for (const [containerPath, content] of Object.entries(harness.files)) {
+    await session.writeFile(containerPath, content);
+  }
+  await session.writeFile(harness.targetPath, targetSource);
+  await session.writeFile(harness.exploitPath, exploitCode);
+}
@@ -11,7 +11,8 @@ import { DEFAULT_SANDBOX_CONFIG } from "./types";
 
 const IMAGE_MAP: Record<SandboxProfile, string> = {
   generic: "hydra/sandbox-generic:local",
-  solana: "hydra/sandbox-solana:local"
+  solana: "hydra/sandbox-solana:local",
+  evm: "hydra/sandbox-evm:local"
 };
 
 function runDocker(args: string[], timeoutMs: number): Promise<{ stdout: string; stderr: string; exitCode: number }> {
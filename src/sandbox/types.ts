@@ -1,4 +1,4 @@
-export type SandboxProfile = "generic" | "solana";
+export type SandboxProfile = "generic" | "solana" | "evm";
 
 export interface SandboxConfig {
   profile: SandboxProfile;
@@ -41,5 +41,11 @@ export const DEFAULT_SANDBOX_CONFIG: Record<SandboxProfile, SandboxConfig> = {
     timeoutMs: 120_000,
     memoryLimitMb: 2048,
     cpuLimit: 2.0
+  },
+  evm: {
+    profile: "evm",
+    timeoutMs: 120_000,
+    memoryLimitMb: 2048,
+    cpuLimit: 2.0
   }
 };
@@ -1,20 +1,12 @@
+import type { BuiltinVulnClass } from "./vulns/registry";
+
 export type Severity = "CRITICAL" | "HIGH" | "MEDIUM" | "LOW";
 
-export type VulnClass =
-  | "hardcoded_secret"
-  | "command_injection"
-  | "sql_injection"
-  | "xss"
-  | "insecure_deserialization"
-  | "missing_signer_check"
-  | "missing_has_one"
-  | "account_type_confusion"
-  | "arbitrary_cpi"
-  | "cpi_signer_seed_bypass"
-  | "cpi_reentrancy"
-  | "non_canonical_bump"
-  | "seed_collision"
-  | "attacker_controlled_seed";
+/**
+ * Id of a class in the vulnerability-class registry (`src/vulns/`). Built-in ids are listed for
+ * completion; domain profiles may register others.
+ */
+export type VulnClass = BuiltinVulnClass | (string & {});
 
 export interface Finding {
   id: string;
@@ -27,6 +19,18 @@ export interface Finding {
   title: string;
   description: string;
   evidence: string;
+  /** Cargo crate (workspace member) the finding belongs to */
+  crate?: string;
+  program_module?: string;
+  instruction?: string;
+  account_field?: string;
+  trace?: FindingLocation[];
+}
+
+export interface FindingLocation {
+  file: string;
+  line: number;
+  label: string;
 }
 
 export interface ScanTarget {
@@ -84,6 +88,10 @@ export type AgentRunStatus = "queued" | "running" | "completed" | "failed" | "ti
 export interface AgentRunRecord {
   id: string;
   agent_id: string;
+  /** Id of the domain profile the scanner ran for */
+  domain?: string;
+  /** Subtree the scanner ran on, relative to the scan root ("." for the root itself) */
+  scope?: string;
   status: AgentRunStatus;
   queued_at: string;
   started_at?: string;
@@ -93,13 +101,54 @@ export interface AgentRunRecord {
   error?: string;
 }
 
+/** Privileges an instruction's Accounts struct asks for, and whether its handler uses them. */
+export interface InstructionPrivileges {
+  crate?: string;
+  program_module: string;
+  instruction: string;
+  file: string;
+  line: number;
+  mutable_accounts: { account: string; written: boolean }[];
+  signers: { account: string; authorizes: boolean }[];
+  pda_signers: { seeds: string; operation: string; line: number }[];
+  /** The handler hands its context to other code, so unused privileges were not judged */
+  opaque: boolean;
+}
+
+export type AuthorityNodeKind = "instruction" | "account_type" | "pda" | "signer" | "program";
+export type AuthorityEdgeKind = "authorizes" | "constraint" | "seeds" | "cpi" | "signs" | "mutates";
+
+export interface AuthorityNode {
+  id: string;
+  kind: AuthorityNodeKind;
+  label: string;
+  crate?: string;
+}
+
+export interface AuthorityEdge {
+  from: string;
+  to: string;
+  kind: AuthorityEdgeKind;
+  label: string;
+}
+
+/** Who authorizes which instruction, which PDAs sign which CPIs and how accounts are bound together. */
+export interface AuthorityGraph {
+  nodes: AuthorityNode[];
+  edges: AuthorityEdge[];
+}
+
 export interface ScanResult {
   target: ScanTarget;
   started_at: string;
   completed_at: string;
   threat_model?: ThreatModelInfo;
+  /** Ids of the domain profiles that chose the scanners, one or more per polyglot repo */
+  domains?: string[];
   agent_runs?: AgentRunRecord[];
   findings: Finding[];
+  privileges?: InstructionPrivileges[];
+  authority_graph?: AuthorityGraph;
   adversarial_results?: AdversarialResult[];
   patch_results?: PatchResult[];
 }
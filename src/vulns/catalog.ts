@@ -0,0 +1,33 @@
+import type { VulnClassCatalog, VulnReference } from "./types";
+
+/** Checks a domain's built-in classes against the definition shape while keeping their ids as literal types. */
+export function vulnCatalog<T extends VulnClassCatalog>(catalog: T): T {
+  return catalog;
+}
+
+export function cwe(id: number): VulnReference {
+  return { source: "CWE", id: `CWE-${id}`, url: `https://cwe.mitre.org/data/definitions/${id}.html` };
+}
+
+/** An OWASP Top 10 (2021) category, e.g. `owasp("A03", "Injection")`. */
+export function owasp(code: string, name: string): VulnReference {
+  return {
+    source: "OWASP",
+    id: `${code}:2021 ${name}`,
+    url: `https://owasp.org/Top10/${code}_2021-${name.replace(/ /g, "_")}/`
+  };
+}
+
+/** A program of the coral-xyz/sealevel-attacks catalogue of Solana account-handling bugs. */
+export function sealevel(program: string): VulnReference {
+  return {
+    source: "Sealevel Attacks",
+    id: program,
+    url: `https://github.com/coral-xyz/sealevel-attacks/tree/master/programs/${program}`
+  };
+}
+
+/** An entry of the Smart Contract Weakness Classification registry. */
+export function swc(id: number): VulnReference {
+  return { source: "SWC", id: `SWC-${id}`, url: `https://swcregistry.io/docs/SWC-${id}` };
+}
@@ -0,0 +1,44 @@
+import { cwe, vulnCatalog } from "./catalog";
+
+export const cosmwasmVulnClasses = vulnCatalog({
+  missing_sender_auth: {
+    title: "Privileged execute handler does not check the sender",
+    description: "An execute message changes configuration, ownership or funds without comparing `info.sender` to the stored admin.",
+    defaultSeverity: "HIGH",
+    references: [cwe(862)],
+    remediation: "Load the stored admin or owner and return `Unauthorized` unless `info.sender` matches it before changing state.",
+    exampleFix: "if info.sender != config.admin {\n    return Err(ContractError::Unauthorized {});\n}"
+  },
+  unchecked_addr: {
+    title: "Address stored without validation",
+    description: "A user-supplied address is turned into `Addr` with `Addr::unchecked`, so malformed or mixed-case addresses are stored.",
+    defaultSeverity: "MEDIUM",
+    references: [cwe(20)],
+    remediation: "Validate user-supplied addresses with `deps.api.addr_validate` before storing or comparing them.",
+    exampleFix: "let recipient = deps.api.addr_validate(&msg.recipient)?;"
+  },
+  submsg_reentrancy: {
+    title: "State updated only after a submessage reply",
+    description: "Bookkeeping is deferred to the `reply` handler, so the callee runs against state that does not yet reflect the operation.",
+    defaultSeverity: "HIGH",
+    references: [cwe(841)],
+    remediation: "Update balances and locks before dispatching the submessage, and use `reply` only to reconcile its result.",
+    exampleFix: "BALANCES.update(deps.storage, &info.sender, |b| -> StdResult<_> { Ok(b.unwrap_or_default() - amount) })?;\nOk(Response::new().add_submessage(SubMsg::reply_on_error(msg, WITHDRAW_REPLY)))"
+  },
+  unbounded_iteration: {
+    title: "Unbounded iteration over storage",
+    description: "A `Map::range` or `keys` walk has no limit, so a growing map makes the message exceed its gas limit.",
+    defaultSeverity: "MEDIUM",
+    references: [cwe(834), cwe(400)],
+    remediation: "Paginate with `start_after` and a capped `limit`, or keep running totals instead of recomputing them over the whole map.",
+    exampleFix: "let page: Vec<_> = STAKES.range(deps.storage, start, None, Order::Ascending).take(limit.min(MAX_LIMIT) as usize).collect();"
+  },
+  unprotected_migration: {
+    title: "Migration without version or authority checks",
+    description: "The `migrate` entry point rewrites state without checking the stored contract name and version.",
+    defaultSeverity: "HIGH",
+    references: [cwe(284)],
+    remediation: "Check the stored contract name and version with `cw2::get_contract_version` and refuse downgrades or foreign contracts before migrating.",
+    exampleFix: "let stored = get_contract_version(deps.storage)?;\nif stored.contract != CONTRACT_NAME {\n    return Err(ContractError::InvalidMigration {});\n}"
+  }
+});
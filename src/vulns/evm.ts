@@ -0,0 +1,52 @@
+import { cwe, swc, vulnCatalog } from "./catalog";
+
+export const evmVulnClasses = vulnCatalog({
+  reentrancy: {
+    title: "External call before state update (reentrancy)",
+    description: "A function sends ether or calls another contract before updating the balances that call depends on.",
+    defaultSeverity: "HIGH",
+    references: [cwe(841), swc(107)],
+    remediation: "Follow checks-effects-interactions: update state before the external call, and add a reentrancy guard to functions that transfer value.",
+    exampleFix: "balances[msg.sender] -= amount;\n(bool ok, ) = msg.sender.call{value: amount}(\"\");\nrequire(ok, \"transfer failed\");"
+  },
+  tx_origin_auth: {
+    title: "Authorization based on tx.origin",
+    description: "Access control compares `tx.origin`, so any contract the owner interacts with can call through with the owner's authority.",
+    defaultSeverity: "HIGH",
+    references: [cwe(863), swc(115)],
+    remediation: "Authorize with `msg.sender`.",
+    exampleFix: "require(msg.sender == owner, \"not owner\");"
+  },
+  unchecked_call_return: {
+    title: "Return value of a low-level call is not checked",
+    description: "The `bool` returned by `call`, `send` or `delegatecall` is ignored, so a failed transfer goes unnoticed.",
+    defaultSeverity: "HIGH",
+    references: [cwe(252), swc(104)],
+    remediation: "Check the returned `bool` and revert on failure, or use `Address.sendValue` / `SafeERC20`.",
+    exampleFix: "(bool ok, ) = recipient.call{value: amount}(\"\");\nrequire(ok, \"transfer failed\");"
+  },
+  arbitrary_delegatecall: {
+    title: "delegatecall to a caller-chosen address",
+    description: "The target of a `delegatecall` comes from arguments or unprotected storage, letting a caller run arbitrary code with the contract's storage.",
+    defaultSeverity: "HIGH",
+    references: [cwe(829), swc(112)],
+    remediation: "Delegatecall only to allowlisted implementations set by an authorized role.",
+    exampleFix: "require(approvedModules[module], \"module not approved\");"
+  },
+  missing_access_modifier: {
+    title: "Privileged function has no access control",
+    description: "A public or external function changes owners, fees or funds without an access modifier or sender check.",
+    defaultSeverity: "HIGH",
+    references: [cwe(284)],
+    remediation: "Restrict the function with `onlyOwner`, a role check or an explicit `msg.sender` comparison.",
+    exampleFix: "function setWithdrawFee(uint256 bps) external onlyOwner {"
+  },
+  unsafe_ecrecover: {
+    title: "Signature verified with raw ecrecover",
+    description: "Signatures are checked with `ecrecover` without rejecting the zero address, malleable signatures or replays across nonces and chains.",
+    defaultSeverity: "HIGH",
+    references: [cwe(347), swc(117), swc(121)],
+    remediation: "Verify with OpenZeppelin `ECDSA.recover` over an EIP-712 digest that includes a nonce and the chain id, and mark nonces used.",
+    exampleFix: "bytes32 digest = _hashTypedDataV4(keccak256(abi.encode(CLAIM_TYPEHASH, to, amount, nonces[to]++)));\naddress signer = ECDSA.recover(digest, signature);"
+  }
+});
@@ -0,0 +1,44 @@
+import { cwe, owasp, vulnCatalog } from "./catalog";
+
+export const genericVulnClasses = vulnCatalog({
+  hardcoded_secret: {
+    title: "Hardcoded secret",
+    description: "A credential, API key or private key is committed in source where anyone with the code can read it.",
+    defaultSeverity: "HIGH",
+    references: [cwe(798), owasp("A07", "Identification and Authentication Failures")],
+    remediation: "Rotate the exposed credential, load it from the environment or a secret manager at runtime and keep it out of version control.",
+    exampleFix: "const apiKey = process.env.PAYMENTS_API_KEY;"
+  },
+  command_injection: {
+    title: "Command injection",
+    description: "Untrusted input is concatenated or interpolated into a shell command line.",
+    defaultSeverity: "CRITICAL",
+    references: [cwe(78), owasp("A03", "Injection")],
+    remediation: "Invoke the program directly with an argument array instead of a shell string, and allowlist the values callers may pass.",
+    exampleFix: 'execFile("git", ["log", "--oneline", branch]);'
+  },
+  sql_injection: {
+    title: "SQL injection",
+    description: "A SQL statement is built from untrusted input by string concatenation or interpolation.",
+    defaultSeverity: "HIGH",
+    references: [cwe(89), owasp("A03", "Injection")],
+    remediation: "Use parameterized queries or prepared statements and never splice input into SQL text.",
+    exampleFix: 'db.query("SELECT * FROM users WHERE id = $1", [userId]);'
+  },
+  xss: {
+    title: "Cross-site scripting",
+    description: "Untrusted data reaches an HTML sink such as innerHTML or document.write without escaping.",
+    defaultSeverity: "HIGH",
+    references: [cwe(79), owasp("A03", "Injection")],
+    remediation: "Render untrusted data through text APIs or framework escaping, and sanitize with an allowlist when HTML is required.",
+    exampleFix: "element.textContent = comment.body;"
+  },
+  insecure_deserialization: {
+    title: "Insecure deserialization",
+    description: "Untrusted bytes are decoded with a deserializer that can instantiate arbitrary types or run code.",
+    defaultSeverity: "HIGH",
+    references: [cwe(502), owasp("A08", "Software and Data Integrity Failures")],
+    remediation: "Parse untrusted input as plain data (JSON, safe YAML loaders) and validate it against a schema before use.",
+    exampleFix: "config = yaml.safe_load(body)"
+  }
+});
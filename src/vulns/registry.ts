@@ -0,0 +1,79 @@
+import type { Severity, VulnClass } from "../types";
+import { cosmwasmVulnClasses } from "./cosmwasm";
+import { evmVulnClasses } from "./evm";
+import { genericVulnClasses } from "./generic";
+import { rustVulnClasses } from "./rust";
+import { solanaVulnClasses } from "./solana";
+import { substrateVulnClasses } from "./substrate";
+import type { VulnClassCatalog, VulnClassDefinition } from "./types";
+
+export type BuiltinVulnClass =
+  | keyof typeof genericVulnClasses
+  | keyof typeof solanaVulnClasses
+  | keyof typeof rustVulnClasses
+  | keyof typeof cosmwasmVulnClasses
+  | keyof typeof substrateVulnClasses
+  | keyof typeof evmVulnClasses;
+
+const SEVERITIES = new Set<Severity>(["CRITICAL", "HIGH", "MEDIUM", "LOW"]);
+const VULN_CLASS_ID = /^[a-z][a-z0-9_]*$/;
+
+const vulnClasses = new Map<string, VulnClassDefinition>();
+
+export function registerVulnClass(definition: VulnClassDefinition): void {
+  if (vulnClasses.has(definition.id)) {
+    throw new Error(`Vulnerability class already registered: ${definition.id}`);
+  }
+  vulnClasses.set(definition.id, definition);
+}
+
+function registerCatalog(domain: string, catalog: VulnClassCatalog): void {
+  for (const [id, definition] of Object.entries(catalog)) {
+    registerVulnClass({ id, domain, ...definition });
+  }
+}
+
+registerCatalog("generic", genericVulnClasses);
+registerCatalog("solana", solanaVulnClasses);
+registerCatalog("rust", rustVulnClasses);
+registerCatalog("cosmwasm", cosmwasmVulnClasses);
+registerCatalog("substrate", substrateVulnClasses);
+registerCatalog("evm", evmVulnClasses);
+
+/** Registered classes, built-ins first, in registration order. */
+export function listVulnClasses(): VulnClassDefinition[] {
+  return [...vulnClasses.values()];
+}
+
+export function getVulnClass(id: string): VulnClassDefinition | undefined {
+  return vulnClasses.get(id);
+}
+
+export function isVulnClass(value: unknown): value is VulnClass {
+  return typeof value === "string" && vulnClasses.has(value);
+}
+
+/** The definition of a class scanners report; throws for ids no profile registered. */
+export function vulnClassInfo(id: VulnClass): VulnClassDefinition {
+  const definition = vulnClasses.get(id);
+  if (!definition) {
+    throw new Error(`Unknown vulnerability class: ${id}`);
+  }
+  return definition;
+}
+
+export function assertVulnClassDefinition(value: unknown, source: string): VulnClassDefinition {
+  const definition = (value ?? {}) as Partial<VulnClassDefinition>;
+  const problems: string[] = [];
+  if (typeof definition.id !== "string" || !VULN_CLASS_ID.test(definition.id)) problems.push("id must be a snake_case slug");
+  if (typeof definition.domain !== "string") problems.push("domain must be a string");
+  if (typeof definition.title !== "string") problems.push("title must be a string");
+  if (typeof definition.description !== "string") problems.push("description must be a string");
+  if (!SEVERITIES.has(definition.defaultSeverity as Severity)) problems.push("defaultSeverity must be CRITICAL, HIGH, MEDIUM or LOW");
+  if (!Array.isArray(definition.references)) problems.push("references must be an array");
+  if (typeof definition.remediation !== "string") problems.push("remediation must be a string");
+  if (problems.length > 0) {
+    throw new Error(`Invalid vulnerability class ${String(definition.id)} in ${source}: ${problems.join("; ")}`);
+  }
+  return definition as VulnClassDefinition;
+}
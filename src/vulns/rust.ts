@@ -0,0 +1,52 @@
+import { cwe, owasp, vulnCatalog } from "./catalog";
+
+export const rustVulnClasses = vulnCatalog({
+  rust_command_injection: {
+    title: "Command injection through std::process::Command",
+    description: "Request data reaches a shell invocation (`sh -c`) or chooses the program a `Command` runs.",
+    defaultSeverity: "CRITICAL",
+    references: [cwe(78), owasp("A03", "Injection")],
+    remediation: "Run the program directly with `Command::new(program).arg(value)` instead of `sh -c`, and allowlist the program and its arguments.",
+    exampleFix: "Command::new(\"convert\").arg(&input_path).arg(&output_path).status()?;"
+  },
+  rust_sql_injection: {
+    title: "SQL built from request data",
+    description: "A query string assembled with `format!` or concatenation is passed to a database driver.",
+    defaultSeverity: "HIGH",
+    references: [cwe(89), owasp("A03", "Injection")],
+    remediation: "Use bind parameters (`sqlx::query(..).bind(..)`, `diesel` DSL) and never format values into SQL text.",
+    exampleFix: "sqlx::query(\"SELECT * FROM users WHERE name = $1\").bind(&name).fetch_all(&pool).await?;"
+  },
+  path_traversal: {
+    title: "Path traversal",
+    description: "A request-controlled path is joined onto a base directory and opened without canonicalizing and checking the result.",
+    defaultSeverity: "HIGH",
+    references: [cwe(22), owasp("A01", "Broken Access Control")],
+    remediation: "Canonicalize the joined path and verify it still starts with the base directory, or reject names containing separators and `..`.",
+    exampleFix: "let path = base.join(&name).canonicalize()?;\nif !path.starts_with(&base) {\n    return Err(StatusCode::FORBIDDEN);\n}"
+  },
+  unsafe_code: {
+    title: "Undocumented unsafe code",
+    description: "An `unsafe` block or function has no `// SAFETY:` comment stating the invariants it relies on.",
+    defaultSeverity: "MEDIUM",
+    references: [cwe(119)],
+    remediation: "Replace the `unsafe` with a safe API where one exists; otherwise document the invariants in a `// SAFETY:` comment and check them.",
+    exampleFix: "// SAFETY: `idx < self.len` was checked above, so the read is in bounds.\nunsafe { *self.ptr.add(idx) }"
+  },
+  handler_panic: {
+    title: "Request handler can panic",
+    description: "A request handler calls `unwrap`, `expect` or indexes with input-derived values, so a crafted request aborts it.",
+    defaultSeverity: "MEDIUM",
+    references: [cwe(248)],
+    remediation: "Propagate errors with `?` into a response type and use `get` instead of indexing on request-derived values.",
+    exampleFix: "let id: u64 = params.id.parse().map_err(|_| StatusCode::BAD_REQUEST)?;"
+  },
+  unbounded_deserialization: {
+    title: "Unbounded deserialization of request data",
+    description: "Request bodies or network input are decoded without a size limit, letting a client exhaust memory.",
+    defaultSeverity: "MEDIUM",
+    references: [cwe(770), cwe(502)],
+    remediation: "Cap the body size before decoding (e.g. `DefaultBodyLimit`, `bincode` `with_limit`) and bound collection lengths.",
+    exampleFix: "let config = bincode::DefaultOptions::new().with_limit(64 * 1024);\nlet msg: Message = config.deserialize(&bytes)?;"
+  }
+});
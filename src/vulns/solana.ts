@@ -0,0 +1,268 @@
+import { cwe, sealevel, vulnCatalog } from "./catalog";
+
+export const solanaVulnClasses = vulnCatalog({
+  missing_signer_check: {
+    title: "Missing signer check on authority account",
+    description: "Instruction uses an authority-like account without enforcing signer semantics.",
+    defaultSeverity: "HIGH",
+    references: [cwe(862), sealevel("0-signer-authorization")],
+    remediation: "Declare the authority as `Signer<'info>` (or check `is_signer` in native programs) before it authorizes any state change.",
+    exampleFix: "pub authority: Signer<'info>,"
+  },
+  missing_has_one: {
+    title: "Missing has_one relationship constraint",
+    description: "Account relationship constraints are missing, allowing owner/context substitution.",
+    defaultSeverity: "HIGH",
+    references: [cwe(639), sealevel("1-account-data-matching")],
+    remediation: "Bind the account to the keys stored in program state with `has_one` or an explicit `constraint`, so callers cannot pass someone else's account.",
+    exampleFix: "#[account(mut, has_one = authority)]\npub vault: Account<'info, Vault>,"
+  },
+  account_type_confusion: {
+    title: "Account type confusion risk",
+    description: "Account type validation appears weak and may allow wrong account struct substitution.",
+    defaultSeverity: "HIGH",
+    references: [cwe(843), sealevel("3-type-cosplay")],
+    remediation: "Deserialize through `Account<'info, T>` so the discriminator is checked, or compare an explicit type tag before trusting the data layout.",
+    exampleFix: "pub config: Account<'info, Config>,"
+  },
+  arbitrary_cpi: {
+    title: "Arbitrary CPI target risk",
+    description: "Program appears to allow user-controlled CPI target, which may permit malicious invocation.",
+    defaultSeverity: "CRITICAL",
+    references: [cwe(829), sealevel("5-arbitrary-cpi")],
+    remediation: "Take the callee as `Program<'info, T>` or compare its key to the expected program id before invoking it.",
+    exampleFix: "pub token_program: Program<'info, Token>,"
+  },
+  cpi_signer_seed_bypass: {
+    title: "Signer seed validation weakness in CPI",
+    description: "CPI signer seed handling appears weak and may permit authority bypass.",
+    defaultSeverity: "CRITICAL",
+    references: [cwe(863), sealevel("8-pda-sharing")],
+    remediation: "Derive `invoke_signed` seeds from validated accounts and the stored canonical bump, never from instruction arguments.",
+    exampleFix: "let seeds = &[b\"vault\", pool.key().as_ref(), &[pool.vault_bump]];"
+  },
+  cpi_reentrancy: {
+    title: "Potential CPI reentrancy path",
+    description: "Instruction flow appears vulnerable to callback-based reentrancy.",
+    defaultSeverity: "CRITICAL",
+    references: [cwe(841)],
+    remediation: "Commit state changes before the CPI, and reload or re-validate accounts the callee may have modified afterwards.",
+    exampleFix: "vault.locked = true;\ninvoke(&ix, &accounts)?;\nctx.accounts.vault.reload()?;"
+  },
+  non_canonical_bump: {
+    title: "Non-canonical bump handling",
+    description: "PDA derivation appears to allow non-canonical bump use, increasing spoof/collision risk.",
+    defaultSeverity: "HIGH",
+    references: [cwe(345), sealevel("7-bump-seed-canonicalization")],
+    remediation: "Derive PDAs with `find_program_address` or Anchor's `bump` constraint and store the canonical bump, rather than accepting a caller-supplied bump.",
+    exampleFix: "#[account(seeds = [b\"escrow\", maker.key().as_ref()], bump = escrow.bump)]"
+  },
+  seed_collision: {
+    title: "PDA seed collision risk",
+    description: "Seed composition may permit collisions across logical account namespaces.",
+    defaultSeverity: "HIGH",
+    references: [cwe(694), sealevel("8-pda-sharing")],
+    remediation: "Prefix every PDA with a distinct static seed per account kind and use fixed-length components so different tuples cannot concatenate to the same bytes.",
+    exampleFix: "seeds = [b\"user_stats\", user.key().as_ref()]"
+  },
+  attacker_controlled_seed: {
+    title: "Attacker-controlled PDA seed component",
+    description: "Attacker input appears to influence PDA seeds without strong domain separation.",
+    defaultSeverity: "HIGH",
+    references: [cwe(639)],
+    remediation: "Build seeds from validated account keys rather than free-form arguments, and domain-separate any argument that must be included.",
+    exampleFix: "seeds = [b\"position\", owner.key().as_ref(), market.key().as_ref()]"
+  },
+  integer_overflow: {
+    title: "Unchecked integer arithmetic",
+    description: "Token amount arithmetic appears to be unchecked and may overflow or underflow.",
+    defaultSeverity: "HIGH",
+    references: [cwe(190), cwe(191)],
+    remediation: "Use `checked_*` arithmetic and map `None` to an error; release builds wrap silently unless `overflow-checks` is enabled.",
+    exampleFix: "vault.balance = vault.balance.checked_sub(amount).ok_or(ErrorCode::InsufficientFunds)?;"
+  },
+  precision_loss: {
+    title: "Precision loss in token math",
+    description: "Integer division appears to truncate in a way an attacker can accumulate or exploit.",
+    defaultSeverity: "HIGH",
+    references: [cwe(1339), cwe(682)],
+    remediation: "Multiply before dividing, widen to u128 for intermediates and round in the protocol's favour.",
+    exampleFix: "let shares = (amount as u128 * total_shares as u128 / total_assets as u128) as u64;"
+  },
+  unsafe_cast: {
+    title: "Lossy integer cast",
+    description: "Numeric `as` cast may silently truncate or change sign.",
+    defaultSeverity: "HIGH",
+    references: [cwe(681), cwe(197)],
+    remediation: "Convert with `try_from` / `try_into` and handle the error instead of `as`.",
+    exampleFix: "let amount = u64::try_from(wide).map_err(|_| ErrorCode::Overflow)?;"
+  },
+  division_by_zero: {
+    title: "Division by attacker-influenced zero",
+    description: "Division denominator may be zero, aborting the instruction.",
+    defaultSeverity: "HIGH",
+    references: [cwe(369)],
+    remediation: "Reject a zero denominator explicitly or use `checked_div` before dividing by values callers can influence.",
+    exampleFix: "let price = reserve_a.checked_div(reserve_b).ok_or(ErrorCode::EmptyPool)?;"
+  },
+  reinitialization: {
+    title: "Account re-initialization risk",
+    description: "Initialization path can run again on an already-initialized account and overwrite its state.",
+    defaultSeverity: "HIGH",
+    references: [cwe(665), sealevel("4-initialization")],
+    remediation: "Create accounts with Anchor's `init` constraint, or check and set an `is_initialized` flag in native programs.",
+    exampleFix: "#[account(init, payer = payer, space = 8 + Config::INIT_SPACE)]"
+  },
+  unsafe_init_if_needed: {
+    title: "init_if_needed without initialization guard",
+    description: "init_if_needed account is used without checking whether it was already initialized.",
+    defaultSeverity: "HIGH",
+    references: [cwe(665), sealevel("4-initialization")],
+    remediation: "Prefer `init`; where `init_if_needed` is required, only write initial fields when the account is fresh.",
+    exampleFix: "if !user.initialized {\n    user.owner = owner.key();\n    user.initialized = true;\n}"
+  },
+  unsafe_account_close: {
+    title: "Unsafe manual account close",
+    description: "Account is closed by draining lamports without wiping data, allowing it to be revived.",
+    defaultSeverity: "HIGH",
+    references: [cwe(672), sealevel("9-closing-accounts")],
+    remediation: "Close accounts with Anchor's `close` constraint, or zero the data, assign it to the system program and drain lamports together.",
+    exampleFix: "#[account(mut, close = receiver)]\npub position: Account<'info, Position>,"
+  },
+  realloc_without_zero: {
+    title: "Realloc without zero-initialization",
+    description: "Account is reallocated without zeroing new bytes, exposing stale data.",
+    defaultSeverity: "HIGH",
+    references: [cwe(908)],
+    remediation: "Zero newly allocated bytes when growing an account that may have shrunk earlier in the same transaction.",
+    exampleFix: "#[account(mut, realloc = new_len, realloc::payer = payer, realloc::zero = true)]"
+  },
+  unchecked_oracle_price: {
+    title: "Oracle price used without validation",
+    description: "Oracle price is consumed without staleness or confidence-interval checks.",
+    defaultSeverity: "HIGH",
+    references: [cwe(345)],
+    remediation: "Reject prices older than a maximum age or with a wide confidence interval, and pin the feed account's address.",
+    exampleFix: "let price = feed.get_price_no_older_than(&clock, MAX_AGE_SECS).ok_or(ErrorCode::StalePrice)?;"
+  },
+  missing_slippage_check: {
+    title: "Swap without slippage protection",
+    description: "Swap-style instruction has no minimum-output bound and can be sandwiched.",
+    defaultSeverity: "HIGH",
+    references: [cwe(20)],
+    remediation: "Take a caller-supplied minimum output (or maximum input) and fail when the executed amount crosses it.",
+    exampleFix: "require!(amount_out >= min_amount_out, ErrorCode::SlippageExceeded);"
+  },
+  spot_price_manipulation: {
+    title: "Share/price derived from spot balances",
+    description: "Exchange rate is computed from live token balances that can be moved within one transaction.",
+    defaultSeverity: "HIGH",
+    references: [cwe(345)],
+    remediation: "Price from internally tracked reserves, a time-weighted average or an oracle instead of live token account balances.",
+    exampleFix: "let rate = pool.tracked_assets as u128 * PRECISION / pool.total_shares as u128;"
+  },
+  fee_rounding: {
+    title: "Fee rounds in the user's favour",
+    description: "Fee computation truncates toward zero, letting small or split trades avoid fees.",
+    defaultSeverity: "HIGH",
+    references: [cwe(682)],
+    remediation: "Round fees up (ceiling division) and enforce a minimum fee so splitting a trade cannot avoid it.",
+    exampleFix: "let fee = (amount * fee_bps + 9_999) / 10_000;"
+  },
+  missing_owner_check: {
+    title: "Missing owner check on deserialized account",
+    description: "Raw account data is deserialized without verifying the account is owned by the expected program.",
+    defaultSeverity: "HIGH",
+    references: [cwe(345), sealevel("2-owner-checks")],
+    remediation: "Use `Account<'info, T>` or add an `owner =` constraint, or compare `account.owner` to the expected program id before deserializing.",
+    exampleFix: "if price_feed.owner != &ORACLE_PROGRAM_ID {\n    return Err(ProgramError::IncorrectProgramId);\n}"
+  },
+  missing_token_mint_check: {
+    title: "Token account not bound to a mint",
+    description: "Token account taking part in a transfer is not constrained to the expected mint.",
+    defaultSeverity: "HIGH",
+    references: [cwe(345), sealevel("1-account-data-matching")],
+    remediation: "Constrain every token account in a transfer to the expected mint with `token::mint` or a `constraint`.",
+    exampleFix: "#[account(mut, token::mint = pool.mint)]\npub user_token: Account<'info, TokenAccount>,"
+  },
+  missing_token_authority_check: {
+    title: "Token account not bound to an authority",
+    description: "Program-custodied token account is not constrained to the expected owner.",
+    defaultSeverity: "HIGH",
+    references: [cwe(862), sealevel("1-account-data-matching")],
+    remediation: "Constrain program-custodied token accounts to the PDA or authority that owns them with `token::authority`.",
+    exampleFix: "#[account(mut, token::authority = vault_authority)]\npub vault_token: Account<'info, TokenAccount>,"
+  },
+  associated_token_mismatch: {
+    title: "Associated token account derived incorrectly",
+    description: "Associated token address is derived or validated with the wrong wallet, mint or token program.",
+    defaultSeverity: "HIGH",
+    references: [cwe(345)],
+    remediation: "Validate associated token accounts with `associated_token::mint`, `associated_token::authority` and the token program that owns the mint.",
+    exampleFix: "#[account(associated_token::mint = mint, associated_token::authority = owner, associated_token::token_program = token_program)]"
+  },
+  unchecked_token_extension: {
+    title: "Token-2022 extensions not checked",
+    description: "Token-2022 mint is accepted without inspecting extensions such as transfer hooks or a permanent delegate.",
+    defaultSeverity: "HIGH",
+    references: [cwe(20)],
+    remediation: "Read the mint's extension list and reject extensions the program cannot handle, such as permanent delegates or transfer hooks.",
+    exampleFix: "let mint = StateWithExtensions::<Mint>::unpack(&data)?;\nrequire!(mint.get_extension::<PermanentDelegate>().is_err(), ErrorCode::UnsupportedMint);"
+  },
+  substitutable_transfer_authority: {
+    title: "Transfer authority can be substituted",
+    description: "Authority signing a token transfer is derived from an account the caller chooses.",
+    defaultSeverity: "HIGH",
+    references: [cwe(639)],
+    remediation: "Derive the signing PDA from accounts already bound to program state, not from an account the caller passes in.",
+    exampleFix: "#[account(seeds = [b\"authority\", pool.key().as_ref()], bump = pool.authority_bump)]"
+  },
+  unchecked_remaining_accounts: {
+    title: "remaining_accounts trusted without validation",
+    description: "Accounts from ctx.remaining_accounts are used without checking their owner, address or type.",
+    defaultSeverity: "HIGH",
+    references: [cwe(345), sealevel("2-owner-checks")],
+    remediation: "Check each remaining account's owner, key and discriminator before using it, or move it into the Accounts struct.",
+    exampleFix: "let market = Account::<Market>::try_from(&ctx.remaining_accounts[0])?;"
+  },
+  sysvar_spoofing: {
+    title: "Sysvar account can be spoofed",
+    description: "Sysvar account is taken as a raw AccountInfo without pinning its address to the sysvar id.",
+    defaultSeverity: "HIGH",
+    references: [cwe(345), sealevel("10-sysvar-address-checking")],
+    remediation: "Take sysvars as `Sysvar<'info, T>` or pin the account to the sysvar id with an `address =` constraint.",
+    exampleFix: "#[account(address = sysvar::instructions::ID)]\npub instructions: UncheckedAccount<'info>,"
+  },
+  duplicate_mutable_accounts: {
+    title: "Mutable accounts of the same type can be the same account",
+    description: "Two mutable accounts of the same type are not required to differ, so a caller can pass one account for both.",
+    defaultSeverity: "HIGH",
+    references: [cwe(694), sealevel("6-duplicate-mutable-accounts")],
+    remediation: "Require the keys to differ with a `constraint` or `require_keys_neq!`, or derive the accounts from distinct PDA seeds.",
+    exampleFix: "#[account(mut, constraint = from.key() != to.key() @ ErrorCode::SameAccount)]"
+  },
+  unnecessary_mut_account: {
+    title: "Account marked mutable but never written",
+    description: "Account is declared #[account(mut)] although the instruction never modifies it.",
+    defaultSeverity: "LOW",
+    references: [cwe(272)],
+    remediation: "Drop `mut` from accounts the instruction only reads.",
+    exampleFix: "pub config: Account<'info, Config>,"
+  },
+  unnecessary_signer: {
+    title: "Signer required but never used for authorization",
+    description: "Account must sign the transaction although no constraint or check relies on its signature.",
+    defaultSeverity: "LOW",
+    references: [cwe(272)],
+    remediation: "Remove the signer requirement, or bind the signer to program state so its signature authorizes something.",
+    exampleFix: "#[account(has_one = operator)]\npub pool: Account<'info, Pool>,\npub operator: Signer<'info>,"
+  },
+  overprivileged_pda_signer: {
+    title: "PDA authority signs unrelated operations",
+    description: "One PDA with program-wide seeds signs several kinds of CPI, so any flaw in one path lends its authority to all.",
+    defaultSeverity: "MEDIUM",
+    references: [cwe(250), sealevel("8-pda-sharing")],
+    remediation: "Give each purpose its own PDA authority with purpose-specific seeds, such as per-vault or per-operation seeds.",
+    exampleFix: "seeds = [b\"fee_authority\", pool.key().as_ref()]"
+  }
+});
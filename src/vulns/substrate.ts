@@ -0,0 +1,28 @@
+import { cwe, vulnCatalog } from "./catalog";
+
+export const substrateVulnClasses = vulnCatalog({
+  missing_caller_check: {
+    title: "ink! message mutates storage without checking the caller",
+    description: "A message that changes ownership, configuration or balances never compares `self.env().caller()` to a stored authority.",
+    defaultSeverity: "HIGH",
+    references: [cwe(862)],
+    remediation: "Compare `self.env().caller()` to the stored owner or role and return an error before mutating storage.",
+    exampleFix: "if self.env().caller() != self.owner {\n    return Err(Error::NotOwner);\n}"
+  },
+  wrong_origin_check: {
+    title: "Privileged dispatchable accepts any signed origin",
+    description: "A pallet call that changes configuration uses `ensure_signed` where it should require root or a configured admin origin.",
+    defaultSeverity: "HIGH",
+    references: [cwe(863)],
+    remediation: "Use `ensure_root` or a configured `EnsureOrigin` (such as `T::AdminOrigin`) for privileged calls.",
+    exampleFix: "T::AdminOrigin::ensure_origin(origin)?;"
+  },
+  unbounded_weight: {
+    title: "Unbounded work in a hook or dispatchable",
+    description: "A hook or call iterates storage or an unbounded `Vec` whose size its weight does not account for.",
+    defaultSeverity: "HIGH",
+    references: [cwe(400), cwe(834)],
+    remediation: "Bound inputs with `BoundedVec`, cap per-block work in hooks and charge weight proportional to the items processed.",
+    exampleFix: "pub fn claim_all(origin: OriginFor<T>, ids: BoundedVec<T::ClaimId, T::MaxClaims>) -> DispatchResult"
+  }
+});
@@ -0,0 +1,30 @@
+import type { Severity } from "../types";
+
+/** An entry in a public weakness taxonomy or a domain-specific write-up of the class. */
+export interface VulnReference {
+  /** Taxonomy or source, such as `CWE`, `OWASP`, `SWC` or `Sealevel Attacks` */
+  source: string;
+  id: string;
+  url?: string;
+}
+
+/**
+ * What Hydra knows about a vulnerability class: the LLM parser accepts its id, SARIF rules and the
+ * markdown report describe it, and pattern scanners take their marker titles from it.
+ */
+export interface VulnClassDefinition {
+  id: string;
+  /** Id of the domain profile the class belongs to */
+  domain: string;
+  title: string;
+  description: string;
+  /** Severity when a scanner or the LLM does not judge one */
+  defaultSeverity: Severity;
+  references: VulnReference[];
+  remediation: string;
+  /** A short snippet showing the fixed code */
+  exampleFix?: string;
+}
+
+/** Built-in classes of one domain, keyed by id. */
+export type VulnClassCatalog = Record<string, Omit<VulnClassDefinition, "id" | "domain">>;